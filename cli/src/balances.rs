@@ -0,0 +1,23 @@
+// cli/src/balances.rs
+use crate::{signer, Global};
+use clap::Args;
+use jupiter_sdk::{portfolio_flow, Config, Result, TxSigner};
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
+
+#[derive(Args, Debug)]
+pub struct BalancesArgs {
+    /// Wallet to look up instead of the signer's; needs no key
+    #[arg(long)]
+    owner: Option<String>,
+}
+
+pub async fn run(global: &Global, args: BalancesArgs) -> Result<()> {
+    let cfg = Config::load()?;
+    let owner = match args.owner {
+        Some(owner) => Pubkey::from_str(&owner)?,
+        None => signer(global, &cfg)?.address(),
+    };
+    portfolio_flow(&owner, false).await?;
+    Ok(())
+}
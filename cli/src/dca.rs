@@ -0,0 +1,377 @@
+// cli/src/dca.rs
+use crate::{confirm, signer, Global, Pair};
+use chrono::{DateTime, Utc};
+use clap::{Args, Subcommand, ValueEnum};
+use jupiter_sdk::{
+    cancel_recurring_flow, emit, get_prices, mint_decimals, mint_label, preview_recurring_order, price_recurring_flow,
+    progress, recurring_with_preview, rpc_client, try_http_client, ui_amount, withdraw_flow, Config, JupiterClient,
+    PriceRecurringParams, RecurringOrder, RecurringOrderParams, RecurringOrderStatus, RecurringPreview, RecurringType,
+    Result, TokenAmount, TxSigner, Wallet, WithdrawParams, WithdrawSide, USDC_MINT,
+};
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use std::time::Duration;
+
+#[derive(Args, Debug)]
+pub struct DcaArgs {
+    #[command(subcommand)]
+    command: DcaCommand,
+}
+
+#[derive(Subcommand, Debug)]
+enum DcaCommand {
+    /// Create a time- or price-based recurring order, after showing its schedule
+    Create(CreateArgs),
+    /// The wallet's open recurring orders, or past ones with --history
+    List(ListArgs),
+    /// Close an order; what it holds goes back to the wallet
+    Cancel(CancelArgs),
+    /// Take input or received output out of an open price-based order
+    Withdraw(WithdrawArgs),
+}
+
+pub async fn run(global: &Global, args: DcaArgs) -> Result<()> {
+    match args.command {
+        DcaCommand::Create(args) => create(global, args).await,
+        DcaCommand::List(args) => list(global, args).await,
+        DcaCommand::Cancel(args) => cancel(global, args).await,
+        DcaCommand::Withdraw(args) => withdraw(global, args).await,
+    }
+}
+
+/// The order `key` among the signer's open ones, which also tells its strategy and mints
+async fn open_order(cfg: &Config, wallet: &Wallet, key: &str) -> Result<RecurringOrder> {
+    let user = wallet.address().to_string();
+    let orders = JupiterClient::new(cfg)?.recurring().get_orders(&user, RecurringOrderStatus::Active).await?;
+    orders
+        .into_iter()
+        .find(|order| order.order_key == key)
+        .ok_or_else(|| anyhow::anyhow!("{user} has no open recurring order {key}").into())
+}
+
+// ─────────────────── create ───────────────────
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum Strategy {
+    /// Spend the same amount every interval
+    Time,
+    /// Buy whatever raises the position's USD value by --increment every interval
+    Price,
+}
+
+#[derive(Args, Debug)]
+struct CreateArgs {
+    #[command(flatten)]
+    pair: Pair,
+    /// Amount to spend (time) or deposit (price) in whole tokens, e.g. 100
+    #[arg(long)]
+    amount: String,
+    #[arg(long, value_enum, default_value_t = Strategy::Time)]
+    strategy: Strategy,
+    /// Number of orders to split the amount into (time)
+    #[arg(long, default_value_t = 2)]
+    orders: u64,
+    /// Time between orders, e.g. 1d, 6h or 45m
+    #[arg(long, default_value = "1d")]
+    interval: String,
+    /// Lower price bound; orders priced below it are skipped (time)
+    #[arg(long)]
+    min_price: Option<f64>,
+    /// Upper price bound; orders priced above it are skipped (time)
+    #[arg(long)]
+    max_price: Option<f64>,
+    /// USD the position should grow by each interval, e.g. 10 (price)
+    #[arg(long, required_if_eq("strategy", "price"))]
+    increment: Option<String>,
+    /// Delay the first order, e.g. 2h; it runs right away otherwise
+    #[arg(long, value_parser = humantime::parse_duration)]
+    start_in: Option<Duration>,
+    /// Sign and send without asking
+    #[arg(long, short)]
+    yes: bool,
+}
+
+async fn create(global: &Global, args: CreateArgs) -> Result<()> {
+    let cfg = Config::load()?;
+    let wallet = signer(global, &cfg)?;
+    let rpc = rpc_client(&cfg);
+
+    let (input_mint, output_mint) = args.pair.mints().await?;
+    let amount = TokenAmount::from_ui(&args.amount, mint_decimals(&rpc, &input_mint).await?)?;
+    let start_at = match args.start_in {
+        Some(after) => Some(Utc::now() + chrono::Duration::from_std(after).map_err(anyhow::Error::from)?),
+        None => None,
+    };
+
+    if args.strategy == Strategy::Time {
+        let mut params = RecurringOrderParams {
+            input_mint,
+            output_mint,
+            in_amount: amount,
+            number_of_orders: args.orders,
+            ..RecurringOrderParams::default()
+        }
+        .with_interval(&args.interval)?
+        .with_price_bounds(args.min_price, args.max_price);
+        params.start_at = start_at;
+        let preview = preview_recurring_order(&try_http_client()?, &params).await?;
+        progress(TimeSchedule { params: &params, preview: &preview });
+
+        if !confirm("Sign and create this recurring order?", args.yes)? {
+            progress("Cancelled; nothing was signed.");
+            return Ok(());
+        }
+        recurring_with_preview(&wallet, params, preview).await?;
+        return Ok(());
+    }
+
+    if args.min_price.is_some() || args.max_price.is_some() {
+        return Err(anyhow::anyhow!("--min-price and --max-price only apply to --strategy time").into());
+    }
+    let increment = args.increment.as_deref().expect("clap requires --increment for --strategy price");
+    let increment = TokenAmount::from_ui(increment, mint_decimals(&rpc, USDC_MINT).await?)?;
+    let params = PriceRecurringParams {
+        input_mint,
+        output_mint,
+        deposit_amount: amount,
+        increment_usdc_value: increment,
+        start_at,
+        ..PriceRecurringParams::default()
+    }
+    .with_interval(&args.interval)?;
+    params.validate()?;
+    let deposit_usd = get_prices(&try_http_client()?, &[&params.input_mint])
+        .await?
+        .get(&params.input_mint)
+        .map(|price| amount.ui_f64() * price.usd_price);
+    progress(PriceSchedule { params: &params, deposit_usd });
+
+    if !confirm("Sign and create this recurring order?", args.yes)? {
+        progress("Cancelled; nothing was signed.");
+        return Ok(());
+    }
+    price_recurring_flow(&wallet, params).await?;
+    Ok(())
+}
+
+fn at(time: DateTime<Utc>) -> impl Display {
+    time.format("%Y-%m-%d %H:%M UTC")
+}
+
+fn every(secs: u64) -> humantime::FormattedDuration {
+    humantime::format_duration(Duration::from_secs(secs))
+}
+
+/// A time-based order's amounts and the time of each order: the first three and
+/// the last, when there are more than five
+struct TimeSchedule<'a> {
+    params: &'a RecurringOrderParams,
+    preview: &'a RecurringPreview,
+}
+
+impl Display for TimeSchedule<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (p, preview) = (self.params, self.preview);
+        let usd = |value: Option<f64>| value.map_or(String::new(), |usd| format!(" (${usd:.2})"));
+
+        writeln!(
+            f,
+            "Buy {} with {} in {} orders, every {}",
+            mint_label(&p.output_mint),
+            ui_amount(&p.input_mint, preview.total_deposit),
+            preview.number_of_cycles,
+            every(preview.interval_secs),
+        )?;
+        let (per_cycle, deposit) =
+            (ui_amount(&p.input_mint, preview.amount_per_cycle), ui_amount(&p.input_mint, preview.total_deposit));
+        writeln!(f, "  {:<18} {per_cycle}{}", "each order", usd(preview.per_cycle_usd))?;
+        writeln!(f, "  {:<18} {deposit}{}", "deposit", usd(preview.total_usd))?;
+        if preview.remainder > 0 {
+            let remainder = ui_amount(&p.input_mint, preview.remainder);
+            writeln!(f, "  {:<18} {remainder} of the deposit doesn't divide into the orders", "left over")?;
+        }
+        if p.min_price.is_some() || p.max_price.is_some() {
+            let bound = |b: Option<f64>| b.map_or("-".to_string(), |b| b.to_string());
+            writeln!(f, "  {:<18} {} to {}", "price range", bound(p.min_price), bound(p.max_price))?;
+        }
+        let cycles = preview.number_of_cycles;
+        for i in 0..cycles {
+            if cycles > 5 && (3..cycles - 1).contains(&i) {
+                if i == 3 {
+                    writeln!(f, "  {:<18} …", "")?;
+                }
+                continue;
+            }
+            let time = preview.first_execution + chrono::Duration::seconds((preview.interval_secs * i) as i64);
+            writeln!(f, "  {:<18} {}", format!("order {}", i + 1), at(time))?;
+        }
+        write!(f, "  {:<18} {}", "completes", at(preview.estimated_completion))
+    }
+}
+
+/// A price-based order's deposit, target growth and how long the deposit
+/// lasts if prices stay where they are
+struct PriceSchedule<'a> {
+    params: &'a PriceRecurringParams,
+    deposit_usd: Option<f64>,
+}
+
+impl Display for PriceSchedule<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let p = self.params;
+        let increment = p.increment_usdc_value.ui_f64();
+        let first = p.start_at.unwrap_or_else(Utc::now);
+
+        writeln!(
+            f,
+            "Buy {} so the position grows by ${increment:.2} every {}",
+            mint_label(&p.output_mint),
+            every(p.interval_secs),
+        )?;
+        write!(f, "  {:<18} {}", "deposit", ui_amount(&p.input_mint, p.deposit_amount.raw))?;
+        match self.deposit_usd {
+            Some(usd) => writeln!(f, " (${usd:.2})")?,
+            None => writeln!(f)?,
+        }
+        write!(f, "  {:<18} {}", "first order", at(first))?;
+        if let Some(usd) = self.deposit_usd {
+            // value averaging buys more when the price drops, so this only holds at today's prices
+            let cycles = (usd / increment).floor().max(1.0) as u64;
+            // a tiny increment against a large deposit can put the end past what a date can hold
+            let last = p
+                .interval_secs
+                .checked_mul(cycles - 1)
+                .and_then(|secs| i64::try_from(secs).ok())
+                .and_then(chrono::Duration::try_seconds)
+                .and_then(|after| first.checked_add_signed(after))
+                .map_or_else(|| "-".to_string(), |last| at(last).to_string());
+            write!(f, "\n  {:<18} about {cycles} orders, until {last} at today's prices", "lasts")?;
+        }
+        Ok(())
+    }
+}
+
+// ─────────────────── list ───────────────────
+
+#[derive(Args, Debug)]
+struct ListArgs {
+    /// Completed and cancelled orders instead of open ones
+    #[arg(long)]
+    history: bool,
+}
+
+async fn list(global: &Global, args: ListArgs) -> Result<()> {
+    let cfg = Config::load()?;
+    let wallet = signer(global, &cfg)?;
+    let status = if args.history { RecurringOrderStatus::History } else { RecurringOrderStatus::Active };
+    let orders = JupiterClient::new(&cfg)?.recurring().get_orders(&wallet.address().to_string(), status).await?;
+    emit("recurring_orders", &orders, OrderTable { orders: &orders, history: args.history });
+    Ok(())
+}
+
+/// One row per order: account, strategy, input left, output not yet
+/// withdrawn, then cycles done and when the next one is due
+struct OrderTable<'a> {
+    orders: &'a [RecurringOrder],
+    history: bool,
+}
+
+impl Display for OrderTable<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.orders.is_empty() {
+            return write!(f, "No {} recurring orders", if self.history { "past" } else { "open" });
+        }
+        // UI amounts, trimmed to at most 6 decimals
+        let ui = |value: f64, mint: &str| {
+            let value = format!("{value:.6}");
+            format!("{} {}", value.trim_end_matches('0').trim_end_matches('.'), mint_label(mint))
+        };
+        write!(f, "{:<44} {:<5} {:>20} {:>20}  status", "order", "type", "input left", "to withdraw")?;
+        for order in self.orders {
+            let kind = match order.recurring_type() {
+                RecurringType::Time => "time",
+                RecurringType::Price => "price",
+            };
+            write!(
+                f,
+                "\n{:<44} {:<5} {:>20} {:>20}  {}",
+                order.order_key,
+                kind,
+                ui(order.remaining_in().max(0.0), &order.input_mint),
+                ui(order.unclaimed_out().max(0.0), &order.output_mint),
+                order.summary(),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+// ─────────────────── cancel ───────────────────
+
+#[derive(Args, Debug)]
+struct CancelArgs {
+    /// Order account, as shown by `jup dca list`
+    order: String,
+    /// Sign and send without asking
+    #[arg(long, short)]
+    yes: bool,
+}
+
+async fn cancel(global: &Global, args: CancelArgs) -> Result<()> {
+    let cfg = Config::load()?;
+    let wallet = signer(global, &cfg)?;
+    let order = open_order(&cfg, &wallet, &args.order).await?;
+    progress(&order);
+
+    if !confirm("Cancel this order and return its balances to the wallet?", args.yes)? {
+        progress("Nothing was cancelled.");
+        return Ok(());
+    }
+    cancel_recurring_flow(&wallet, &order.order_key, order.recurring_type()).await?;
+    Ok(())
+}
+
+// ─────────────────── withdraw ───────────────────
+
+#[derive(Args, Debug)]
+struct WithdrawArgs {
+    /// Order account, as shown by `jup dca list`
+    order: String,
+    /// `out` for the output bought so far, `in` for unspent input
+    #[arg(long, value_parser = WithdrawSide::from_str, default_value = "out")]
+    side: WithdrawSide,
+    /// Amount in whole tokens; everything on that side when not given
+    #[arg(long)]
+    amount: Option<String>,
+    /// Sign and send without asking
+    #[arg(long, short)]
+    yes: bool,
+}
+
+async fn withdraw(global: &Global, args: WithdrawArgs) -> Result<()> {
+    let cfg = Config::load()?;
+    let wallet = signer(global, &cfg)?;
+    let order = open_order(&cfg, &wallet, &args.order).await?;
+    if order.recurring_type() != RecurringType::Price {
+        let reason = "only price-based orders allow withdrawals; `jup dca cancel` closes a time-based one";
+        return Err(anyhow::anyhow!(reason).into());
+    }
+
+    let mint = match args.side {
+        WithdrawSide::Input => &order.input_mint,
+        WithdrawSide::Output => &order.output_mint,
+    };
+    let amount = match &args.amount {
+        Some(amount) => Some(TokenAmount::from_ui(amount, mint_decimals(&rpc_client(&cfg), mint).await?)?),
+        None => None,
+    };
+    let what = amount.map_or(format!("all {}", mint_label(mint)), |amount| ui_amount(mint, amount.raw));
+    progress(format!("Withdraw {what} from {}", order.order_key));
+
+    if !confirm("Sign and withdraw?", args.yes)? {
+        progress("Nothing was withdrawn.");
+        return Ok(());
+    }
+    withdraw_flow(&wallet, WithdrawParams { order: order.order_key, side: args.side, amount }).await?;
+    Ok(())
+}
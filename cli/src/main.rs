@@ -0,0 +1,181 @@
+// cli/src/main.rs
+//
+// `jup`: the library's flows as subcommands, configured by flags on top of the
+// same .env / jupiter.toml the examples read.
+//
+//     jup quote --amount 0.01
+//     jup --output json price EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v
+
+mod balances;
+mod dca;
+mod price;
+mod quote;
+mod swap;
+mod trigger;
+mod ultra;
+
+use anyhow::Result;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use jupiter_sdk::{
+    dry_run, read_keypair, try_http_client, try_payer, try_signer, Config, ConfigError, EmitErrorExt, TokenResolver,
+    TxSigner, Wallet, USDC_MINT, WSOL_MINT,
+};
+use solana_sdk::pubkey::Pubkey;
+use std::io::{BufRead, IsTerminal};
+use std::str::FromStr;
+
+#[derive(Parser, Debug)]
+#[command(name = "jup", version, about = "Swap, quote and place orders through the Jupiter APIs")]
+struct Cli {
+    #[command(flatten)]
+    global: Global,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Quote a swap without signing anything
+    Quote(quote::QuoteArgs),
+    /// Swap through /swap/v1: quote, build, sign, send and confirm
+    Swap(swap::SwapArgs),
+    /// Swap through Ultra, which builds, lands and confirms the transaction itself
+    Ultra(ultra::UltraArgs),
+    /// Place, list and cancel limit orders
+    Trigger(trigger::TriggerArgs),
+    /// Recurring (DCA) orders: create, list, cancel and withdraw
+    #[command(alias = "recurring")]
+    Dca(dca::DcaArgs),
+    /// USD prices for one or more mints
+    Price(price::PriceArgs),
+    /// The wallet's token balances, valued in USD
+    Balances(balances::BalancesArgs),
+}
+
+// ─────────────────── Global flags ───────────────────
+
+#[derive(Args, Debug)]
+struct Global {
+    /// Keypair file to sign with, instead of SECRET_KEY / MNEMONIC / KEYSTORE_PATH / KEYPAIR_PATH
+    #[arg(long, global = true, value_name = "PATH")]
+    wallet: Option<String>,
+    /// RPC endpoint, instead of RPC_URL
+    #[arg(long, global = true, value_name = "URL")]
+    rpc: Option<String>,
+    /// Output format, instead of OUTPUT
+    #[arg(long, global = true, value_enum)]
+    output: Option<Output>,
+    /// Build, sign and simulate, but send nothing (DRY_RUN=1)
+    #[arg(long, global = true)]
+    dry_run: bool,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum Output {
+    Text,
+    Json,
+}
+
+impl Global {
+    /// The flows read RPC_URL, OUTPUT and DRY_RUN themselves, so those flags are passed
+    /// on as the variables they replace. dotenv never overrides a variable
+    /// that is already set, so the flags win over .env and jupiter.toml.
+    /// Called before the runtime starts any threads.
+    fn export(&self) {
+        if let Some(rpc) = &self.rpc {
+            std::env::set_var("RPC_URL", rpc);
+        }
+        if let Some(output) = self.output {
+            std::env::set_var("OUTPUT", output.to_possible_value().expect("no skipped variants").get_name());
+        }
+        if self.dry_run {
+            std::env::set_var("DRY_RUN", "1");
+        }
+    }
+}
+
+/// The signer from `--wallet`, else the one configured like in the examples;
+/// `PAYER_*` sponsorship applies to either
+fn signer(global: &Global, cfg: &Config) -> Result<Wallet, ConfigError> {
+    let Some(path) = &global.wallet else {
+        return try_signer(cfg);
+    };
+    let wallet = read_keypair(path)
+        .map(Wallet::Keypair)
+        .map_err(|e| ConfigError::Invalid { var: "--wallet", reason: format!("{path}: {e}") })?;
+    match try_payer()? {
+        Some(payer) if payer.address() != wallet.address() => Ok(Wallet::Sponsored { user: Box::new(wallet), payer }),
+        _ => Ok(wallet),
+    }
+}
+
+// ─────────────────── Shared arguments ───────────────────
+
+/// What to sell and what to buy, for the commands that trade a pair
+#[derive(Args, Debug)]
+struct Pair {
+    /// Token to sell: a symbol such as JUP, or its mint
+    #[arg(long, default_value = WSOL_MINT)]
+    from: String,
+    /// Token to buy: a symbol such as JUP, or its mint
+    #[arg(long, default_value = USDC_MINT)]
+    to: String,
+}
+
+impl Pair {
+    /// (input mint, output mint); symbols are looked up in the verified token
+    /// list, which is only fetched when one is given
+    async fn mints(&self) -> jupiter_sdk::Result<(String, String)> {
+        if Pubkey::from_str(&self.from).is_ok() && Pubkey::from_str(&self.to).is_ok() {
+            return Ok((self.from.clone(), self.to.clone()));
+        }
+        let resolver = TokenResolver::new(try_http_client()?).await?;
+        Ok((resolver.resolve(&self.from).await?, resolver.resolve(&self.to).await?))
+    }
+}
+
+/// `--slippage 0.5` or `0.5%`: percent of the quoted amount, as basis points
+fn parse_slippage(s: &str) -> Result<u64, String> {
+    let pct: f64 = s.trim().trim_end_matches('%').parse().map_err(|_| format!("{s:?} is not a percentage"))?;
+    if !(0.0..=100.0).contains(&pct) {
+        return Err(format!("{s:?} is not between 0% and 100%"));
+    }
+    Ok((pct * 100.0).round() as u64)
+}
+
+/// Ask `question` on the terminal; `yes` (from `--yes`) and dry runs, which
+/// send nothing, skip the prompt. Without a terminal to ask on, `--yes` is required.
+fn confirm(question: &str, yes: bool) -> Result<bool> {
+    if yes || dry_run() {
+        return Ok(true);
+    }
+    if !std::io::stdin().is_terminal() {
+        anyhow::bail!("no terminal to confirm on; pass --yes to go ahead without asking");
+    }
+    // stderr, so the prompt doesn't end up in piped output
+    eprint!("{question} [y/N] ");
+    let mut answer = String::new();
+    std::io::stdin().lock().read_line(&mut answer)?;
+    Ok(matches!(answer.trim().to_ascii_lowercase().as_str(), "y" | "yes"))
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    cli.global.export();
+    let runtime = tokio::runtime::Builder::new_multi_thread().enable_all().build()?;
+    runtime.block_on(run(cli)).or_emit()?;
+    Ok(())
+}
+
+async fn run(cli: Cli) -> jupiter_sdk::Result<()> {
+    let global = &cli.global;
+    match cli.command {
+        Command::Quote(args) => quote::run(args).await,
+        Command::Swap(args) => swap::run(global, args).await,
+        Command::Ultra(args) => ultra::run(global, args).await,
+        Command::Trigger(args) => trigger::run(global, args).await,
+        Command::Dca(args) => dca::run(global, args).await,
+        Command::Price(args) => price::run(args).await,
+        Command::Balances(args) => balances::run(global, args).await,
+    }
+}
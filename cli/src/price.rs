@@ -0,0 +1,24 @@
+// cli/src/price.rs
+use clap::Args;
+use jupiter_sdk::{emit, get_prices, mint_label, try_http_client, Config, Result};
+use std::fmt::Write;
+
+#[derive(Args, Debug)]
+pub struct PriceArgs {
+    /// Mints to price
+    #[arg(required = true)]
+    mints: Vec<String>,
+}
+
+pub async fn run(args: PriceArgs) -> Result<()> {
+    Config::load()?;
+    let prices = get_prices(&try_http_client()?, &args.mints).await?;
+
+    let mut text = String::new();
+    for mint in &args.mints {
+        let usd = prices.get(mint).map(|p| format!("{:.6}", p.usd_price)).unwrap_or_else(|| "no price".into());
+        let _ = writeln!(text, "{:<12} {usd:>16}  {mint}", mint_label(mint));
+    }
+    emit("prices", &prices, text.trim_end());
+    Ok(())
+}
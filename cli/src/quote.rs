@@ -0,0 +1,109 @@
+// cli/src/quote.rs
+use crate::{parse_slippage, Pair};
+use clap::Args;
+use jupiter_sdk::{
+    emit, load_decimals, mint_decimals, output_format, rpc_client, ui_amount, Config, JupiterClient, OutputFormat,
+    QuoteResponse, Result, SwapParams, TokenAmount,
+};
+use std::fmt::{self, Display};
+
+#[derive(Args, Debug)]
+pub struct QuoteArgs {
+    #[command(flatten)]
+    pair: Pair,
+    /// Amount of the input mint in whole tokens, e.g. 0.01
+    #[arg(long)]
+    amount: String,
+    /// Slippage tolerance in percent, e.g. 0.5; SLIPPAGE_BPS when not given
+    #[arg(long, value_parser = parse_slippage)]
+    slippage: Option<u64>,
+    /// Print the quote as the API returned it, pretty-printed JSON
+    #[arg(long)]
+    json: bool,
+}
+
+pub async fn run(args: QuoteArgs) -> Result<()> {
+    let cfg = Config::load()?;
+    let rpc = rpc_client(&cfg);
+    let (input_mint, output_mint) = args.pair.mints().await?;
+    let amount = TokenAmount::from_ui(&args.amount, mint_decimals(&rpc, &input_mint).await?)?;
+    let params = SwapParams {
+        input_mint,
+        output_mint,
+        amount,
+        slippage_bps: args.slippage.unwrap_or(cfg.slippage_bps),
+        ..SwapParams::default()
+    };
+    let quote = JupiterClient::new(&cfg)?.swap().quote(&params).await?;
+
+    // OUTPUT=json already prints the quote, as a `quote` event
+    if args.json && output_format() == OutputFormat::Text {
+        println!("{}", serde_json::to_string_pretty(&quote)?);
+        return Ok(());
+    }
+    let mut mints = vec![quote.inputMint.as_str(), quote.outputMint.as_str()];
+    for step in &quote.routePlan {
+        let leg = &step.swap_info;
+        mints.extend([leg.input_mint.as_str(), leg.output_mint.as_str()]);
+        mints.extend(leg.fee_mint());
+    }
+    mints.sort_unstable();
+    mints.dedup();
+    load_decimals(&rpc, &mints).await?;
+
+    emit("quote", &quote, RouteTable(&quote));
+    Ok(())
+}
+
+/// A quote as one row per route step (DEX, share of the previous step's
+/// output, amounts, venue fee) followed by the totals
+struct RouteTable<'a>(&'a QuoteResponse);
+
+impl Display for RouteTable<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let q = self.0;
+        let raw = |amount: &str| amount.parse::<u64>().unwrap_or(0);
+        // ExactOut fixes the output, so the slippage bound and the fee are on the input side
+        let exact_out = q.swapMode == "ExactOut";
+        let fee_mint = if exact_out { &q.inputMint } else { &q.outputMint };
+
+        writeln!(
+            f,
+            "{} → {} in {} step{}",
+            ui_amount(&q.inputMint, raw(&q.inAmount)),
+            ui_amount(&q.outputMint, raw(&q.outAmount)),
+            q.routePlan.len(),
+            if q.routePlan.len() == 1 { "" } else { "s" },
+        )?;
+        writeln!(f)?;
+        writeln!(f, "{:<3} {:<18} {:>5}  {:>24} {:>24} {:>20}", "#", "DEX", "share", "in", "out", "fee")?;
+        for (i, step) in q.routePlan.iter().enumerate() {
+            let leg = &step.swap_info;
+            let fee = match (leg.fee_amount(), leg.fee_mint()) {
+                (Some(amount), Some(mint)) => ui_amount(mint, amount),
+                _ => "-".to_string(),
+            };
+            writeln!(
+                f,
+                "{:<3} {:<18} {:>4}%  {:>24} {:>24} {:>20}",
+                i + 1,
+                leg.label,
+                step.percent,
+                ui_amount(&leg.input_mint, raw(&leg.in_amount)),
+                ui_amount(&leg.output_mint, raw(&leg.out_amount)),
+                fee,
+            )?;
+        }
+        writeln!(f)?;
+        writeln!(f, "{:<18} {:.4}%", "price impact", q.price_impact_pct())?;
+        if let Some((amount, bps)) = q.platform_fee() {
+            writeln!(f, "{:<18} {} ({bps} bps)", "platform fee", ui_amount(fee_mint, amount))?;
+        }
+        writeln!(f, "{:<18} {} bps", "slippage", q.slippageBps)?;
+        if exact_out {
+            write!(f, "{:<18} {}", "maximum sent", ui_amount(&q.inputMint, raw(&q.otherAmountThreshold)))
+        } else {
+            write!(f, "{:<18} {}", "minimum received", ui_amount(&q.outputMint, raw(&q.otherAmountThreshold)))
+        }
+    }
+}
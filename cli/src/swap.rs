@@ -0,0 +1,104 @@
+// cli/src/swap.rs
+use crate::{confirm, parse_slippage, signer, Global, Pair};
+use clap::Args;
+use jupiter_sdk::{
+    get_prices, load_decimals, mint_decimals, progress, rpc_client, swap_quote, swap_with_quote, try_http_client,
+    ui_amount, Config, Price, QuoteResponse, Result, SwapParams, TokenAmount,
+};
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+
+#[derive(Args, Debug)]
+pub struct SwapArgs {
+    #[command(flatten)]
+    pair: Pair,
+    /// Amount to sell in whole tokens, e.g. 1.5
+    #[arg(long)]
+    amount: String,
+    /// Slippage tolerance in percent, e.g. 0.5; SLIPPAGE_BPS when not given
+    #[arg(long, value_parser = parse_slippage)]
+    slippage: Option<u64>,
+    /// Sign and send without asking
+    #[arg(long, short)]
+    yes: bool,
+}
+
+pub async fn run(global: &Global, args: SwapArgs) -> Result<()> {
+    let cfg = Config::load()?;
+    let wallet = signer(global, &cfg)?;
+    let rpc = rpc_client(&cfg);
+
+    let (input_mint, output_mint) = args.pair.mints().await?;
+    let amount = TokenAmount::from_ui(&args.amount, mint_decimals(&rpc, &input_mint).await?)?;
+    let params = SwapParams {
+        input_mint,
+        output_mint,
+        amount,
+        slippage_bps: args.slippage.unwrap_or(cfg.slippage_bps),
+        ..SwapParams::default()
+    };
+    let quote = swap_quote(&params).await?;
+
+    let mut mints = vec![quote.outputMint.as_str()];
+    mints.extend(quote.routePlan.iter().filter_map(|step| step.swap_info.fee_mint()));
+    load_decimals(&rpc, &mints).await?;
+    let prices = get_prices(&try_http_client()?, &[&quote.inputMint, &quote.outputMint]).await?;
+    progress(Summary { quote: &quote, prices: &prices });
+
+    if !confirm("Sign and send this swap?", args.yes)? {
+        progress("Cancelled; nothing was signed.");
+        return Ok(());
+    }
+    swap_with_quote(&wallet, &quote).await?;
+    Ok(())
+}
+
+/// What the user is asked to confirm: amounts and their USD value, route,
+/// price impact, fees and the slippage bound
+struct Summary<'a> {
+    quote: &'a QuoteResponse,
+    prices: &'a HashMap<String, Price>,
+}
+
+impl Summary<'_> {
+    fn usd(&self, mint: &str, raw: &str) -> String {
+        let value = |price: &Price, raw: u64| TokenAmount::new(raw, price.decimals).ui_f64() * price.usd_price;
+        match (self.prices.get(mint), raw.parse::<u64>()) {
+            (Some(price), Ok(raw)) => format!("${:.2}", value(price, raw)),
+            _ => "$?".to_string(),
+        }
+    }
+}
+
+impl Display for Summary<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let q = self.quote;
+        let raw = |amount: &str| amount.parse::<u64>().unwrap_or(0);
+        let route: Vec<String> =
+            q.routePlan.iter().map(|step| format!("{} {}%", step.swap_info.label, step.percent)).collect();
+        let venue_fees: Vec<String> = q
+            .routePlan
+            .iter()
+            .filter_map(|step| Some(ui_amount(step.swap_info.fee_mint()?, step.swap_info.fee_amount()?)))
+            .collect();
+
+        writeln!(
+            f,
+            "Swap {} for {}",
+            ui_amount(&q.inputMint, raw(&q.inAmount)),
+            ui_amount(&q.outputMint, raw(&q.outAmount)),
+        )?;
+        let (in_usd, out_usd) = (self.usd(&q.inputMint, &q.inAmount), self.usd(&q.outputMint, &q.outAmount));
+        writeln!(f, "  {:<18} {in_usd} → {out_usd}", "value")?;
+        writeln!(f, "  {:<18} {}", "route", route.join(", "))?;
+        writeln!(f, "  {:<18} {:.4}%", "price impact", q.price_impact_pct())?;
+        if !venue_fees.is_empty() {
+            writeln!(f, "  {:<18} {}", "venue fees", venue_fees.join(", "))?;
+        }
+        if let Some((amount, bps)) = q.platform_fee() {
+            writeln!(f, "  {:<18} {} ({bps} bps)", "platform fee", ui_amount(&q.outputMint, amount))?;
+        }
+        writeln!(f, "  {:<18} {}%", "slippage", q.slippageBps as f64 / 100.0)?;
+        write!(f, "  {:<18} {}", "minimum received", ui_amount(&q.outputMint, raw(&q.otherAmountThreshold)))
+    }
+}
@@ -0,0 +1,294 @@
+// cli/src/trigger.rs
+use crate::{confirm, signer, Global, Pair};
+use chrono::Utc;
+use clap::{Args, Subcommand};
+use jupiter_sdk::{
+    cancel_trigger_orders_flow, emit, get_prices, mint_decimals, mint_label, progress, rpc_client, trigger_with_params,
+    try_http_client, ui_amount, Config, JupiterClient, Result, TokenAmount, TriggerOrder, TriggerOrderStatus,
+    TriggerParams, TxSigner,
+};
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+#[derive(Args, Debug)]
+pub struct TriggerArgs {
+    #[command(subcommand)]
+    command: TriggerCommand,
+}
+
+#[derive(Subcommand, Debug)]
+enum TriggerCommand {
+    /// Place a limit order at a price, or at a percentage above or below the market
+    Create(CreateArgs),
+    /// The wallet's open orders, or past ones with --history
+    List(ListArgs),
+    /// Cancel one or more orders by their order account
+    Cancel(CancelArgs),
+    /// Cancel every open order of the wallet
+    CancelAll(CancelAllArgs),
+}
+
+pub async fn run(global: &Global, args: TriggerArgs) -> Result<()> {
+    match args.command {
+        TriggerCommand::Create(args) => create(global, args).await,
+        TriggerCommand::List(args) => list(global, args).await,
+        TriggerCommand::Cancel(args) => cancel(global, args).await,
+        TriggerCommand::CancelAll(args) => cancel_all(global, args).await,
+    }
+}
+
+// ─────────────────── create ───────────────────
+
+#[derive(Args, Debug)]
+struct CreateArgs {
+    #[command(flatten)]
+    pair: Pair,
+    /// Amount to sell in whole tokens, e.g. 1.5
+    #[arg(long)]
+    amount: String,
+    /// Limit price in output tokens per input token (e.g. 180), or relative to the market (e.g. +5%, -2.5%)
+    #[arg(
+        long,
+        value_parser = LimitPrice::from_str,
+        allow_hyphen_values = true,
+        required_unless_present = "receive"
+    )]
+    sell_at: Option<LimitPrice>,
+    /// Output to receive in whole tokens, instead of --sell-at
+    #[arg(long, conflicts_with = "sell_at")]
+    receive: Option<String>,
+    /// Cancel the order if still open after this long, e.g. 12h or 7d
+    #[arg(long, value_parser = humantime::parse_duration)]
+    expires: Option<std::time::Duration>,
+    /// Sign and send without asking
+    #[arg(long, short)]
+    yes: bool,
+}
+
+/// `--sell-at`: output per input, either as is or as a percentage off the market price
+#[derive(Debug, Clone, Copy)]
+enum LimitPrice {
+    Absolute(f64),
+    Relative(f64),
+}
+
+impl FromStr for LimitPrice {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let number = |n: &str| n.parse::<f64>().ok().filter(|n| n.is_finite());
+        match s.strip_suffix('%') {
+            Some(pct) => match number(pct) {
+                Some(pct) if pct > -100.0 => Ok(LimitPrice::Relative(pct)),
+                _ => Err(format!("{s:?} is not a percentage above -100%")),
+            },
+            None => match number(s) {
+                Some(price) if price > 0.0 => Ok(LimitPrice::Absolute(price)),
+                _ => Err(format!("{s:?} is not a positive price or a percentage such as +5%")),
+            },
+        }
+    }
+}
+
+async fn create(global: &Global, args: CreateArgs) -> Result<()> {
+    let cfg = Config::load()?;
+    let wallet = signer(global, &cfg)?;
+    let rpc = rpc_client(&cfg);
+
+    let (input_mint, output_mint) = args.pair.mints().await?;
+    let making = TokenAmount::from_ui(&args.amount, mint_decimals(&rpc, &input_mint).await?)?;
+    let out_decimals = mint_decimals(&rpc, &output_mint).await?;
+
+    // The market price is shown either way, so the user sees how far off the limit is
+    let prices = get_prices(&try_http_client()?, &[&input_mint, &output_mint]).await?;
+    let market = match (prices.get(&input_mint), prices.get(&output_mint)) {
+        (Some(input), Some(output)) if output.usd_price > 0.0 => Some(input.usd_price / output.usd_price),
+        _ => None,
+    };
+    let taking = match (args.receive, args.sell_at) {
+        (Some(receive), _) => TokenAmount::from_ui(&receive, out_decimals)?,
+        (None, Some(limit)) => {
+            let price = match limit {
+                LimitPrice::Absolute(price) => price,
+                LimitPrice::Relative(pct) => {
+                    let market = market.ok_or_else(|| {
+                        anyhow::anyhow!("no market price for {input_mint} / {output_mint}; give --sell-at as a price")
+                    })?;
+                    market * (1.0 + pct / 100.0)
+                }
+            };
+            let raw = (making.ui_f64() * price * 10f64.powi(out_decimals as i32)).round() as u64;
+            TokenAmount::new(raw, out_decimals)
+        }
+        (None, None) => unreachable!("clap requires --sell-at or --receive"),
+    };
+    if taking.raw == 0 {
+        return Err(anyhow::anyhow!("the order would receive nothing; raise --sell-at or --receive").into());
+    }
+
+    let expired_at = match args.expires {
+        Some(after) => Some(Utc::now() + chrono::Duration::from_std(after).map_err(anyhow::Error::from)?),
+        None => None,
+    };
+    let params = TriggerParams {
+        input_mint,
+        output_mint,
+        making_amount: making,
+        taking_amount: taking,
+        expired_at,
+    };
+    progress(OrderSummary { params: &params, market });
+
+    if !confirm("Sign and place this order?", args.yes)? {
+        progress("Cancelled; nothing was signed.");
+        return Ok(());
+    }
+    trigger_with_params(&wallet, params).await?;
+    Ok(())
+}
+
+/// What the user is asked to confirm before placing an order
+struct OrderSummary<'a> {
+    params: &'a TriggerParams,
+    /// Output per input at the current USD prices
+    market: Option<f64>,
+}
+
+impl Display for OrderSummary<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let p = self.params;
+        let price = p.taking_amount.ui_f64() / p.making_amount.ui_f64();
+        let (input, output) = (mint_label(&p.input_mint), mint_label(&p.output_mint));
+
+        writeln!(
+            f,
+            "Sell {} for {}",
+            ui_amount(&p.input_mint, p.making_amount.raw),
+            ui_amount(&p.output_mint, p.taking_amount.raw),
+        )?;
+        write!(f, "  {:<18} 1 {input} = {price:.6} {output}", "limit price")?;
+        match self.market {
+            Some(market) => writeln!(f, " ({:+.2}% vs market {market:.6})", (price / market - 1.0) * 100.0)?,
+            None => writeln!(f)?,
+        }
+        match p.expired_at {
+            Some(at) => write!(f, "  {:<18} {}", "expires", at.format("%Y-%m-%d %H:%M UTC")),
+            None => write!(f, "  {:<18} never", "expires"),
+        }
+    }
+}
+
+// ─────────────────── list ───────────────────
+
+#[derive(Args, Debug)]
+struct ListArgs {
+    /// Filled, cancelled and expired orders instead of open ones
+    #[arg(long)]
+    history: bool,
+}
+
+async fn list(global: &Global, args: ListArgs) -> Result<()> {
+    let cfg = Config::load()?;
+    let wallet = signer(global, &cfg)?;
+    let status = if args.history { TriggerOrderStatus::History } else { TriggerOrderStatus::Active };
+    let orders = JupiterClient::new(&cfg)?.trigger().get_orders(&wallet.address().to_string(), status).await?;
+    emit("trigger_orders", &orders, OrderTable { orders: &orders, history: args.history });
+    Ok(())
+}
+
+/// One row per order: account, what is left to sell, what it buys, the limit
+/// price and the expiry (or the final status, for past orders)
+struct OrderTable<'a> {
+    orders: &'a [TriggerOrder],
+    history: bool,
+}
+
+impl Display for OrderTable<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.orders.is_empty() {
+            return write!(f, "No {} orders", if self.history { "past" } else { "open" });
+        }
+        let last = if self.history { "status" } else { "expires" };
+        write!(f, "{:<44} {:>24} {:>24} {:>14}  {last}", "order", "selling", "for", "price")?;
+        for order in self.orders {
+            let (input, output) = (mint_label(&order.input_mint), mint_label(&order.output_mint));
+            let (selling, buying) = if self.history {
+                (&order.making_amount, &order.taking_amount)
+            } else {
+                (&order.remaining_making_amount, &order.remaining_taking_amount)
+            };
+            let price = order.limit_price().map_or("-".to_string(), |price| format!("{price:.6}"));
+            let last = match (self.history, order.expires_at()) {
+                (true, _) => order.status.clone(),
+                (false, Some(at)) => at.format("%Y-%m-%d %H:%M").to_string(),
+                (false, None) => "never".to_string(),
+            };
+            write!(
+                f,
+                "\n{:<44} {:>24} {:>24} {:>14}  {last}",
+                order.order_key,
+                format!("{selling} {input}"),
+                format!("{buying} {output}"),
+                price,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+// ─────────────────── cancel / cancel-all ───────────────────
+
+#[derive(Args, Debug)]
+struct CancelArgs {
+    /// Order account(s), as shown by `jup trigger list`
+    #[arg(required = true)]
+    orders: Vec<String>,
+    /// Sign and send without asking
+    #[arg(long, short)]
+    yes: bool,
+}
+
+async fn cancel(global: &Global, args: CancelArgs) -> Result<()> {
+    let cfg = Config::load()?;
+    let wallet = signer(global, &cfg)?;
+    let question = match args.orders.as_slice() {
+        [order] => format!("Cancel order {order}?"),
+        orders => format!("Cancel {} orders?", orders.len()),
+    };
+    if !confirm(&question, args.yes)? {
+        progress("Nothing was cancelled.");
+        return Ok(());
+    }
+    cancel_trigger_orders_flow(&wallet, &args.orders).await?;
+    Ok(())
+}
+
+#[derive(Args, Debug)]
+struct CancelAllArgs {
+    /// Sign and send without asking
+    #[arg(long, short)]
+    yes: bool,
+}
+
+async fn cancel_all(global: &Global, args: CancelAllArgs) -> Result<()> {
+    let cfg = Config::load()?;
+    let wallet = signer(global, &cfg)?;
+    let orders = JupiterClient::new(&cfg)?
+        .trigger()
+        .get_orders(&wallet.address().to_string(), TriggerOrderStatus::Active)
+        .await?;
+    if orders.is_empty() {
+        progress("No open orders to cancel.");
+        return Ok(());
+    }
+    progress(OrderTable { orders: &orders, history: false });
+    if !confirm(&format!("Cancel all {} open orders?", orders.len()), args.yes)? {
+        progress("Nothing was cancelled.");
+        return Ok(());
+    }
+    // exactly the orders shown, not whatever is open by the time the cancel lands
+    let keys: Vec<String> = orders.into_iter().map(|order| order.order_key).collect();
+    cancel_trigger_orders_flow(&wallet, &keys).await?;
+    Ok(())
+}
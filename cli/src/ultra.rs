@@ -0,0 +1,44 @@
+// cli/src/ultra.rs
+use crate::{confirm, signer, Global, Pair};
+use clap::Args;
+use jupiter_sdk::{
+    get_prices, mint_decimals, mint_label, progress, rpc_client, try_http_client, ultra_with_params, Config, Result,
+    TokenAmount, UltraParams,
+};
+
+#[derive(Args, Debug)]
+pub struct UltraArgs {
+    #[command(flatten)]
+    pair: Pair,
+    /// Amount to sell in whole tokens, e.g. 1.5
+    #[arg(long)]
+    amount: String,
+    /// Sign and send without asking
+    #[arg(long, short)]
+    yes: bool,
+}
+
+pub async fn run(global: &Global, args: UltraArgs) -> Result<()> {
+    let cfg = Config::load()?;
+    let wallet = signer(global, &cfg)?;
+
+    let (input_mint, output_mint) = args.pair.mints().await?;
+    let amount = TokenAmount::from_ui(&args.amount, mint_decimals(&rpc_client(&cfg), &input_mint).await?)?;
+    // Ultra prices the order when it builds the transaction, so only the input side is known up front
+    let value = get_prices(&try_http_client()?, &[&input_mint])
+        .await?
+        .get(&input_mint)
+        .map_or(String::new(), |price| format!(" (${:.2})", amount.ui_f64() * price.usd_price));
+    progress(format!(
+        "Swap {amount} {}{value} for {} through Ultra",
+        mint_label(&input_mint),
+        mint_label(&output_mint),
+    ));
+
+    if !confirm("Sign and send this swap?", args.yes)? {
+        progress("Cancelled; nothing was signed.");
+        return Ok(());
+    }
+    ultra_with_params(&wallet, UltraParams { input_mint, output_mint, amount }).await?;
+    Ok(())
+}
@@ -0,0 +1,109 @@
+// common/benches/transactions.rs
+//
+// The per-swap work between a Jupiter response and the RPC send: decoding the
+// transaction, converting /swap-instructions output, compiling the message and
+// signing it. Inputs are the fixtures the response tests decode.
+//
+//     cargo bench -p jupiter-sdk
+
+use jupiter_sdk::{sign_versioned_tx, SwapInstructionResponse, SwapResponse, Wallet};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use serde::de::DeserializeOwned;
+use solana_sdk::address_lookup_table_account::AddressLookupTableAccount;
+use solana_sdk::hash::Hash;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::message::{v0, VersionedMessage};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signature, Signer};
+use solana_sdk::transaction::VersionedTransaction;
+use std::str::FromStr;
+
+/// Wallet the fixtures were saved for
+const WALLET: &str = "EvJyF2AW5kq4M8ZryHqLdDTpPXc1dwxB7tK8yPP5cqgW";
+
+fn fixture<T: DeserializeOwned>(name: &str) -> T {
+    let path = format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"));
+    serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap()
+}
+
+/// The swap-instructions fixture with its wallet replaced by `user`
+fn instructions_for(user: &Pubkey) -> Vec<Instruction> {
+    let wallet = Pubkey::from_str(WALLET).unwrap();
+    let mut ix = fixture::<SwapInstructionResponse>("swap_instructions.json").instructions().unwrap();
+    for meta in ix.iter_mut().flat_map(|i| i.accounts.iter_mut()) {
+        if meta.pubkey == wallet {
+            meta.pubkey = *user;
+        }
+    }
+    ix
+}
+
+fn unsigned(payer: &Pubkey, ix: &[Instruction]) -> VersionedTransaction {
+    let message = v0::Message::try_compile(payer, ix, &[], Hash::new_unique()).unwrap();
+    let signatures = vec![Signature::default(); message.header.num_required_signatures as usize];
+    VersionedTransaction { signatures, message: VersionedMessage::V0(message) }
+}
+
+fn decode(c: &mut Criterion) {
+    let swap: SwapResponse = fixture("swap.json");
+    c.bench_function("decode swapTransaction (base64 + bincode)", |b| {
+        b.iter(|| {
+            let bytes = base64::decode(black_box(&swap.swap_transaction)).unwrap();
+            bincode::deserialize::<VersionedTransaction>(&bytes).unwrap()
+        })
+    });
+}
+
+fn convert(c: &mut Criterion) {
+    let resp: SwapInstructionResponse = fixture("swap_instructions.json");
+    c.bench_function("swap-instructions JSON -> Instruction", |b| {
+        b.iter(|| black_box(&resp).instructions().unwrap())
+    });
+}
+
+fn compile(c: &mut Criterion) {
+    let payer = Pubkey::from_str(WALLET).unwrap();
+    let ix = instructions_for(&payer);
+    let blockhash = Hash::new_unique();
+    c.bench_function("compile v0 message", |b| {
+        b.iter(|| v0::Message::try_compile(&payer, black_box(&ix), &[], blockhash).unwrap())
+    });
+
+    // every non-signer account behind one lookup table, as Jupiter routes usually are
+    let table = AddressLookupTableAccount {
+        key: Pubkey::new_unique(),
+        addresses: ix.iter().flat_map(|i| &i.accounts).filter(|a| !a.is_signer).map(|a| a.pubkey).collect(),
+    };
+    let tables = [table];
+    c.bench_function("compile v0 message with a lookup table", |b| {
+        b.iter(|| v0::Message::try_compile(&payer, black_box(&ix), &tables, blockhash).unwrap())
+    });
+}
+
+fn sign(c: &mut Criterion) {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    let user = Keypair::new();
+    let tx = unsigned(&user.pubkey(), &instructions_for(&user.pubkey()));
+    c.bench_function("sign_versioned_tx", |b| {
+        b.to_async(&rt).iter(|| async {
+            let mut tx = tx.clone();
+            sign_versioned_tx(&mut tx, &user).await.unwrap();
+            tx
+        })
+    });
+
+    let payer = Keypair::new();
+    let sponsored = unsigned(&payer.pubkey(), &instructions_for(&user.pubkey()));
+    let wallet = Wallet::Sponsored { user: Box::new(Wallet::Keypair(user)), payer };
+    c.bench_function("sign_versioned_tx (sponsored)", |b| {
+        b.to_async(&rt).iter(|| async {
+            let mut tx = sponsored.clone();
+            sign_versioned_tx(&mut tx, &wallet).await.unwrap();
+            tx
+        })
+    });
+}
+
+criterion_group!(benches, decode, convert, compile, sign);
+criterion_main!(benches);
@@ -0,0 +1,216 @@
+// common/src/amount.rs
+use crate::{mint_label, Error, Result, USDC_MINT, WSOL_MINT};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{OnceLock, RwLock};
+#[cfg(not(target_arch = "wasm32"))]
+use {solana_client::nonblocking::rpc_client::RpcClient, solana_sdk::pubkey::Pubkey, std::str::FromStr};
+
+// ─────────────────── UI amounts ───────────────────
+//
+// On-chain amounts are raw integers; a UI amount is raw / 10^decimals. The
+// conversions here are exact integer/string arithmetic, never f64, and use
+// no locale: "." as the decimal separator, no grouping, trailing zeros
+// trimmed. Mint decimals are cached for the process so Display impls can
+// format amounts without an RPC round trip.
+
+/// Decimals of the mints the examples use, so they never need a lookup
+fn known_decimals(mint: &str) -> Option<u8> {
+    match mint {
+        WSOL_MINT => Some(9),
+        USDC_MINT => Some(6),
+        "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB" => Some(6),
+        "JUPyiwrYJFskUPiHa7hkeR8VUtAeFoSYbKedZNsDvCN" => Some(6),
+        "27G8MtK7VtTcCHkpASjSDdkWWYfoqT6ggEuKidVJidD4" => Some(6),
+        _ => None,
+    }
+}
+
+fn decimals_cache() -> &'static RwLock<HashMap<String, u8>> {
+    static CACHE: OnceLock<RwLock<HashMap<String, u8>>> = OnceLock::new();
+    CACHE.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+/// Record `mint`'s decimals for later formatting ([`crate::check_mint`] does this for every mint it decodes)
+pub fn remember_decimals(mint: &str, decimals: u8) {
+    decimals_cache().write().unwrap().insert(mint.to_string(), decimals);
+}
+
+/// `mint`'s decimals if known or already looked up
+pub fn cached_decimals(mint: &str) -> Option<u8> {
+    known_decimals(mint).or_else(|| decimals_cache().read().unwrap().get(mint).copied())
+}
+
+/// `mint`'s decimals, from the cache or the mint account
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn mint_decimals(rpc: &RpcClient, mint: &str) -> Result<u8> {
+    if let Some(decimals) = cached_decimals(mint) {
+        return Ok(decimals);
+    }
+    let decimals = rpc.get_token_supply(&Pubkey::from_str(mint)?).await?.decimals;
+    remember_decimals(mint, decimals);
+    Ok(decimals)
+}
+
+/// Look up every mint not cached yet, so [`ui_amount`] can format them
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn load_decimals(rpc: &RpcClient, mints: &[impl AsRef<str>]) -> Result<()> {
+    for mint in mints {
+        mint_decimals(rpc, mint.as_ref()).await?;
+    }
+    Ok(())
+}
+
+/// `raw` as a decimal string: `format_ui_amount(1_500_000, 6) == "1.5"`
+pub fn format_ui_amount(raw: u64, decimals: u8) -> String {
+    if decimals == 0 {
+        return raw.to_string();
+    }
+    let digits = format!("{raw:0>width$}", width = decimals as usize + 1);
+    let (int, frac) = digits.split_at(digits.len() - decimals as usize);
+    let frac = frac.trim_end_matches('0');
+    if frac.is_empty() {
+        int.to_string()
+    } else {
+        format!("{int}.{frac}")
+    }
+}
+
+/// Decimal string to raw units: `parse_ui_amount("1.5", 6) == Ok(1_500_000)`.
+/// Rejects more fractional digits than the mint has rather than rounding.
+pub fn parse_ui_amount(ui: &str, decimals: u8) -> Result<u64> {
+    let ui = ui.trim();
+    let invalid = |reason: &str| Error::Invalid(format!("amount {ui:?}: {reason}"));
+    let (int, frac) = ui.split_once('.').unwrap_or((ui, ""));
+    if int.is_empty() && frac.is_empty() {
+        return Err(invalid("empty"));
+    }
+    if !int.chars().chain(frac.chars()).all(|c| c.is_ascii_digit()) {
+        return Err(invalid("expected digits with an optional '.'"));
+    }
+    if frac.len() > decimals as usize {
+        return Err(invalid(&format!("more than {decimals} decimal places")));
+    }
+    let digits = format!("{int}{frac:0<width$}", width = decimals as usize);
+    let digits = digits.trim_start_matches('0');
+    if digits.is_empty() {
+        return Ok(0);
+    }
+    digits.parse().map_err(|_| invalid("too large"))
+}
+
+/// `raw` of `mint` for display, e.g. "1.5 USDC"; "1500000 raw ABCD…WXYZ" when
+/// the decimals have not been looked up
+pub fn ui_amount(mint: &str, raw: u64) -> String {
+    match cached_decimals(mint) {
+        Some(decimals) => format!("{} {}", format_ui_amount(raw, decimals), mint_label(mint)),
+        None => format!("{raw} raw {}", mint_label(mint)),
+    }
+}
+
+// ─────────────────── Amount types ───────────────────
+//
+// Raw u64s are easy to mix up: lamports, token base units of different mints,
+// UI amounts. Flow params and results, ATA rent and fee figures carry these
+// instead, so passing a fee in lamports where a token amount is expected
+// doesn't compile, and adding amounts of different precision is caught at
+// runtime.
+
+/// SOL in lamports (10^-9 SOL); serializes as the bare number
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[serde(transparent)]
+pub struct Lamports(pub u64);
+
+impl Lamports {
+    pub const ZERO: Lamports = Lamports(0);
+    const DECIMALS: u8 = 9;
+
+    /// Parse a SOL amount such as "0.05"
+    pub fn from_sol(sol: &str) -> Result<Self> {
+        parse_ui_amount(sol, Self::DECIMALS).map(Lamports)
+    }
+
+    /// The amount in SOL, e.g. "0.05"
+    pub fn sol(self) -> String {
+        format_ui_amount(self.0, Self::DECIMALS)
+    }
+
+    pub fn checked_add(self, other: Lamports) -> Option<Lamports> {
+        self.0.checked_add(other.0).map(Lamports)
+    }
+
+    pub fn checked_sub(self, other: Lamports) -> Option<Lamports> {
+        self.0.checked_sub(other.0).map(Lamports)
+    }
+
+    pub fn checked_mul(self, n: u64) -> Option<Lamports> {
+        self.0.checked_mul(n).map(Lamports)
+    }
+
+    pub fn saturating_sub(self, other: Lamports) -> Lamports {
+        Lamports(self.0.saturating_sub(other.0))
+    }
+}
+
+impl fmt::Display for Lamports {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} SOL", self.sol())
+    }
+}
+
+/// A token amount in base units together with its mint's decimals
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct TokenAmount {
+    pub raw: u64,
+    pub decimals: u8,
+}
+
+impl TokenAmount {
+    pub fn new(raw: u64, decimals: u8) -> Self {
+        Self { raw, decimals }
+    }
+
+    /// Parse a UI amount such as "12.5" for a mint with `decimals`
+    pub fn from_ui(ui: &str, decimals: u8) -> Result<Self> {
+        parse_ui_amount(ui, decimals).map(|raw| Self { raw, decimals })
+    }
+
+    /// `raw` of `mint`, with decimals from the cache or the mint account
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn fetch(rpc: &RpcClient, mint: &str, raw: u64) -> Result<Self> {
+        Ok(Self { raw, decimals: mint_decimals(rpc, mint).await? })
+    }
+
+    /// The UI amount as an exact decimal string
+    pub fn ui(&self) -> String {
+        format_ui_amount(self.raw, self.decimals)
+    }
+
+    /// The UI amount as f64; lossy, for ratios and charts only
+    pub fn ui_f64(&self) -> f64 {
+        self.raw as f64 / 10f64.powi(self.decimals as i32)
+    }
+
+    /// `None` on overflow or when the decimals differ
+    pub fn checked_add(self, other: TokenAmount) -> Option<TokenAmount> {
+        if self.decimals != other.decimals {
+            return None;
+        }
+        self.raw.checked_add(other.raw).map(|raw| Self { raw, ..self })
+    }
+
+    /// `None` on underflow or when the decimals differ
+    pub fn checked_sub(self, other: TokenAmount) -> Option<TokenAmount> {
+        if self.decimals != other.decimals {
+            return None;
+        }
+        self.raw.checked_sub(other.raw).map(|raw| Self { raw, ..self })
+    }
+}
+
+impl fmt::Display for TokenAmount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.ui())
+    }
+}
@@ -0,0 +1,71 @@
+// common/src/api.rs
+//
+// Hosts and product paths of the Jupiter APIs. Nothing here touches the
+// environment or the network, so it builds for wasm32 as well; config.rs
+// layers the env / jupiter.toml overrides on top.
+
+// ─────────────────── API hosts ───────────────────
+
+/// Free, rate-limited host used when no API key is configured
+pub const JUPITER_API_URL: &str = "https://lite-api.jup.ag";
+
+/// Paid host; API keys are only honoured here
+pub const JUPITER_PRO_API_URL: &str = "https://api.jup.ag";
+
+/// Jupiter products whose root URL can be overridden one by one
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JupiterApi {
+    Swap,
+    Ultra,
+    Trigger,
+    Recurring,
+    Price,
+    Tokens,
+    Lend,
+}
+
+impl JupiterApi {
+    pub const ALL: [JupiterApi; 7] = [
+        JupiterApi::Swap,
+        JupiterApi::Ultra,
+        JupiterApi::Trigger,
+        JupiterApi::Recurring,
+        JupiterApi::Price,
+        JupiterApi::Tokens,
+        JupiterApi::Lend,
+    ];
+
+    /// Versioned path below the host
+    pub fn path(self) -> &'static str {
+        match self {
+            JupiterApi::Swap      => "swap/v1",
+            JupiterApi::Ultra     => "ultra/v1",
+            JupiterApi::Trigger   => "trigger/v1",
+            JupiterApi::Recurring => "recurring/v1",
+            JupiterApi::Price     => "price/v3",
+            JupiterApi::Tokens    => "tokens/v2",
+            JupiterApi::Lend      => "lend/v1",
+        }
+    }
+
+    /// Key in jupiter.toml's `[api_urls]` table
+    pub fn name(self) -> &'static str {
+        self.path().split('/').next().unwrap_or_default()
+    }
+
+    /// Env var holding this product's full root URL, e.g. a self-hosted router for Swap
+    pub fn env_var(self) -> &'static str {
+        match self {
+            JupiterApi::Swap      => "JUPITER_SWAP_URL",
+            JupiterApi::Ultra     => "JUPITER_ULTRA_URL",
+            JupiterApi::Trigger   => "JUPITER_TRIGGER_URL",
+            JupiterApi::Recurring => "JUPITER_RECURRING_URL",
+            JupiterApi::Price     => "JUPITER_PRICE_URL",
+            JupiterApi::Tokens    => "JUPITER_TOKENS_URL",
+            JupiterApi::Lend      => "JUPITER_LEND_URL",
+        }
+    }
+}
+
+/// Slippage used when neither `SLIPPAGE_BPS` nor jupiter.toml sets one
+pub const DEFAULT_SLIPPAGE_BPS: u64 = 50;
@@ -0,0 +1,139 @@
+// common/src/ata.rs
+use crate::referral::{TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID};
+use crate::{check_mint, mint_label, progress, Error, Lamports, Result, TokenAmount};
+use serde::Serialize;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey};
+use spl_associated_token_account::{
+    get_associated_token_address_with_program_id, instruction::create_associated_token_account_idempotent,
+};
+
+// ─────────────────── Associated token accounts ───────────────────
+//
+// Nearly every flow that sends tokens somewhere needs the recipient's ATA to
+// exist first. The create instruction used here is the idempotent one, so it
+// can be prepended unconditionally without failing when the account is
+// already there; `ensure_ata` skips it anyway to save the payer a few CU.
+//
+// Token-2022 mints live under a different program id, which changes the ATA
+// address, and may withhold a transfer fee from every transfer, so what lands
+// in the account can be less than what was sent.
+
+/// SPL Token account: 165 bytes
+const TOKEN_ACCOUNT_LEN: usize = 165;
+/// Token-2022 ATA: the base account, an account-type byte and the
+/// ImmutableOwner extension header the ATA program always adds
+const TOKEN_2022_ACCOUNT_LEN: usize = TOKEN_ACCOUNT_LEN + 1 + 4;
+
+/// `owner`'s ATA for `mint`, and what it takes to have it exist
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ata {
+    pub address: Pubkey,
+    pub owner: Pubkey,
+    pub mint: Pubkey,
+    pub token_program: Pubkey,
+    /// Idempotent create instruction; `None` when the account already exists
+    pub create_ix: Option<Instruction>,
+    /// What the payer locks up for rent if `create_ix` runs (0 if it exists)
+    pub rent: Lamports,
+}
+
+impl Ata {
+    pub fn exists(&self) -> bool {
+        self.create_ix.is_none()
+    }
+
+    pub fn is_token_2022(&self) -> bool {
+        self.token_program == TOKEN_2022_PROGRAM_ID
+    }
+}
+
+/// A transfer of `gross` split into the mint's transfer fee and what the recipient is credited
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReceivedAmount {
+    pub gross: TokenAmount,
+    /// Withheld by a Token-2022 transfer fee (0 for SPL Token mints)
+    pub transfer_fee: TokenAmount,
+    pub net: TokenAmount,
+}
+
+/// Address of `owner`'s ATA for `mint` under `token_program`
+pub fn ata_address(owner: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    get_associated_token_address_with_program_id(owner, mint, token_program)
+}
+
+/// Create `owner`'s ATA for `mint`, paid by `payer`; a no-op if it already exists
+pub fn create_ata_ix(payer: &Pubkey, owner: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Instruction {
+    create_associated_token_account_idempotent(payer, owner, mint, token_program)
+}
+
+/// Token program that owns `mint` (SPL Token or Token-2022)
+pub async fn mint_token_program(rpc: &RpcClient, mint: &Pubkey) -> Result<Pubkey> {
+    let owner = rpc.get_account(mint).await?.owner;
+    if owner != TOKEN_PROGRAM_ID && owner != TOKEN_2022_PROGRAM_ID {
+        return Err(Error::Deserialize(format!("{mint} is not owned by a token program ({owner})")));
+    }
+    Ok(owner)
+}
+
+/// Whether an account exists at `address`
+pub async fn ata_exists(rpc: &RpcClient, address: &Pubkey) -> Result<bool> {
+    Ok(rpc.get_account_with_commitment(address, rpc.commitment()).await?.value.is_some())
+}
+
+/// Rent-exempt balance for a new ATA under `token_program`. For Token-2022
+/// mints whose extensions add per-account state (transfer fees, hooks, …)
+/// this is a lower bound.
+pub async fn ata_rent(rpc: &RpcClient, token_program: &Pubkey) -> Result<Lamports> {
+    let len = if *token_program == TOKEN_2022_PROGRAM_ID { TOKEN_2022_ACCOUNT_LEN } else { TOKEN_ACCOUNT_LEN };
+    Ok(Lamports(rpc.get_minimum_balance_for_rent_exemption(len).await?))
+}
+
+/// Look up `owner`'s ATA for `mint` and, if it is missing, the instruction and
+/// rent `payer` needs to create it
+pub async fn ensure_ata(rpc: &RpcClient, payer: &Pubkey, owner: &Pubkey, mint: &Pubkey) -> Result<Ata> {
+    let token_program = mint_token_program(rpc, mint).await?;
+    let address = ata_address(owner, mint, &token_program);
+    let (create_ix, rent) = if ata_exists(rpc, &address).await? {
+        (None, Lamports::ZERO)
+    } else {
+        (Some(create_ata_ix(payer, owner, mint, &token_program)), ata_rent(rpc, &token_program).await?)
+    };
+    Ok(Ata { address, owner: *owner, mint: *mint, token_program, create_ix, rent })
+}
+
+/// Raw balance of `owner`'s ATA for `mint` (0 if it doesn't exist)
+pub async fn token_balance(rpc: &RpcClient, owner: &Pubkey, mint: &Pubkey) -> Result<u64> {
+    let token_program = mint_token_program(rpc, mint).await?;
+    match rpc.get_token_account_balance(&ata_address(owner, mint, &token_program)).await {
+        Ok(balance) => Ok(balance.amount.parse()?),
+        Err(_) => Ok(0),
+    }
+}
+
+/// What the recipient is credited when `amount` of `mint` is transferred this epoch
+pub async fn expected_received(rpc: &RpcClient, mint: &Pubkey, amount: u64) -> Result<ReceivedAmount> {
+    let info = check_mint(rpc, mint).await?;
+    let transfer_fee = match info.transfer_fee {
+        Some(_) => info.transfer_fee_for(amount, rpc.get_epoch_info().await?.epoch),
+        None => 0,
+    };
+    let token = |raw| TokenAmount::new(raw, info.decimals);
+    Ok(ReceivedAmount { gross: token(amount), transfer_fee: token(transfer_fee), net: token(amount - transfer_fee) })
+}
+
+/// [`expected_received`], printing a warning when `mint` withholds a transfer fee
+pub async fn warn_transfer_fee(rpc: &RpcClient, mint: &Pubkey, amount: u64) -> Result<ReceivedAmount> {
+    let received = expected_received(rpc, mint, amount).await?;
+    if received.transfer_fee.raw > 0 {
+        progress(format!(
+            "warning: {} charges a transfer fee; {} of {} will be withheld, {} received",
+            mint_label(&mint.to_string()),
+            received.transfer_fee,
+            received.gross,
+            received.net,
+        ));
+    }
+    Ok(received)
+}
@@ -0,0 +1,194 @@
+// common/src/basket.rs
+use crate::{
+    default_slippage_bps, emit, get_prices, get_quote, mint_label, swap_with_params, try_http_client, Config, Error,
+    Result, SwapParams, TokenAmount, TxSigner, USDC_MINT, WSOL_MINT,
+};
+use serde::Serialize;
+
+// ─────────────────── Treasury basket diversification ───────────────────
+
+#[derive(Debug, Clone)]
+pub struct BasketParams {
+    pub input_mint: String,
+    /// Total of the input mint to convert
+    pub total_amount: TokenAmount,
+    /// (output mint, weight); weights are normalised, so 40/30/30 and 0.4/0.3/0.3 are the same
+    pub targets: Vec<(String, f64)>,
+    pub slippage_bps: u64,
+    /// Skip a leg whose quote has more price impact than this (percent)
+    pub max_price_impact_pct: f64,
+    /// Skip a leg whose quoted output is worth this much less than its input at Price API prices
+    pub max_value_loss_bps: f64,
+}
+
+impl Default for BasketParams {
+    /// 100 USDC → 40% SOL, 30% JUP, 30% JLP
+    fn default() -> Self {
+        Self {
+            input_mint: USDC_MINT.to_string(),
+            total_amount: TokenAmount::new(100_000_000, 6),
+            targets: vec![
+                (WSOL_MINT.to_string(), 40.0),
+                ("JUPyiwrYJFskUPiHa7hkeR8VUtAeFoSYbKedZNsDvCN".to_string(), 30.0),
+                ("27G8MtK7VtTcCHkpASjSDdkWWYfoqT6ggEuKidVJidD4".to_string(), 30.0),
+            ],
+            slippage_bps: default_slippage_bps(),
+            max_price_impact_pct: 1.0,
+            max_value_loss_bps: 100.0,
+        }
+    }
+}
+
+impl BasketParams {
+    /// Input per target; the last leg absorbs rounding so the legs sum to `total_amount`
+    pub fn allocations(&self) -> Result<Vec<(String, TokenAmount)>> {
+        let total_weight: f64 = self.targets.iter().map(|(_, w)| w).sum();
+        if self.targets.is_empty() || total_weight <= 0.0 || self.targets.iter().any(|(_, w)| *w < 0.0) {
+            return Err(Error::Invalid("basket weights must be non-negative and sum to more than 0".into()));
+        }
+        let mut left = self.total_amount.raw;
+        let mut out = Vec::with_capacity(self.targets.len());
+        for (i, (mint, w)) in self.targets.iter().enumerate() {
+            let raw = if i + 1 == self.targets.len() {
+                left
+            } else {
+                ((self.total_amount.raw as f64) * w / total_weight).floor() as u64
+            };
+            left -= raw;
+            out.push((mint.clone(), TokenAmount { raw, ..self.total_amount }));
+        }
+        Ok(out)
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct BasketLeg {
+    pub output_mint: String,
+    pub weight: f64,
+    /// Raw input allocated
+    pub in_amount: u64,
+    /// Raw quoted output
+    pub quoted_out: u64,
+    pub price_impact_pct: f64,
+    /// Input USD value minus quoted output USD value, in bps of the input
+    pub value_loss_bps: Option<f64>,
+    pub in_usd: Option<f64>,
+    pub out_usd: Option<f64>,
+    pub signature: Option<String>,
+    pub skipped: Option<String>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct BasketReport {
+    pub legs: Vec<BasketLeg>,
+    pub executed: bool,
+    pub in_usd: f64,
+    pub out_usd: f64,
+}
+
+/// Quote every leg concurrently, apply the guards, then (if `execute`) swap
+/// the legs that passed one after another
+pub async fn diversify(kp: &dyn TxSigner, params: &BasketParams, execute: bool) -> Result<BasketReport> {
+    let http = try_http_client()?;
+    let allocations = params.allocations()?;
+    let total_weight: f64 = params.targets.iter().map(|(_, w)| w).sum();
+
+    let mut mints: Vec<&str> = allocations.iter().map(|(m, _)| m.as_str()).collect();
+    mints.push(&params.input_mint);
+    let prices = get_prices(&http, &mints).await?;
+    let usd = |mint: &str, raw: u64| prices.get(mint).map(|p| raw as f64 / 10f64.powi(p.decimals as i32) * p.usd_price);
+
+    let swaps: Vec<SwapParams> = allocations
+        .iter()
+        .map(|(mint, amount)| SwapParams {
+            input_mint: params.input_mint.clone(),
+            output_mint: mint.clone(),
+            amount: *amount,
+            slippage_bps: params.slippage_bps,
+            mint_policy: None,
+        })
+        .collect();
+    let quotes = futures::future::join_all(swaps.iter().map(|s| get_quote(&http, s))).await;
+
+    let mut legs = Vec::with_capacity(swaps.len());
+    for ((swap, quote), (_, weight)) in swaps.iter().zip(quotes).zip(&params.targets) {
+        let mut leg = BasketLeg {
+            output_mint: swap.output_mint.clone(),
+            weight: weight / total_weight,
+            in_amount: swap.amount.raw,
+            quoted_out: 0,
+            price_impact_pct: 0.0,
+            value_loss_bps: None,
+            in_usd: usd(&swap.input_mint, swap.amount.raw),
+            out_usd: None,
+            signature: None,
+            skipped: None,
+        };
+        match quote {
+            Ok(q) => {
+                leg.quoted_out = q.outAmount.parse()?;
+                leg.price_impact_pct = q.price_impact_pct();
+                leg.out_usd = usd(&swap.output_mint, leg.quoted_out);
+                leg.value_loss_bps = leg.in_usd.zip(leg.out_usd).map(|(i, o)| (i - o) / i * 10_000.0);
+
+                if leg.price_impact_pct > params.max_price_impact_pct {
+                    leg.skipped = Some(format!("price impact {:.2}%", leg.price_impact_pct));
+                } else if let Some(loss) = leg.value_loss_bps.filter(|l| *l > params.max_value_loss_bps) {
+                    leg.skipped = Some(format!("quote {loss:.1} bps below Price API value"));
+                }
+            }
+            Err(e) => leg.skipped = Some(format!("quote failed: {e}")),
+        }
+        legs.push(leg);
+    }
+
+    if execute {
+        for (leg, swap) in legs.iter_mut().zip(swaps) {
+            if leg.skipped.is_some() {
+                continue;
+            }
+            match swap_with_params(kp, swap).await {
+                Ok(res) => {
+                    leg.quoted_out = res.out_amount.raw;
+                    leg.signature = Some(res.signature);
+                }
+                Err(e) => leg.skipped = Some(format!("swap failed: {e}")),
+            }
+        }
+    }
+
+    let counted = legs.iter().filter(|l| l.skipped.is_none());
+    let in_usd = counted.clone().filter_map(|l| l.in_usd).sum();
+    let out_usd = counted.filter_map(|l| l.out_usd).sum();
+    Ok(BasketReport { legs, executed: execute, in_usd, out_usd })
+}
+
+impl std::fmt::Display for BasketReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for l in &self.legs {
+            writeln!(
+                f,
+                "{:<6} {:>5.1}%  in {:>12}  out {:>16}  impact {:>5.2}%  loss {:>7}  {}",
+                mint_label(&l.output_mint),
+                l.weight * 100.0,
+                l.in_amount,
+                l.quoted_out,
+                l.price_impact_pct,
+                l.value_loss_bps.map(|b| format!("{b:.1}bps")).unwrap_or_else(|| "-".into()),
+                match (&l.signature, &l.skipped) {
+                    (Some(sig), _) => sig.clone(),
+                    (_, Some(reason)) => format!("skipped: {reason}"),
+                    _ => "ok (not executed)".into(),
+                },
+            )?;
+        }
+        write!(f, "${:.2} in → ${:.2} out at Price API prices", self.in_usd, self.out_usd)
+    }
+}
+
+pub async fn basket_flow(kp: &dyn TxSigner, params: BasketParams, execute: bool) -> Result<BasketReport> {
+    Config::load()?;
+    let report = diversify(kp, &params, execute).await?;
+    emit("basket", &report, &report);
+    Ok(report)
+}
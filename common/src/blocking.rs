@@ -0,0 +1,244 @@
+// common/src/blocking.rs
+use crate::{Config, Result};
+use std::future::Future;
+use std::sync::OnceLock;
+use tokio::runtime::{Builder, Handle, Runtime};
+#[cfg(feature = "swap")]
+use crate::{QuoteResponse, SwapParams, SwapResponse, SwapResult};
+#[cfg(any(feature = "swap", feature = "ultra"))]
+use crate::TxSigner;
+#[cfg(feature = "ultra")]
+use crate::{UltraExecuteResponse, UltraOrderResponse};
+#[cfg(feature = "trigger")]
+use crate::{CancelTriggerResponse, CreateTriggerResponse, ExecuteTriggerResponse, TriggerOrder, TriggerOrderStatus};
+#[cfg(feature = "price")]
+use {crate::Price, std::collections::HashMap};
+#[cfg(feature = "tokens")]
+use crate::{Token, TokenTag};
+
+// ─────────────────── Blocking API ───────────────────
+//
+// Synchronous versions of the JupiterClient calls and the main flows, for
+// scripts and non-async code. Every call runs on one runtime shared by the
+// whole process and started on first use, so the HTTP connection pool stays
+// alive between calls instead of dying with a per-call runtime. Like
+// reqwest::blocking, these can't be called from inside an async runtime.
+
+static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+
+/// The runtime blocking calls run on, started on first use; spawn onto its
+/// handle to run background work (e.g. a price watcher) alongside them
+pub fn runtime() -> Result<&'static Runtime> {
+    if let Some(rt) = RUNTIME.get() {
+        return Ok(rt);
+    }
+    let rt = Builder::new_multi_thread()
+        .enable_all()
+        .thread_name("jupiter-blocking")
+        .build()
+        .map_err(|e| anyhow::anyhow!("can't start the blocking runtime: {e}"))?;
+    // a runtime that lost the race is dropped here, before it ran anything
+    let _ = RUNTIME.set(rt);
+    Ok(RUNTIME.get().expect("set above"))
+}
+
+/// Run `fut` to completion on [`runtime`]
+pub fn block_on<T>(fut: impl Future<Output = Result<T>>) -> Result<T> {
+    if Handle::try_current().is_ok() {
+        return Err(anyhow::anyhow!("blocking call inside an async runtime; use the async API there").into());
+    }
+    runtime()?.block_on(fut)
+}
+
+// ─────────────────── Jupiter client ───────────────────
+
+/// [`crate::JupiterClient`] with every call blocking; configure the async
+/// client and convert it with `From` for anything beyond [`Config`]
+#[derive(Clone)]
+pub struct JupiterClient {
+    inner: crate::JupiterClient,
+}
+
+impl JupiterClient {
+    pub fn new(cfg: &Config) -> Result<Self> {
+        Ok(Self { inner: crate::JupiterClient::new(cfg)? })
+    }
+
+    /// Reads the environment and jupiter.toml, like [`Config::load`]
+    pub fn from_env() -> Result<Self> {
+        Self::new(&Config::load()?)
+    }
+
+    /// The async client underneath, e.g. to hand to an async flow
+    pub fn inner(&self) -> &crate::JupiterClient {
+        &self.inner
+    }
+
+    #[cfg(feature = "swap")]
+    pub fn swap(&self) -> SwapApi {
+        SwapApi { inner: self.inner.swap() }
+    }
+
+    #[cfg(feature = "ultra")]
+    pub fn ultra(&self) -> UltraApi {
+        UltraApi { inner: self.inner.ultra() }
+    }
+
+    #[cfg(feature = "trigger")]
+    pub fn trigger(&self) -> TriggerApi {
+        TriggerApi { inner: self.inner.trigger() }
+    }
+
+    #[cfg(feature = "price")]
+    pub fn price(&self) -> PriceApi {
+        PriceApi { inner: self.inner.price() }
+    }
+
+    #[cfg(feature = "tokens")]
+    pub fn tokens(&self) -> TokensApi {
+        TokensApi { inner: self.inner.tokens() }
+    }
+}
+
+impl From<crate::JupiterClient> for JupiterClient {
+    fn from(inner: crate::JupiterClient) -> Self {
+        Self { inner }
+    }
+}
+
+// ─────────────────── Sub-clients ───────────────────
+
+/// Blocking [`crate::SwapApi`]
+#[cfg(feature = "swap")]
+#[derive(Clone)]
+pub struct SwapApi {
+    inner: crate::SwapApi,
+}
+
+#[cfg(feature = "swap")]
+impl SwapApi {
+    pub fn quote(&self, params: &SwapParams) -> Result<QuoteResponse> {
+        block_on(self.inner.quote(params))
+    }
+
+    pub fn swap_transaction(&self, quote: &QuoteResponse, user: &str, extra: serde_json::Value) -> Result<SwapResponse> {
+        block_on(self.inner.swap_transaction(quote, user, extra))
+    }
+}
+
+/// Blocking [`crate::UltraApi`]
+#[cfg(feature = "ultra")]
+#[derive(Clone)]
+pub struct UltraApi {
+    inner: crate::UltraApi,
+}
+
+#[cfg(feature = "ultra")]
+impl UltraApi {
+    pub fn order(
+        &self,
+        input_mint: &str,
+        output_mint: &str,
+        amount: u64,
+        taker: &str,
+        extra_query: &str,
+    ) -> Result<UltraOrderResponse> {
+        block_on(self.inner.order(input_mint, output_mint, amount, taker, extra_query))
+    }
+
+    pub fn execute(&self, signed_transaction: &str, request_id: &str) -> Result<UltraExecuteResponse> {
+        block_on(self.inner.execute(signed_transaction, request_id))
+    }
+}
+
+/// Blocking [`crate::TriggerApi`]
+#[cfg(feature = "trigger")]
+#[derive(Clone)]
+pub struct TriggerApi {
+    inner: crate::TriggerApi,
+}
+
+#[cfg(feature = "trigger")]
+impl TriggerApi {
+    pub fn create_order(&self, body: &serde_json::Value) -> Result<CreateTriggerResponse> {
+        block_on(self.inner.create_order(body))
+    }
+
+    pub fn execute(&self, signed_transaction: &str, request_id: &str) -> Result<ExecuteTriggerResponse> {
+        block_on(self.inner.execute(signed_transaction, request_id))
+    }
+
+    pub fn get_orders(&self, user: &str, status: TriggerOrderStatus) -> Result<Vec<TriggerOrder>> {
+        block_on(self.inner.get_orders(user, status))
+    }
+
+    pub fn cancel_order(&self, maker: &str, order: &str) -> Result<CancelTriggerResponse> {
+        block_on(self.inner.cancel_order(maker, order))
+    }
+
+    pub fn cancel_orders(&self, maker: &str, orders: &[String]) -> Result<CancelTriggerResponse> {
+        block_on(self.inner.cancel_orders(maker, orders))
+    }
+}
+
+/// Blocking [`crate::PriceApi`]
+#[cfg(feature = "price")]
+#[derive(Clone)]
+pub struct PriceApi {
+    inner: crate::PriceApi,
+}
+
+#[cfg(feature = "price")]
+impl PriceApi {
+    pub fn prices<S: AsRef<str>>(&self, mints: &[S]) -> Result<HashMap<String, Price>> {
+        block_on(self.inner.prices(mints))
+    }
+
+    pub fn price(&self, mint: &str) -> Result<Option<Price>> {
+        block_on(self.inner.price(mint))
+    }
+
+    pub fn usd_price(&self, mint: &str) -> Result<Option<f64>> {
+        block_on(self.inner.usd_price(mint))
+    }
+}
+
+/// Blocking [`crate::TokensApi`]
+#[cfg(feature = "tokens")]
+#[derive(Clone)]
+pub struct TokensApi {
+    inner: crate::TokensApi,
+}
+
+#[cfg(feature = "tokens")]
+impl TokensApi {
+    pub fn search(&self, query: &str) -> Result<Vec<Token>> {
+        block_on(self.inner.search(query))
+    }
+
+    pub fn token(&self, mint: &str) -> Result<Option<Token>> {
+        block_on(self.inner.token(mint))
+    }
+
+    pub fn tagged(&self, tag: TokenTag) -> Result<Vec<Token>> {
+        block_on(self.inner.tagged(tag))
+    }
+
+    pub fn recent(&self, limit: usize) -> Result<Vec<Token>> {
+        block_on(self.inner.recent(limit))
+    }
+}
+
+// ─────────────────── Flows ───────────────────
+
+/// Blocking [`crate::swap_with_params`]: quote, build, sign, send and confirm
+#[cfg(feature = "swap")]
+pub fn swap_with_params(kp: &dyn TxSigner, params: SwapParams) -> Result<SwapResult> {
+    block_on(crate::swap_with_params(kp, params))
+}
+
+/// Blocking [`crate::ultra_flow`]
+#[cfg(feature = "ultra")]
+pub fn ultra_flow(kp: &dyn TxSigner) -> Result<()> {
+    block_on(crate::ultra_flow(kp))
+}
@@ -0,0 +1,103 @@
+// common/src/cache.rs
+use serde::Serialize;
+use std::sync::atomic::{AtomicU64, Ordering};
+#[cfg(feature = "swap")]
+use {
+    crate::swap::quote_query,
+    crate::{QuoteResponse, SwapParams},
+    std::collections::HashMap,
+    std::sync::RwLock,
+    std::time::{Duration, Instant},
+};
+
+// ─────────────────── Response caches ───────────────────
+//
+// Quotes go stale within seconds, so the quote cache is meant for a TTL of a
+// second or two: enough for a UI re-rendering the same quote, or several
+// components asking for the same pair in one tick, to share one request.
+// Token metadata barely changes and is cached long-term by [`crate::TokenCache`].
+
+/// Hit/miss counts of one cache
+#[derive(Serialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+impl CacheStats {
+    /// Share of lookups served from the cache (0 before the first lookup)
+    pub fn hit_rate(&self) -> f64 {
+        let total = self.hits + self.misses;
+        if total == 0 {
+            0.0
+        } else {
+            self.hits as f64 / total as f64
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct CacheCounters {
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl CacheCounters {
+    pub(crate) fn record(&self, hit: bool) {
+        let counter = if hit { &self.hits } else { &self.misses };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn stats(&self) -> CacheStats {
+        CacheStats { hits: self.hits.load(Ordering::Relaxed), misses: self.misses.load(Ordering::Relaxed) }
+    }
+}
+
+/// Short-TTL cache of /quote responses keyed by pair, amount, slippage and fee
+#[cfg(feature = "swap")]
+#[derive(Debug)]
+pub struct QuoteCache {
+    ttl: Duration,
+    entries: RwLock<HashMap<String, (Instant, QuoteResponse)>>,
+    counters: CacheCounters,
+}
+
+#[cfg(feature = "swap")]
+impl QuoteCache {
+    pub fn new(ttl: Duration) -> Self {
+        Self { ttl, entries: RwLock::new(HashMap::new()), counters: CacheCounters::default() }
+    }
+
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// The cached quote for `params`, if it is younger than the TTL
+    pub fn get(&self, params: &SwapParams, platform_fee_bps: Option<u64>) -> Option<QuoteResponse> {
+        let key = quote_query(params, platform_fee_bps);
+        let quote = self
+            .entries
+            .read()
+            .unwrap()
+            .get(&key)
+            .filter(|(at, _)| at.elapsed() < self.ttl)
+            .map(|(_, quote)| quote.clone());
+        self.counters.record(quote.is_some());
+        quote
+    }
+
+    /// Store `quote` for `params`, dropping expired entries on the way
+    pub fn insert(&self, params: &SwapParams, platform_fee_bps: Option<u64>, quote: QuoteResponse) {
+        let mut entries = self.entries.write().unwrap();
+        entries.retain(|_, (at, _)| at.elapsed() < self.ttl);
+        entries.insert(quote_query(params, platform_fee_bps), (Instant::now(), quote));
+    }
+
+    pub fn clear(&self) {
+        self.entries.write().unwrap().clear();
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        self.counters.stats()
+    }
+}
@@ -0,0 +1,388 @@
+// common/src/client.rs
+use crate::{try_http_client, ApiResponseExt, Config, JupiterApi, JupiterReqExt, Result};
+use reqwest::{Client, RequestBuilder};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::HashMap;
+#[cfg(feature = "swap")]
+use {
+    crate::metrics::record_quote,
+    crate::swap::quote_query,
+    crate::{CacheStats, QuoteCache, QuoteResponse, SwapParams, SwapResponse},
+    std::sync::Arc,
+    std::time::Duration,
+};
+#[cfg(any(feature = "ultra", feature = "trigger"))]
+use crate::{dry_run, execute_guard, metrics::record_sent, metrics::SentVia, simulate_execute, DRY_RUN_STATUS};
+#[cfg(any(feature = "swap", feature = "ultra", feature = "trigger"))]
+use serde_json::json;
+#[cfg(feature = "ultra")]
+use crate::{UltraExecuteResponse, UltraOrderResponse};
+#[cfg(feature = "trigger")]
+use crate::{
+    trigger::TriggerOrdersResponse, CancelTriggerResponse, CreateTriggerResponse, ExecuteTriggerResponse, TriggerOrder,
+    TriggerOrderStatus,
+};
+#[cfg(feature = "recurring")]
+use crate::RecurringClient;
+#[cfg(feature = "price")]
+use {
+    crate::price::{parse_prices, MAX_PRICE_IDS},
+    crate::Price,
+    std::collections::HashSet,
+};
+#[cfg(feature = "tokens")]
+use crate::{Token, TokenTag};
+
+// ─────────────────── Jupiter client ───────────────────
+
+/// One entry point for every Jupiter API. The sub-clients returned by
+/// `.swap()`, `.ultra()`, … share this client's HTTP connection pool, API
+/// URLs and API key, so a flow builds it once instead of re-reading the env.
+/// Each sub-client exists only when its product's cargo feature is on.
+#[derive(Clone)]
+pub struct JupiterClient {
+    http: Client,
+    base_url: String,
+    api_urls: HashMap<JupiterApi, String>,
+    api_key: Option<String>,
+    #[cfg(feature = "swap")]
+    quote_cache: Option<Arc<QuoteCache>>,
+}
+
+impl JupiterClient {
+    /// Fails on a `JUPITER_PROXY` the HTTP client can't use, like [`try_http_client`]
+    pub fn new(cfg: &Config) -> Result<Self> {
+        Ok(Self {
+            http: try_http_client()?,
+            base_url: cfg.base_url.clone(),
+            api_urls: cfg.api_urls.clone(),
+            api_key: cfg.api_key.clone(),
+            #[cfg(feature = "swap")]
+            quote_cache: cfg.quote_cache_ttl.map(|ttl| Arc::new(QuoteCache::new(ttl))),
+        })
+    }
+
+    pub fn with_http(mut self, http: Client) -> Self {
+        self.http = http;
+        self
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Point one product somewhere other than `{base_url}/{path}`
+    pub fn with_api_url(mut self, api: JupiterApi, url: impl Into<String>) -> Self {
+        self.api_urls.insert(api, url.into().trim_end_matches('/').to_string());
+        self
+    }
+
+    /// Serve repeated identical quotes from memory for `ttl`; clones of this
+    /// client (and its sub-clients) share the cache
+    #[cfg(feature = "swap")]
+    pub fn with_quote_cache(mut self, ttl: Duration) -> Self {
+        self.quote_cache = Some(Arc::new(QuoteCache::new(ttl)));
+        self
+    }
+
+    /// Hits and misses of the quote cache, if one is enabled
+    #[cfg(feature = "swap")]
+    pub fn quote_cache_stats(&self) -> Option<CacheStats> {
+        self.quote_cache.as_ref().map(|c| c.stats())
+    }
+
+    pub fn http(&self) -> &Client {
+        &self.http
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Root URL `api` is served from
+    pub fn api_url(&self, api: JupiterApi) -> String {
+        match self.api_urls.get(&api) {
+            Some(url) => url.clone(),
+            None => format!("{}/{}", self.base_url, api.path()),
+        }
+    }
+
+    /// `{api root}/{endpoint}`; an endpoint that is only a query string attaches to the root
+    fn url(&self, api: JupiterApi, endpoint: &str) -> String {
+        let sep = if endpoint.starts_with('?') { "" } else { "/" };
+        format!("{}{}{}", self.api_url(api), sep, endpoint)
+    }
+
+    /// GET `endpoint` of `api` with the API key attached
+    pub(crate) fn get(&self, api: JupiterApi, endpoint: &str) -> RequestBuilder {
+        self.authed(self.http.get(self.url(api, endpoint)))
+    }
+
+    /// POST `endpoint` of `api` with the API key attached
+    pub(crate) fn post(&self, api: JupiterApi, endpoint: &str) -> RequestBuilder {
+        self.authed(self.http.post(self.url(api, endpoint)))
+    }
+
+    fn authed(&self, req: RequestBuilder) -> RequestBuilder {
+        match &self.api_key {
+            Some(key) => req.header("X-API-KEY", key),
+            None => req,
+        }
+    }
+
+    #[cfg(any(feature = "swap", feature = "trigger", feature = "tokens"))]
+    async fn get_json<R: DeserializeOwned + 'static>(&self, api: JupiterApi, endpoint: &str) -> Result<R> {
+        Ok(self.get(api, endpoint).send_with_retry().await?.api_json().await?)
+    }
+
+    #[cfg(any(feature = "swap", feature = "ultra", feature = "trigger"))]
+    async fn post_json<B: Serialize, R: DeserializeOwned + 'static>(
+        &self,
+        api: JupiterApi,
+        endpoint: &str,
+        body: &B,
+    ) -> Result<R> {
+        Ok(self.post(api, endpoint).json(body).send_with_retry().await?.api_json().await?)
+    }
+
+    #[cfg(feature = "swap")]
+    pub fn swap(&self) -> SwapApi {
+        SwapApi { client: self.clone() }
+    }
+
+    #[cfg(feature = "ultra")]
+    pub fn ultra(&self) -> UltraApi {
+        UltraApi { client: self.clone() }
+    }
+
+    #[cfg(feature = "trigger")]
+    pub fn trigger(&self) -> TriggerApi {
+        TriggerApi { client: self.clone() }
+    }
+
+    #[cfg(feature = "recurring")]
+    pub fn recurring(&self) -> RecurringClient {
+        RecurringClient::new(self.http.clone())
+            .with_base_url(self.api_url(JupiterApi::Recurring))
+            .with_api_key(self.api_key.clone())
+    }
+
+    #[cfg(feature = "price")]
+    pub fn price(&self) -> PriceApi {
+        PriceApi { client: self.clone() }
+    }
+
+    #[cfg(feature = "tokens")]
+    pub fn tokens(&self) -> TokensApi {
+        TokensApi { client: self.clone() }
+    }
+}
+
+// ─────────────────── Sub-clients ───────────────────
+
+/// /swap/v1
+#[cfg(feature = "swap")]
+#[derive(Clone)]
+pub struct SwapApi {
+    client: JupiterClient,
+}
+
+#[cfg(feature = "swap")]
+impl SwapApi {
+    pub async fn quote(&self, params: &SwapParams) -> Result<QuoteResponse> {
+        self.quote_with_fee(params, None).await
+    }
+
+    pub(crate) async fn quote_with_fee(&self, params: &SwapParams, platform_fee_bps: Option<u64>) -> Result<QuoteResponse> {
+        let cache = self.client.quote_cache.as_deref();
+        if let Some(quote) = cache.and_then(|c| c.get(params, platform_fee_bps)) {
+            return Ok(quote);
+        }
+        let endpoint = format!("quote?{}", quote_query(params, platform_fee_bps));
+        let quote: QuoteResponse = self.client.get_json(JupiterApi::Swap, &endpoint).await?;
+        record_quote();
+        if let Some(cache) = cache {
+            cache.insert(params, platform_fee_bps, quote.clone());
+        }
+        Ok(quote)
+    }
+
+    /// Unsigned swap transaction for `quote`; fields of `extra` (e.g. `feeAccount`) are merged into the request
+    pub async fn swap_transaction(&self, quote: &QuoteResponse, user: &str, extra: serde_json::Value) -> Result<SwapResponse> {
+        let mut body = json!({
+            "quoteResponse": quote,
+            "userPublicKey": user,
+            "payer": user,
+        });
+        if let (Some(fields), serde_json::Value::Object(extra)) = (body.as_object_mut(), extra) {
+            fields.extend(extra);
+        }
+        self.client.post_json(JupiterApi::Swap, "swap", &body).await
+    }
+}
+
+/// /ultra/v1
+#[cfg(feature = "ultra")]
+#[derive(Clone)]
+pub struct UltraApi {
+    client: JupiterClient,
+}
+
+#[cfg(feature = "ultra")]
+impl UltraApi {
+    /// `extra_query` is appended as-is, e.g. "&referralAccount=…&referralFee=50"
+    pub async fn order(
+        &self,
+        input_mint: &str,
+        output_mint: &str,
+        amount: u64,
+        taker: &str,
+        extra_query: &str,
+    ) -> Result<UltraOrderResponse> {
+        let path = format!(
+            "order?inputMint={}&outputMint={}&amount={}&taker={}{}",
+            input_mint, output_mint, amount, taker, extra_query
+        );
+        Ok(self.client.get(JupiterApi::Ultra, &path).send_with_retry().await?.api_json().await?)
+    }
+
+    /// Under `DRY_RUN` the transaction is only simulated; otherwise it needs `I_UNDERSTAND_MAINNET`
+    pub async fn execute(&self, signed_transaction: &str, request_id: &str) -> Result<UltraExecuteResponse> {
+        if dry_run() {
+            let signature = simulate_execute("/ultra/v1/execute", signed_transaction).await?;
+            return Ok(UltraExecuteResponse {
+                status: Some(DRY_RUN_STATUS.into()),
+                signature: Some(signature.to_string()),
+                slot: None,
+                extra: serde_json::Value::Null,
+            });
+        }
+        execute_guard()?;
+        let body = json!({ "signedTransaction": signed_transaction, "requestId": request_id });
+        let resp = self.client.post_json(JupiterApi::Ultra, "execute", &body).await?;
+        record_sent(SentVia::Ultra);
+        Ok(resp)
+    }
+}
+
+/// /trigger/v1
+#[cfg(feature = "trigger")]
+#[derive(Clone)]
+pub struct TriggerApi {
+    client: JupiterClient,
+}
+
+#[cfg(feature = "trigger")]
+impl TriggerApi {
+    pub async fn create_order(&self, body: &serde_json::Value) -> Result<CreateTriggerResponse> {
+        self.client.post_json(JupiterApi::Trigger, "createOrder", body).await
+    }
+
+    /// Under `DRY_RUN` the transaction is only simulated; otherwise it needs `I_UNDERSTAND_MAINNET`
+    pub async fn execute(&self, signed_transaction: &str, request_id: &str) -> Result<ExecuteTriggerResponse> {
+        if dry_run() {
+            let signature = simulate_execute("/trigger/v1/execute", signed_transaction).await?;
+            return Ok(ExecuteTriggerResponse {
+                status: DRY_RUN_STATUS.into(),
+                signature: signature.to_string(),
+                extra: serde_json::Value::Null,
+            });
+        }
+        execute_guard()?;
+        let body = json!({ "signedTransaction": signed_transaction, "requestId": request_id });
+        let resp = self.client.post_json(JupiterApi::Trigger, "execute", &body).await?;
+        record_sent(SentVia::Trigger);
+        Ok(resp)
+    }
+
+    /// Every order of `user` with `status`, across all pages
+    pub async fn get_orders(&self, user: &str, status: TriggerOrderStatus) -> Result<Vec<TriggerOrder>> {
+        let mut orders = Vec::new();
+        let mut page = 1;
+        loop {
+            let endpoint = format!("getTriggerOrders?user={user}&orderStatus={}&page={page}", status.as_api());
+            let resp: TriggerOrdersResponse = self.client.get_json(JupiterApi::Trigger, &endpoint).await?;
+            orders.extend(resp.orders);
+            if page >= resp.total_pages {
+                return Ok(orders);
+            }
+            page += 1;
+        }
+    }
+
+    /// Unsigned transaction cancelling one order of `maker`
+    pub async fn cancel_order(&self, maker: &str, order: &str) -> Result<CancelTriggerResponse> {
+        let body = json!({ "maker": maker, "order": order, "computeUnitPrice": "auto" });
+        self.client.post_json(JupiterApi::Trigger, "cancelOrder", &body).await
+    }
+
+    /// Unsigned transactions cancelling `orders` of `maker`, or all of them when `orders` is empty
+    pub async fn cancel_orders(&self, maker: &str, orders: &[String]) -> Result<CancelTriggerResponse> {
+        let mut body = json!({ "maker": maker, "computeUnitPrice": "auto" });
+        if !orders.is_empty() {
+            body["orders"] = orders.into();
+        }
+        self.client.post_json(JupiterApi::Trigger, "cancelOrders", &body).await
+    }
+}
+
+/// /price/v3
+#[cfg(feature = "price")]
+#[derive(Clone)]
+pub struct PriceApi {
+    client: JupiterClient,
+}
+
+#[cfg(feature = "price")]
+impl PriceApi {
+    /// Same as [`crate::get_prices`], against this client's base URL
+    pub async fn prices<S: AsRef<str>>(&self, mints: &[S]) -> Result<HashMap<String, Price>> {
+        let mut seen = HashSet::new();
+        let unique: Vec<&str> = mints.iter().map(|m| m.as_ref()).filter(|m| seen.insert(*m)).collect();
+
+        let mut prices = HashMap::with_capacity(unique.len());
+        for batch in unique.chunks(MAX_PRICE_IDS) {
+            let endpoint = format!("?ids={}", batch.join(","));
+            prices.extend(parse_prices(self.client.get(JupiterApi::Price, &endpoint).send_with_retry().await?).await?);
+        }
+        Ok(prices)
+    }
+
+    pub async fn price(&self, mint: &str) -> Result<Option<Price>> {
+        Ok(self.prices(&[mint]).await?.remove(mint))
+    }
+
+    pub async fn usd_price(&self, mint: &str) -> Result<Option<f64>> {
+        Ok(self.price(mint).await?.map(|p| p.usd_price))
+    }
+}
+
+/// /tokens/v2
+#[cfg(feature = "tokens")]
+#[derive(Clone)]
+pub struct TokensApi {
+    client: JupiterClient,
+}
+
+#[cfg(feature = "tokens")]
+impl TokensApi {
+    pub async fn search(&self, query: &str) -> Result<Vec<Token>> {
+        let req = self.client.get(JupiterApi::Tokens, "search").query(&[("query", query)]);
+        Ok(req.send_with_retry().await?.api_json().await?)
+    }
+
+    pub async fn token(&self, mint: &str) -> Result<Option<Token>> {
+        Ok(self.search(mint).await?.into_iter().find(|t| t.mint == mint))
+    }
+
+    pub async fn tagged(&self, tag: TokenTag) -> Result<Vec<Token>> {
+        let req = self.client.get(JupiterApi::Tokens, "tag").query(&[("query", tag.as_api())]);
+        Ok(req.send_with_retry().await?.api_json().await?)
+    }
+
+    pub async fn recent(&self, limit: usize) -> Result<Vec<Token>> {
+        self.client.get_json(JupiterApi::Tokens, &format!("recent?limit={}", limit.max(1))).await
+    }
+}
@@ -0,0 +1,88 @@
+// common/src/correlation.rs
+use reqwest::Response;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// ─────────────────── Request correlation ───────────────────
+//
+// Every flow invocation gets a correlation id of our own, sent to Jupiter as
+// `X-Correlation-Id`, and remembers the request id Jupiter's edge returned
+// for its latest call (`x-request-id`, else Cloudflare's `cf-ray`). Errors
+// print both, so a failed swap can be reported to support with identifiers
+// that match their logs. One-shot examples share a process-wide id; the
+// daemons start a fresh one per unit of work with `with_correlation`.
+
+/// Header our correlation id is sent in
+pub const CORRELATION_HEADER: &str = "X-Correlation-Id";
+
+/// Response headers tried, in order, for Jupiter's request id
+const REQUEST_ID_HEADERS: [&str; 2] = ["x-request-id", "cf-ray"];
+
+#[derive(Debug)]
+pub(crate) struct Trace {
+    id: String,
+    last_request_id: Mutex<Option<String>>,
+}
+
+impl Trace {
+    fn new(id: String) -> Arc<Self> {
+        Arc::new(Self { id, last_request_id: Mutex::new(None) })
+    }
+
+    /// Remember `resp`'s request id, logging it under `LOG_REQUEST_IDS=1`
+    pub(crate) fn record(&self, resp: &Response) {
+        let Some(request_id) = request_id(resp) else { return };
+        if std::env::var("LOG_REQUEST_IDS").is_ok_and(|v| v == "1" || v.eq_ignore_ascii_case("true")) {
+            let (status, path) = (resp.status().as_u16(), resp.url().path());
+            eprintln!("{status} {path} → request id {request_id}, correlation id {}", self.id);
+        }
+        *self.last_request_id.lock().unwrap() = Some(request_id);
+    }
+}
+
+tokio::task_local! {
+    static TRACE: Arc<Trace>;
+}
+
+fn process_trace() -> &'static Arc<Trace> {
+    static PROCESS: OnceLock<Arc<Trace>> = OnceLock::new();
+    PROCESS.get_or_init(|| Trace::new(new_correlation_id()))
+}
+
+/// The trace of the flow running on this task
+pub(crate) fn current_trace() -> Arc<Trace> {
+    TRACE.try_with(Arc::clone).unwrap_or_else(|_| process_trace().clone())
+}
+
+/// A fresh id: 16 hex digits from the clock, the process id and a counter
+pub fn new_correlation_id() -> String {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_nanos() as u64).unwrap_or_default();
+    let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+    format!("{:016x}", nanos ^ ((std::process::id() as u64) << 40) ^ n.rotate_right(16))
+}
+
+/// Correlation id of the flow running on this task
+pub fn correlation_id() -> String {
+    current_trace().id.clone()
+}
+
+/// Request id of the latest Jupiter response seen by the flow running on this task
+pub fn last_request_id() -> Option<String> {
+    current_trace().last_request_id.lock().unwrap().clone()
+}
+
+/// Run `fut` as its own flow invocation, under a fresh correlation id
+pub async fn with_correlation<F: Future>(fut: F) -> F::Output {
+    TRACE.scope(Trace::new(new_correlation_id()), fut).await
+}
+
+/// Jupiter's id for `resp`, if its edge sent one
+pub(crate) fn request_id(resp: &Response) -> Option<String> {
+    REQUEST_ID_HEADERS
+        .iter()
+        .find_map(|h| resp.headers().get(*h)?.to_str().ok())
+        .map(str::to_string)
+}
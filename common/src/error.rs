@@ -0,0 +1,260 @@
+// common/src/error.rs
+use reqwest::StatusCode;
+use serde_json::Value;
+use std::time::Duration;
+#[cfg(not(target_arch = "wasm32"))]
+use {
+    crate::config::url_api,
+    crate::correlation::request_id,
+    crate::metrics::record_api_error,
+    crate::retry::retry_after,
+    crate::strict::{decode, strict_mode},
+    async_trait::async_trait,
+    reqwest::Response,
+    serde::de::DeserializeOwned,
+    solana_client::client_error::ClientError,
+};
+
+// ─────────────────── Errors ───────────────────
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Failure classes a caller can match on; anything without a class of its
+/// own lands in `Other`
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    Config(#[from] ConfigError),
+    /// Params or user input rejected before anything was sent
+    #[error("{0}")]
+    Invalid(String),
+    /// Jupiter answered with a non-2xx status
+    #[error(transparent)]
+    Status(#[from] JupiterApiError),
+    /// Jupiter answered, but with an error payload
+    #[error("Jupiter API error: {message}")]
+    Api { status: Option<StatusCode>, message: String },
+    /// The request never got an answer (connect, timeout, TLS, …)
+    #[error("HTTP request failed: {0}")]
+    Http(reqwest::Error),
+    #[cfg(not(target_arch = "wasm32"))]
+    #[error("RPC error: {0}")]
+    Rpc(Box<ClientError>),
+    #[error("signing failed: {0}")]
+    Sign(String),
+    /// A response, transaction or field didn't have the expected shape
+    #[error("failed to decode {0}")]
+    Deserialize(String),
+    /// The Recurring API rejected a request with one of its own error codes
+    #[cfg(all(feature = "recurring", not(target_arch = "wasm32")))]
+    #[error(transparent)]
+    Recurring(#[from] crate::RecurringError),
+    /// A Lend endpoint answered without the expected transaction or vault
+    #[cfg(all(feature = "lend", not(target_arch = "wasm32")))]
+    #[error(transparent)]
+    Lend(#[from] crate::LendError),
+    /// A mint failed a [`crate::MintPolicy`]
+    #[cfg(not(target_arch = "wasm32"))]
+    #[error(transparent)]
+    MintRejected(#[from] crate::MintRejected),
+    /// The pre-trade safety pipeline refused the output token
+    #[cfg(not(target_arch = "wasm32"))]
+    #[error(transparent)]
+    SafetyBlocked(#[from] crate::SafetyBlocked),
+    /// Reading or writing a local file (keystore, CSV, …) failed
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+impl Error {
+    /// HTTP status behind an API failure, e.g. to back off on 429
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            Error::Status(e) => Some(e.status),
+            Error::Api { status, .. } => *status,
+            Error::Http(e) => e.status(),
+            _ => None,
+        }
+    }
+}
+
+/// A non-2xx answer from a Jupiter API with its error body decoded
+#[derive(Debug, Clone, thiserror::Error)]
+#[error(
+    "Jupiter API returned {status}{}: {message}{}",
+    code.as_ref().map(|c| format!(" ({c})")).unwrap_or_default(),
+    request_id.as_ref().map(|id| format!(" [request id {id}]")).unwrap_or_default()
+)]
+pub struct JupiterApiError {
+    pub status: StatusCode,
+    /// Machine-readable `errorCode` / `code` from the body, when present
+    pub code: Option<String>,
+    pub message: String,
+    /// From the `Retry-After` header, usually on 429
+    pub retry_after: Option<Duration>,
+    /// The whole body; a JSON string when it wasn't JSON
+    pub body: Value,
+    /// `x-request-id` (or `cf-ray`) of the response, for support
+    pub request_id: Option<String>,
+}
+
+impl JupiterApiError {
+    /// Pull code and message out of the shapes Jupiter uses:
+    /// `{"error": ..}`, `{"message": ..}`, `{"errorCode": .., "error": ..}`, `{"code": .., "msg": ..}`
+    pub fn from_body(status: StatusCode, retry_after: Option<Duration>, text: &str) -> Self {
+        let body = serde_json::from_str(text).unwrap_or_else(|_| Value::String(text.to_string()));
+        let code = ["errorCode", "code"].iter().find_map(|k| match &body[*k] {
+            Value::String(s) => Some(s.clone()),
+            Value::Number(n) => Some(n.to_string()),
+            _ => None,
+        });
+        let message = ["error", "message", "msg"]
+            .iter()
+            .find_map(|k| body[*k].as_str())
+            .or(body.as_str())
+            .map(|m| m.trim().to_string())
+            .filter(|m| !m.is_empty())
+            .unwrap_or_else(|| status.canonical_reason().unwrap_or("no error message").to_string());
+        JupiterApiError { status, code, message, retry_after, body, request_id: None }
+    }
+
+    pub fn is_rate_limited(&self) -> bool {
+        self.status == StatusCode::TOO_MANY_REQUESTS
+    }
+}
+
+/// Status checking and decoding for Jupiter responses; use in place of
+/// `error_for_status()` / `json()` so failures carry the API's own message
+#[cfg(not(target_arch = "wasm32"))]
+#[async_trait]
+pub(crate) trait ApiResponseExt: Sized {
+    /// The response itself on 2xx, its decoded error body otherwise
+    async fn api_status(self) -> Result<Response, JupiterApiError>;
+    /// [`ApiResponseExt::api_status`], then the body as `T`, checked per [`crate::strict_mode`]
+    async fn api_json<T: DeserializeOwned + 'static>(self) -> Result<T>;
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[async_trait]
+impl ApiResponseExt for Response {
+    async fn api_status(self) -> Result<Response, JupiterApiError> {
+        let status = self.status();
+        if status.is_success() {
+            return Ok(self);
+        }
+        record_api_error(url_api(self.url().as_str()), status.as_u16());
+        let retry_after = retry_after(&self);
+        let request_id = request_id(&self);
+        let text = self.text().await.unwrap_or_default();
+        Err(JupiterApiError { request_id, ..JupiterApiError::from_body(status, retry_after, &text) })
+    }
+
+    async fn api_json<T: DeserializeOwned + 'static>(self) -> Result<T> {
+        let resp = self.api_status().await?;
+        let path = resp.url().path().to_string();
+        let bytes = resp.bytes().await?;
+        decode(&path, &bytes, strict_mode())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigError {
+    #[error("{0} must be set")]
+    Missing(&'static str),
+    #[error("{var} is invalid: {reason}")]
+    Invalid { var: &'static str, reason: String },
+    #[error("refusing to send on mainnet: set I_UNDERSTAND_MAINNET=1 to move real funds (Jupiter's execute endpoints \
+             always land on mainnet; RPC-side helpers can use a devnet RPC_URL instead)")]
+    MainnetNotConfirmed,
+    /// Several problems found by one [`crate::Config::load`]
+    #[error("{} configuration problems:{}", .0.len(), .0.iter().map(|e| format!("\n  - {e}")).collect::<String>())]
+    Report(Vec<ConfigError>),
+}
+
+impl ConfigError {
+    /// Every individual problem, flattening a [`ConfigError::Report`]
+    pub fn problems(&self) -> Vec<&ConfigError> {
+        match self {
+            ConfigError::Report(all) => all.iter().collect(),
+            single => vec![single],
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(e: reqwest::Error) -> Self {
+        if e.is_decode() {
+            Error::Deserialize(format!("response: {e}"))
+        } else if e.is_status() {
+            Error::Api { status: e.status(), message: e.to_string() }
+        } else {
+            Error::Http(e)
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl From<ClientError> for Error {
+    fn from(e: ClientError) -> Self {
+        Error::Rpc(Box::new(e))
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl From<solana_sdk::signer::SignerError> for Error {
+    fn from(e: solana_sdk::signer::SignerError) -> Self {
+        Error::Sign(e.to_string())
+    }
+}
+
+impl From<base64::DecodeError> for Error {
+    fn from(e: base64::DecodeError) -> Self {
+        Error::Deserialize(format!("base64: {e}"))
+    }
+}
+
+impl From<bincode::Error> for Error {
+    fn from(e: bincode::Error) -> Self {
+        Error::Deserialize(format!("transaction: {e}"))
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Error::Deserialize(format!("JSON: {e}"))
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl From<solana_sdk::pubkey::ParsePubkeyError> for Error {
+    fn from(e: solana_sdk::pubkey::ParsePubkeyError) -> Self {
+        Error::Deserialize(format!("pubkey: {e}"))
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl From<solana_sdk::signature::ParseSignatureError> for Error {
+    fn from(e: solana_sdk::signature::ParseSignatureError) -> Self {
+        Error::Deserialize(format!("signature: {e}"))
+    }
+}
+
+impl From<std::num::ParseIntError> for Error {
+    fn from(e: std::num::ParseIntError) -> Self {
+        Error::Deserialize(format!("amount: {e}"))
+    }
+}
+
+impl From<std::num::ParseFloatError> for Error {
+    fn from(e: std::num::ParseFloatError) -> Self {
+        Error::Deserialize(format!("number: {e}"))
+    }
+}
+
+impl From<std::array::TryFromSliceError> for Error {
+    fn from(e: std::array::TryFromSliceError) -> Self {
+        Error::Deserialize(format!("account data: {e}"))
+    }
+}
@@ -0,0 +1,82 @@
+// common/src/explorer.rs
+use crate::{file_config, network, setting, ConfigError, Network};
+use std::fmt::Display;
+use std::str::FromStr;
+
+// ─────────────────── Explorer links ───────────────────
+
+/// Block explorer used for links in flow output (`EXPLORER`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Explorer {
+    #[default]
+    Solscan,
+    /// explorer.solana.com
+    SolanaExplorer,
+    SolanaFm,
+}
+
+impl FromStr for Explorer {
+    type Err = ConfigError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "solscan" => Ok(Explorer::Solscan),
+            "solana" | "explorer" | "solana-explorer" => Ok(Explorer::SolanaExplorer),
+            "solanafm" | "solana.fm" => Ok(Explorer::SolanaFm),
+            other => Err(ConfigError::Invalid {
+                var: "EXPLORER",
+                reason: format!("{other:?} is not solscan, solana or solanafm"),
+            }),
+        }
+    }
+}
+
+impl Explorer {
+    /// Page for the transaction `signature` on `network`
+    pub fn tx_url(self, signature: impl Display, network: Network) -> String {
+        format!("{}/tx/{signature}{}", self.root(), self.cluster(network))
+    }
+
+    /// Page for the account or mint `address` on `network`
+    pub fn account_url(self, address: impl Display, network: Network) -> String {
+        let path = match self {
+            Explorer::SolanaExplorer => "address",
+            Explorer::Solscan | Explorer::SolanaFm => "account",
+        };
+        format!("{}/{path}/{address}{}", self.root(), self.cluster(network))
+    }
+
+    fn root(self) -> &'static str {
+        match self {
+            Explorer::Solscan => "https://solscan.io",
+            Explorer::SolanaExplorer => "https://explorer.solana.com",
+            Explorer::SolanaFm => "https://solana.fm",
+        }
+    }
+
+    fn cluster(self, network: Network) -> &'static str {
+        match (self, network) {
+            (_, Network::Mainnet) => "",
+            (Explorer::SolanaFm, Network::Devnet) => "?cluster=devnet-solana",
+            (_, Network::Devnet) => "?cluster=devnet",
+        }
+    }
+}
+
+/// `EXPLORER`, else `explorer` in jupiter.toml, else Solscan. An invalid value
+/// falls back to the default here; [`crate::Config::load`] reports it.
+pub fn explorer() -> Explorer {
+    setting("EXPLORER", &file_config().explorer)
+        .and_then(|s| s.parse().ok())
+        .unwrap_or_default()
+}
+
+/// Link to `signature` on the configured explorer and network
+pub fn tx_link(signature: impl Display) -> String {
+    explorer().tx_url(signature, network().unwrap_or_default())
+}
+
+/// Link to `address` on the configured explorer and network
+pub fn account_link(address: impl Display) -> String {
+    explorer().account_url(address, network().unwrap_or_default())
+}
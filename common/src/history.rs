@@ -0,0 +1,204 @@
+// common/src/history.rs
+use crate::{emit, mint_label, progress, rpc_client, Config, Result, WSOL_MINT};
+use chrono::{DateTime, TimeZone, Utc};
+use serde::Serialize;
+use solana_client::{
+    nonblocking::rpc_client::RpcClient, rpc_client::GetConfirmedSignaturesForAddress2Config,
+    rpc_config::RpcTransactionConfig,
+};
+use solana_sdk::{pubkey::Pubkey, signature::Signature};
+use solana_transaction_status::{
+    option_serializer::OptionSerializer, UiTransactionEncoding, UiTransactionStatusMeta, UiTransactionTokenBalance,
+};
+use std::collections::HashMap;
+use std::path::Path;
+use std::str::FromStr;
+
+// ─────────────────── Historical trade analytics (on-chain) ───────────────────
+
+pub use crate::referral::JUPITER_AGGREGATOR_V6;
+
+/// SOL movements up to this size next to a token leg are account rent, not a trade leg
+const RENT_NOISE_LAMPORTS: i128 = 10_000_000;
+
+/// One Jupiter swap reconstructed from the wallet's balance changes
+#[derive(Serialize, Debug, Clone)]
+pub struct HistoricalTrade {
+    pub signature: String,
+    pub slot: u64,
+    pub block_time: Option<DateTime<Utc>>,
+    /// Route instruction from the program logs (e.g. "SharedAccountsRoute")
+    pub instruction: Option<String>,
+    pub input_mint: String,
+    /// UI amount spent
+    pub in_amount: f64,
+    pub output_mint: String,
+    /// UI amount received
+    pub out_amount: f64,
+    /// Output per unit of input
+    pub price: f64,
+    pub fee_lamports: u64,
+}
+
+/// Raw balance deltas of `owner` per mint, native SOL folded into WSOL
+fn owner_deltas(meta: &UiTransactionStatusMeta, owner: &str) -> HashMap<String, (i128, u8)> {
+    let balances = |b: &OptionSerializer<Vec<UiTransactionTokenBalance>>| {
+        Option::<Vec<UiTransactionTokenBalance>>::from(b.clone()).unwrap_or_default()
+    };
+    let mut deltas: HashMap<String, (i128, u8)> = HashMap::new();
+    for (sign, list) in [(-1i128, balances(&meta.pre_token_balances)), (1, balances(&meta.post_token_balances))] {
+        for b in list {
+            if Option::<String>::from(b.owner.clone()).as_deref() != Some(owner) {
+                continue;
+            }
+            let amount: i128 = b.ui_token_amount.amount.parse().unwrap_or(0);
+            let entry = deltas.entry(b.mint).or_insert((0, b.ui_token_amount.decimals));
+            entry.0 += sign * amount;
+        }
+    }
+    // fee payer is account 0; add the fee back so only the swap remains
+    if let (Some(pre), Some(post)) = (meta.pre_balances.first(), meta.post_balances.first()) {
+        let native = *post as i128 - *pre as i128 + meta.fee as i128;
+        deltas.entry(WSOL_MINT.to_string()).or_insert((0, 9)).0 += native;
+    }
+    deltas.retain(|_, (d, _)| *d != 0);
+    deltas
+}
+
+/// Largest leg with the given sign, ignoring SOL rent noise when a token leg exists
+fn pick_leg(deltas: &HashMap<String, (i128, u8)>, negative: bool) -> Option<(String, f64)> {
+    let legs: Vec<_> = deltas.iter().filter(|(_, (d, _))| (*d < 0) == negative).collect();
+    let is_noise = |m: &str, d: i128| m == WSOL_MINT && d.abs() <= RENT_NOISE_LAMPORTS;
+    let has_token_leg = legs.iter().any(|(m, (d, _))| !is_noise(m, *d));
+    legs.into_iter()
+        .filter(|(m, (d, _))| !has_token_leg || !is_noise(m, *d))
+        .map(|(m, (d, dec))| (m.clone(), d.unsigned_abs() as f64 / 10f64.powi(*dec as i32)))
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+}
+
+fn route_instruction(logs: &[String]) -> Option<String> {
+    let invoke = format!("Program {JUPITER_AGGREGATOR_V6} invoke");
+    let at = logs.iter().position(|l| l.starts_with(&invoke))?;
+    logs.get(at + 1)?.strip_prefix("Program log: Instruction: ").map(str::to_string)
+}
+
+/// Reconstruct the trade in `signature` if it invoked the Jupiter aggregator
+pub async fn parse_trade(rpc: &RpcClient, owner: &Pubkey, signature: &Signature) -> Result<Option<HistoricalTrade>> {
+    let config = RpcTransactionConfig {
+        encoding: Some(UiTransactionEncoding::Json),
+        commitment: None,
+        max_supported_transaction_version: Some(0),
+    };
+    let tx = rpc.get_transaction_with_config(signature, config).await?;
+    let Some(meta) = tx.transaction.meta else { return Ok(None) };
+    if meta.err.is_some() {
+        return Ok(None);
+    }
+    let logs = Option::<Vec<String>>::from(meta.log_messages.clone()).unwrap_or_default();
+    let jupiter = JUPITER_AGGREGATOR_V6.to_string();
+    if !logs.iter().any(|l| l.starts_with("Program ") && l.contains(&jupiter)) {
+        return Ok(None);
+    }
+
+    let deltas = owner_deltas(&meta, &owner.to_string());
+    let (Some((input_mint, in_amount)), Some((output_mint, out_amount))) =
+        (pick_leg(&deltas, true), pick_leg(&deltas, false))
+    else {
+        return Ok(None);
+    };
+
+    Ok(Some(HistoricalTrade {
+        signature: signature.to_string(),
+        slot: tx.slot,
+        block_time: tx.block_time.and_then(|t| Utc.timestamp_opt(t, 0).single()),
+        instruction: route_instruction(&logs),
+        price: if in_amount > 0.0 { out_amount / in_amount } else { 0.0 },
+        input_mint,
+        in_amount,
+        output_mint,
+        out_amount,
+        fee_lamports: meta.fee,
+    }))
+}
+
+/// Walk back through `owner`'s signatures (newest first) and collect Jupiter
+/// swaps, scanning at most `max_signatures` transactions
+pub async fn scan_trades(rpc: &RpcClient, owner: &Pubkey, max_signatures: usize) -> Result<Vec<HistoricalTrade>> {
+    let mut trades = Vec::new();
+    let mut before = None;
+    let mut scanned = 0;
+    while scanned < max_signatures {
+        let config = GetConfirmedSignaturesForAddress2Config {
+            before,
+            until: None,
+            limit: Some((max_signatures - scanned).min(1000)),
+            commitment: None,
+        };
+        let page = rpc.get_signatures_for_address_with_config(owner, config).await?;
+        let Some(last) = page.last() else { break };
+        before = Some(Signature::from_str(&last.signature)?);
+        scanned += page.len();
+
+        for status in page.iter().filter(|s| s.err.is_none()) {
+            if let Some(trade) = parse_trade(rpc, owner, &Signature::from_str(&status.signature)?).await? {
+                trades.push(trade);
+            }
+        }
+    }
+    Ok(trades)
+}
+
+/// Write trades as CSV for spreadsheet / PnL tooling
+pub fn write_trades_csv(trades: &[HistoricalTrade], path: impl AsRef<Path>) -> Result<()> {
+    let mut out = String::from("signature,slot,block_time,instruction,input_mint,in_amount,output_mint,out_amount,price,fee_lamports\n");
+    for t in trades {
+        out.push_str(&format!(
+            "{},{},{},{},{},{},{},{},{},{}\n",
+            t.signature,
+            t.slot,
+            t.block_time.map(|b| b.to_rfc3339()).unwrap_or_default(),
+            t.instruction.as_deref().unwrap_or_default(),
+            t.input_mint,
+            t.in_amount,
+            t.output_mint,
+            t.out_amount,
+            t.price,
+            t.fee_lamports,
+        ));
+    }
+    std::fs::write(path, out)?;
+    Ok(())
+}
+
+pub async fn trade_history_flow(
+    owner: &Pubkey,
+    max_signatures: usize,
+    csv_path: Option<&Path>,
+) -> Result<Vec<HistoricalTrade>> {
+    let cfg = Config::load()?;
+    let rpc = rpc_client(&cfg);
+
+    let trades = scan_trades(&rpc, owner, max_signatures).await?;
+    let mut lines: Vec<String> = trades
+        .iter()
+        .map(|t| {
+            format!(
+                "{} {:.6} {} → {:.6} {} @ {:.6} ({})",
+                t.block_time.map(|b| b.format("%Y-%m-%d %H:%M").to_string()).unwrap_or_else(|| "-".into()),
+                t.in_amount,
+                mint_label(&t.input_mint),
+                t.out_amount,
+                mint_label(&t.output_mint),
+                t.price,
+                t.signature,
+            )
+        })
+        .collect();
+    lines.push(format!("{} Jupiter trades in the last {max_signatures} signatures", trades.len()));
+    emit("trade_history", &trades, lines.join("\n"));
+    if let Some(path) = csv_path {
+        write_trades_csv(&trades, path)?;
+        progress(format!("wrote {}", path.display()));
+    }
+    Ok(trades)
+}
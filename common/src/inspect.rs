@@ -0,0 +1,285 @@
+// common/src/inspect.rs
+use crate::perps::PERPS_PROGRAM_ID;
+use crate::referral::{
+    JUPITER_AGGREGATOR_V6, RECURRING_PROGRAM_ID, REFERRAL_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID,
+    TRIGGER_PROGRAM_ID,
+};
+use crate::{emit, rpc_client, Config, Error, Lamports, Result};
+use serde::Serialize;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_program::address_lookup_table::state::AddressLookupTable;
+use solana_sdk::{
+    compute_budget, message::VersionedMessage, pubkey, pubkey::Pubkey, system_program,
+    transaction::VersionedTransaction,
+};
+use std::fmt;
+
+// ─────────────────── Transaction inspection ───────────────────
+//
+// Decode a transaction the way a wallet's confirmation screen should: who
+// pays, which accounts sign or get written, which programs run and what
+// compute budget is requested. Lookup-table accounts are resolved over RPC,
+// so v0 transactions show real addresses rather than table indexes.
+
+const MEMO_PROGRAM_ID: Pubkey = pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+const ADDRESS_LOOKUP_TABLE_PROGRAM_ID: Pubkey = pubkey!("AddressLookupTab1e1111111111111111111111111");
+
+/// Where an account key comes from
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase", tag = "kind")]
+pub enum AccountSource {
+    /// In the message itself
+    Static,
+    /// Loaded from an address lookup table
+    LookupTable { table: String, index: u8 },
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InspectedAccount {
+    pub address: String,
+    pub signer: bool,
+    pub writable: bool,
+    pub fee_payer: bool,
+    pub source: AccountSource,
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InspectedInstruction {
+    pub program_id: String,
+    /// Well-known program name, when recognised
+    pub program: Option<&'static str>,
+    /// Indexes into [`TransactionInspection::accounts`]
+    pub accounts: Vec<u8>,
+    pub data_len: usize,
+}
+
+/// Compute-budget program requests; `None` when not requested
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ComputeBudgetRequest {
+    pub unit_limit: Option<u32>,
+    pub unit_price_micro_lamports: Option<u64>,
+    pub heap_frame_bytes: Option<u32>,
+    pub loaded_accounts_data_size_limit: Option<u32>,
+}
+
+impl ComputeBudgetRequest {
+    /// Priority fee implied by price × limit (the runtime default of 200k CU per
+    /// instruction applies when no limit is set, so this is `None` then)
+    pub fn priority_fee(&self) -> Option<Lamports> {
+        let units = self.unit_limit? as u128;
+        let price = self.unit_price_micro_lamports? as u128;
+        Some(Lamports((units * price).div_ceil(1_000_000) as u64))
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionInspection {
+    /// "legacy" or "v0"
+    pub version: &'static str,
+    pub fee_payer: String,
+    pub recent_blockhash: String,
+    /// Required signatures and how many are already present
+    pub required_signatures: usize,
+    pub present_signatures: usize,
+    /// Static keys, then lookup-table writable, then lookup-table readonly, as the runtime orders them
+    pub accounts: Vec<InspectedAccount>,
+    pub instructions: Vec<InspectedInstruction>,
+    pub compute_budget: ComputeBudgetRequest,
+}
+
+impl TransactionInspection {
+    /// Every distinct program the transaction invokes at the top level
+    pub fn programs(&self) -> Vec<&str> {
+        let mut programs: Vec<&str> = Vec::new();
+        for ix in &self.instructions {
+            if !programs.contains(&ix.program_id.as_str()) {
+                programs.push(&ix.program_id);
+            }
+        }
+        programs
+    }
+
+    /// Accounts the transaction may write to
+    pub fn writable_accounts(&self) -> impl Iterator<Item = &InspectedAccount> {
+        self.accounts.iter().filter(|a| a.writable)
+    }
+}
+
+/// Decode a base64 transaction (as returned by /swap, /ultra/v1/order, …) and inspect it
+pub async fn inspect_transaction(rpc: &RpcClient, b64: &str) -> Result<TransactionInspection> {
+    let tx: VersionedTransaction = bincode::deserialize(&base64::decode(b64.trim())?)?;
+    inspect_versioned(rpc, &tx).await
+}
+
+/// Inspect an already decoded transaction, resolving its lookup tables over RPC
+pub async fn inspect_versioned(rpc: &RpcClient, tx: &VersionedTransaction) -> Result<TransactionInspection> {
+    let message = &tx.message;
+    // header counts and instruction indexes are trusted below; a malformed message would underflow or panic
+    message.sanitize().map_err(|e| Error::Deserialize(format!("malformed message: {e}")))?;
+    let header = message.header();
+    let keys = message.static_account_keys();
+    let required = header.num_required_signatures as usize;
+    let readonly_signed = header.num_readonly_signed_accounts as usize;
+    let readonly_unsigned = header.num_readonly_unsigned_accounts as usize;
+
+    let mut accounts: Vec<InspectedAccount> = keys
+        .iter()
+        .enumerate()
+        .map(|(i, key)| {
+            let signer = i < required;
+            let writable = if signer { i < required - readonly_signed } else { i < keys.len() - readonly_unsigned };
+            InspectedAccount {
+                address: key.to_string(),
+                signer,
+                writable,
+                fee_payer: i == 0,
+                source: AccountSource::Static,
+            }
+        })
+        .collect();
+
+    if let VersionedMessage::V0(v0) = message {
+        let mut writable = Vec::new();
+        let mut readonly = Vec::new();
+        for lookup in &v0.address_table_lookups {
+            let raw = rpc.get_account(&lookup.account_key).await?;
+            let table = AddressLookupTable::deserialize(&raw.data)
+                .map_err(|e| Error::Deserialize(format!("lookup table {}: {e}", lookup.account_key)))?;
+            let resolve = |index: u8| -> Result<InspectedAccount> {
+                let address = table.addresses.get(index as usize).ok_or_else(|| {
+                    Error::Deserialize(format!("lookup table {} has no index {index}", lookup.account_key))
+                })?;
+                Ok(InspectedAccount {
+                    address: address.to_string(),
+                    signer: false,
+                    writable: false,
+                    fee_payer: false,
+                    source: AccountSource::LookupTable { table: lookup.account_key.to_string(), index },
+                })
+            };
+            for &index in &lookup.writable_indexes {
+                writable.push(InspectedAccount { writable: true, ..resolve(index)? });
+            }
+            for &index in &lookup.readonly_indexes {
+                readonly.push(resolve(index)?);
+            }
+        }
+        accounts.extend(writable);
+        accounts.extend(readonly);
+    }
+
+    let mut compute_budget = ComputeBudgetRequest::default();
+    let mut instructions = Vec::new();
+    for ix in message.instructions() {
+        let program_id = accounts
+            .get(ix.program_id_index as usize)
+            .map(|a| a.address.clone())
+            .ok_or_else(|| Error::Deserialize(format!("program index {} out of range", ix.program_id_index)))?;
+        let program = program_id.parse().ok().and_then(program_name);
+        if program_id == compute_budget::id().to_string() {
+            parse_compute_budget(&ix.data, &mut compute_budget);
+        }
+        instructions.push(InspectedInstruction {
+            program_id,
+            program,
+            accounts: ix.accounts.clone(),
+            data_len: ix.data.len(),
+        });
+    }
+
+    Ok(TransactionInspection {
+        version: match message {
+            VersionedMessage::Legacy(_) => "legacy",
+            VersionedMessage::V0(_) => "v0",
+        },
+        fee_payer: keys.first().map(|k| k.to_string()).unwrap_or_default(),
+        recent_blockhash: message.recent_blockhash().to_string(),
+        required_signatures: required,
+        present_signatures: tx.signatures.iter().filter(|s| **s != Default::default()).count(),
+        accounts,
+        instructions,
+        compute_budget,
+    })
+}
+
+/// ComputeBudgetInstruction: a one-byte tag then little-endian fields
+fn parse_compute_budget(data: &[u8], out: &mut ComputeBudgetRequest) {
+    let u32_at = |d: &[u8]| d.get(1..5).map(|b| u32::from_le_bytes(b.try_into().unwrap()));
+    match data.first() {
+        Some(1) => out.heap_frame_bytes = u32_at(data),
+        Some(2) => out.unit_limit = u32_at(data),
+        Some(3) => out.unit_price_micro_lamports = data.get(1..9).map(|b| u64::from_le_bytes(b.try_into().unwrap())),
+        Some(4) => out.loaded_accounts_data_size_limit = u32_at(data),
+        _ => {}
+    }
+}
+
+fn program_name(id: Pubkey) -> Option<&'static str> {
+    Some(match id {
+        id if id == system_program::id() => "System",
+        id if id == compute_budget::id() => "Compute Budget",
+        id if id == TOKEN_PROGRAM_ID => "Token",
+        id if id == TOKEN_2022_PROGRAM_ID => "Token-2022",
+        id if id == spl_associated_token_account::id() => "Associated Token Account",
+        id if id == MEMO_PROGRAM_ID => "Memo",
+        id if id == ADDRESS_LOOKUP_TABLE_PROGRAM_ID => "Address Lookup Table",
+        id if id == JUPITER_AGGREGATOR_V6 => "Jupiter Aggregator v6",
+        id if id == RECURRING_PROGRAM_ID => "Jupiter Recurring",
+        id if id == TRIGGER_PROGRAM_ID => "Jupiter Trigger",
+        id if id == PERPS_PROGRAM_ID => "Jupiter Perps",
+        id if id == REFERRAL_PROGRAM_ID => "Jupiter Referral",
+        _ => return None,
+    })
+}
+
+impl fmt::Display for TransactionInspection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} transaction, blockhash {}", self.version, self.recent_blockhash)?;
+        writeln!(f, "fee payer  {}", self.fee_payer)?;
+        writeln!(f, "signatures {}/{} present", self.present_signatures, self.required_signatures)?;
+        writeln!(f, "accounts:")?;
+        for (i, a) in self.accounts.iter().enumerate() {
+            let role = match (a.signer, a.writable) {
+                (true, true) => "signer, writable",
+                (true, false) => "signer",
+                (false, true) => "writable",
+                (false, false) => "readonly",
+            };
+            let source = match &a.source {
+                AccountSource::Static => String::new(),
+                AccountSource::LookupTable { table, index } => format!(" (table {table}[{index}])"),
+            };
+            writeln!(f, "  {i:>3} {} [{role}]{source}", a.address)?;
+        }
+        writeln!(f, "instructions:")?;
+        for (i, ix) in self.instructions.iter().enumerate() {
+            let name = ix.program.map(|p| format!(" {p}")).unwrap_or_default();
+            writeln!(f, "  {i:>3} {}{name}: {} accounts, {} bytes", ix.program_id, ix.accounts.len(), ix.data_len)?;
+        }
+        let cb = &self.compute_budget;
+        write!(
+            f,
+            "compute budget: limit {}, price {}",
+            cb.unit_limit.map(|u| format!("{u} CU")).unwrap_or_else(|| "default".into()),
+            cb.unit_price_micro_lamports.map(|p| format!("{p} µlamports/CU")).unwrap_or_else(|| "none".into()),
+        )?;
+        if let Some(fee) = cb.priority_fee() {
+            write!(f, " (priority fee ≤ {fee})")?;
+        }
+        Ok(())
+    }
+}
+
+/// Inspect a base64 transaction and print the breakdown
+pub async fn inspect_transaction_flow(b64: &str) -> Result<TransactionInspection> {
+    let cfg = Config::load()?;
+    let rpc = rpc_client(&cfg);
+
+    let inspection = inspect_transaction(&rpc, b64).await?;
+    emit("inspect", &inspection, &inspection);
+    Ok(inspection)
+}
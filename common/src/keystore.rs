@@ -0,0 +1,178 @@
+// common/src/keystore.rs
+use crate::{read_keypair, ConfigError, Error, Result};
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Nonce};
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+use solana_sdk::signature::{Keypair, Signer};
+use std::env;
+use std::path::Path;
+use std::sync::OnceLock;
+use zeroize::Zeroizing;
+
+// ─────────────────── Encrypted keystore ───────────────────
+//
+// A keypair encrypted with AES-256-GCM under a scrypt-derived key, so the
+// secret never sits in plaintext in .env. Point KEYSTORE_PATH at the file;
+// the passphrase comes from the installed hook, KEYSTORE_PASSPHRASE, or a prompt.
+
+/// scrypt cost: 2^15 iterations, r = 8, p = 1 (about 100ms, 32 MiB)
+const SCRYPT_LOG_N: u8 = 15;
+const SCRYPT_R: u32 = 8;
+const SCRYPT_P: u32 = 1;
+
+/// On-disk JSON layout; binary fields are base64
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Keystore {
+    pub version: u8,
+    /// Public key of the stored keypair, readable without the passphrase
+    pub pubkey: String,
+    pub kdf: KdfParams,
+    pub nonce: String,
+    pub ciphertext: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct KdfParams {
+    pub log_n: u8,
+    pub r: u32,
+    pub p: u32,
+    pub salt: String,
+}
+
+impl Keystore {
+    /// Encrypt `kp` under `passphrase`
+    pub fn encrypt(kp: &Keypair, passphrase: &str) -> Result<Self> {
+        let mut salt = [0u8; 16];
+        OsRng.fill_bytes(&mut salt);
+        let kdf = KdfParams { log_n: SCRYPT_LOG_N, r: SCRYPT_R, p: SCRYPT_P, salt: base64::encode(salt) };
+        let cipher = kdf.cipher(passphrase)?;
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let ciphertext = cipher
+            .encrypt(&nonce, Zeroizing::new(kp.to_bytes()).as_slice())
+            .map_err(|_| anyhow!("encryption failed"))?;
+        Ok(Keystore {
+            version: 1,
+            pubkey: kp.pubkey().to_string(),
+            kdf,
+            nonce: base64::encode(nonce),
+            ciphertext: base64::encode(ciphertext),
+        })
+    }
+
+    /// Decrypt with `passphrase`; a wrong passphrase fails the GCM tag check
+    pub fn decrypt(&self, passphrase: &str) -> Result<Keypair> {
+        if self.version != 1 {
+            return Err(Error::Deserialize(format!("keystore: unsupported version {}", self.version)));
+        }
+        let nonce = base64::decode(&self.nonce)?;
+        if nonce.len() != 12 {
+            return Err(Error::Deserialize(format!("keystore: nonce must be 12 bytes, got {}", nonce.len())));
+        }
+        let plaintext = Zeroizing::new(
+            self.kdf
+                .cipher(passphrase)?
+                .decrypt(Nonce::from_slice(&nonce), base64::decode(&self.ciphertext)?.as_slice())
+                .map_err(|_| Error::Invalid("wrong passphrase or corrupted keystore".into()))?,
+        );
+        let kp = Keypair::from_bytes(&plaintext).map_err(|e| Error::Deserialize(format!("keystore: {e}")))?;
+        if kp.pubkey().to_string() != self.pubkey {
+            let labelled = &self.pubkey;
+            return Err(Error::Invalid(format!("keystore decrypted to {} but is labelled {labelled}", kp.pubkey())));
+        }
+        Ok(kp)
+    }
+
+    pub fn read(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| std::io::Error::new(e.kind(), format!("{}: {e}", path.display())))?;
+        serde_json::from_str(&text).map_err(|e| Error::Deserialize(format!("{}: not a keystore: {e}", path.display())))
+    }
+
+    pub fn write(&self, path: impl AsRef<Path>) -> Result<()> {
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
+
+impl KdfParams {
+    fn cipher(&self, passphrase: &str) -> Result<Aes256Gcm> {
+        let params = scrypt::Params::new(self.log_n, self.r, self.p, 32).map_err(|e| anyhow!("scrypt params: {e}"))?;
+        let mut key = Zeroizing::new([0u8; 32]);
+        scrypt::scrypt(passphrase.as_bytes(), &base64::decode(&self.salt)?, &params, &mut *key)
+            .map_err(|e| anyhow!("scrypt: {e}"))?;
+        Aes256Gcm::new_from_slice(&*key).map_err(|e| anyhow!("cipher key: {e}").into())
+    }
+}
+
+// ─────────────────── Passphrase ───────────────────
+
+type PassphraseHook = Box<dyn Fn(&Path) -> Option<String> + Send + Sync>;
+
+static PASSPHRASE_HOOK: OnceLock<PassphraseHook> = OnceLock::new();
+
+/// Install a function that supplies the passphrase for a keystore (a secrets
+/// manager, OS keychain, …). Returning `None` falls through to
+/// KEYSTORE_PASSPHRASE and then the prompt. Can only be installed once per process.
+pub fn install_passphrase_hook(hook: impl Fn(&Path) -> Option<String> + Send + Sync + 'static) -> Result<()> {
+    PASSPHRASE_HOOK
+        .set(Box::new(hook))
+        .map_err(|_| anyhow!("a passphrase hook is already installed").into())
+}
+
+/// Hook, else `KEYSTORE_PASSPHRASE`, else an interactive prompt
+fn passphrase(path: &Path) -> Result<Zeroizing<String>> {
+    if let Some(p) = PASSPHRASE_HOOK.get().and_then(|hook| hook(path)) {
+        return Ok(p.into());
+    }
+    if let Ok(p) = env::var("KEYSTORE_PASSPHRASE") {
+        return Ok(p.into());
+    }
+    Ok(rpassword::prompt_password(format!("Passphrase for {}: ", path.display()))?.into())
+}
+
+/// Decrypt the keystore at `path`, asking for its passphrase
+pub(crate) fn keystore_keypair(path: &Path) -> Result<Keypair, ConfigError> {
+    let invalid =
+        |e: Error| ConfigError::Invalid { var: "KEYSTORE_PATH", reason: format!("{}: {e}", path.display()) };
+    let keystore = Keystore::read(path).map_err(invalid)?;
+    keystore.decrypt(&passphrase(path).map_err(invalid)?).map_err(invalid)
+}
+
+// ─────────────────── Import ───────────────────
+
+/// Encrypt a keypair into a keystore at `out`. The key is read from `source`
+/// (any format [`crate::read_keypair`] accepts) or pasted at a prompt; the
+/// passphrase is asked for twice.
+pub fn keystore_import_flow(source: Option<&Path>, out: &Path) -> Result<Keystore> {
+    if out.exists() {
+        return Err(Error::Invalid(format!("{} already exists; not overwriting", out.display())));
+    }
+    let kp = match source {
+        Some(path) => read_keypair(path).map_err(|e| Error::Invalid(format!("{}: {e}", path.display())))?,
+        None => {
+            let secret = Zeroizing::new(rpassword::prompt_password("Secret key (base58, hex or [..] bytes): ")?);
+            crate::parse_keypair(&secret).map_err(Error::Invalid)?
+        }
+    };
+    let passphrase = Zeroizing::new(rpassword::prompt_password("New passphrase: ")?);
+    if passphrase.is_empty() {
+        return Err(Error::Invalid("passphrase must not be empty".into()));
+    }
+    if *Zeroizing::new(rpassword::prompt_password("Repeat passphrase: ")?) != *passphrase {
+        return Err(Error::Invalid("passphrases don't match".into()));
+    }
+
+    let keystore = Keystore::encrypt(&kp, &passphrase)?;
+    keystore.write(out)?;
+    let text = format!(
+        "Wrote keystore for {} to {}\nSet KEYSTORE_PATH={} and remove SECRET_KEY from .env",
+        keystore.pubkey,
+        out.display(),
+        out.display()
+    );
+    crate::emit("keystore", &serde_json::json!({ "pubkey": keystore.pubkey, "path": out }), text);
+    Ok(keystore)
+}
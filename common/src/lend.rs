@@ -0,0 +1,421 @@
+// common/src/lend.rs
+use crate::{
+    api_url, emit, fee_payer_address, mainnet_guard, rpc_client, send_and_confirm, sign_versioned_tx, token_balance,
+    try_http_client, tx_link, ApiResponseExt, Config, Error, JupiterApi, JupiterReqExt, Result, TokenAmount, TxSigner,
+    WSOL_MINT,
+};
+use base64::decode;
+use bincode::deserialize;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::{pubkey::Pubkey, transaction::VersionedTransaction};
+use std::str::FromStr;
+
+// ─────────────────── Lend / Earn API (/lend/v1/earn) ───────────────────
+
+fn earn_url() -> String {
+    format!("{}/earn", api_url(JupiterApi::Lend))
+}
+
+/// Failure of a Lend call that got an answer, but not the one asked for
+#[derive(Debug, thiserror::Error)]
+pub enum LendError {
+    #[error("no Lend vault for asset {0}")]
+    UnknownAsset(String),
+    /// A deposit, withdraw or redeem answered without a transaction
+    #[error("Lend {endpoint} failed: {body}")]
+    NoTransaction { endpoint: &'static str, body: serde_json::Value },
+}
+
+/// Unsigned transaction returned by the Earn action endpoints
+#[derive(Deserialize, Debug)]
+pub(crate) struct LendTxResponse {
+    /// Base64-encoded unsigned transaction
+    #[serde(default)]
+    pub transaction: Option<String>,
+
+    /// Any additional fields (e.g. error)
+    #[serde(flatten)]
+    pub extra: serde_json::Value,
+}
+
+#[derive(Serialize)]
+struct DepositRequest<'a> {
+    asset: &'a str,
+    signer: &'a str,
+    /// raw units, as a string
+    amount: String,
+}
+
+/// Deposit of an underlying asset (e.g. USDC) into its Earn vault
+#[derive(Debug, Clone)]
+pub struct LendDepositParams {
+    /// Mint of the underlying asset
+    pub asset: String,
+    /// Amount of the asset
+    pub amount: TokenAmount,
+}
+
+impl LendDepositParams {
+    pub fn validate(&self) -> Result<()> {
+        Pubkey::from_str(&self.asset).map_err(|_| Error::Invalid(format!("invalid asset mint: {}", self.asset)))?;
+        if self.amount.raw == 0 {
+            return Err(Error::Invalid("deposit amount must be greater than zero".into()));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct LendDepositResult {
+    pub signature: String,
+    pub asset: String,
+    pub amount: TokenAmount,
+}
+
+/// Build an unsigned deposit transaction for `signer`
+pub async fn lend_deposit_tx(http: &Client, signer: &str, params: &LendDepositParams) -> Result<String> {
+    params.validate()?;
+    let body = DepositRequest { asset: &params.asset, signer, amount: params.amount.raw.to_string() };
+    let resp: LendTxResponse = http
+        .post(format!("{}/deposit", earn_url()))
+        .with_jupiter_key()
+        .json(&body)
+        .send_with_retry().await?
+        .api_json().await?;
+    match resp.transaction {
+        Some(tx) if !tx.is_empty() => Ok(tx),
+        _ => Err(LendError::NoTransaction { endpoint: "deposit", body: resp.extra }.into()),
+    }
+}
+
+pub async fn lend_deposit_flow(kp: &dyn TxSigner, params: LendDepositParams) -> Result<LendDepositResult> {
+    let cfg  = Config::load()?;
+    let http = try_http_client()?;
+    let rpc  = rpc_client(&cfg);
+    mainnet_guard(&rpc).await?;
+
+    // 1. Get deposit transaction
+    let tx_b64 = lend_deposit_tx(&http, &kp.address().to_string(), &params).await?;
+
+    // 2. Decode, sign, and send via RPC
+    let mut tx: VersionedTransaction = deserialize(&decode(&tx_b64)?)?;
+    sign_versioned_tx(&mut tx, kp).await?;
+    let signature = send_and_confirm(&rpc, &tx, rpc.commitment()).await?;
+    let result = LendDepositResult { signature: signature.to_string(), asset: params.asset, amount: params.amount };
+    emit("lend_deposit", &result, format!("Lend deposit confirmed: {}", tx_link(signature)));
+    Ok(result)
+}
+
+
+// ─────────────────── Lend vault tokens (/earn/tokens) ───────────────────
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LendAsset {
+    pub address: String,
+    pub symbol: String,
+    pub decimals: u8,
+    #[serde(default)]
+    pub price: Option<String>,
+}
+
+/// A Lend vault share token (jlToken) and its exchange rate
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LendToken {
+    /// Share token mint
+    pub address: String,
+    pub symbol: String,
+    pub decimals: u8,
+    /// Underlying asset mint
+    pub asset_address: String,
+    pub asset: LendAsset,
+    pub total_assets: String,
+    pub total_supply: String,
+    /// Shares minted for one whole asset unit (10^decimals raw)
+    pub convert_to_shares: String,
+    /// Assets redeemed for one whole share unit (10^decimals raw)
+    pub convert_to_assets: String,
+    /// Supply APR in basis points
+    #[serde(default)]
+    pub total_rate: Option<String>,
+}
+
+impl LendToken {
+    fn rate(raw: &str) -> u128 {
+        raw.parse().unwrap_or(0)
+    }
+
+    /// Raw underlying assets redeemable for `shares`
+    pub fn shares_to_assets(&self, shares: u64) -> u64 {
+        (shares as u128 * Self::rate(&self.convert_to_assets) / 10u128.pow(self.decimals as u32)) as u64
+    }
+
+    /// Raw shares minted (or burned) for `assets`
+    pub fn assets_to_shares(&self, assets: u64) -> u64 {
+        (assets as u128 * Self::rate(&self.convert_to_shares) / 10u128.pow(self.asset.decimals as u32)) as u64
+    }
+}
+
+pub async fn get_lend_tokens(http: &Client) -> Result<Vec<LendToken>> {
+    Ok(http
+        .get(format!("{}/tokens", earn_url()))
+        .with_jupiter_key()
+        .send_with_retry().await?
+        .api_json().await?)
+}
+
+/// The vault for underlying `asset`
+pub async fn get_lend_token(http: &Client, asset: &str) -> Result<LendToken> {
+    get_lend_tokens(http)
+        .await?
+        .into_iter()
+        .find(|t| t.asset_address == asset)
+        .ok_or_else(|| LendError::UnknownAsset(asset.to_string()).into())
+}
+
+
+// ─────────────────── Lend Withdraw (/earn/withdraw, /earn/redeem) ───────────────────
+
+#[derive(Debug, Clone, Copy)]
+pub enum LendWithdrawAmount {
+    /// Withdraw this much of the underlying asset
+    Assets(TokenAmount),
+    /// Redeem this many vault shares, in the share token's decimals
+    Shares(TokenAmount),
+}
+
+#[derive(Debug, Clone)]
+pub struct LendWithdrawParams {
+    /// Mint of the underlying asset
+    pub asset: String,
+    pub amount: LendWithdrawAmount,
+}
+
+impl LendWithdrawParams {
+    pub fn validate(&self) -> Result<()> {
+        Pubkey::from_str(&self.asset).map_err(|_| Error::Invalid(format!("invalid asset mint: {}", self.asset)))?;
+        let (LendWithdrawAmount::Assets(amount) | LendWithdrawAmount::Shares(amount)) = self.amount;
+        if amount.raw == 0 {
+            return Err(Error::Invalid("withdraw amount must be greater than zero".into()));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct LendWithdrawResult {
+    pub signature: String,
+    pub asset: String,
+    /// Shares burned, estimated from the vault exchange rate
+    pub shares: TokenAmount,
+    /// Assets the exchange rate promised
+    pub expected_assets: TokenAmount,
+    /// Assets that actually arrived in the wallet; lamports for SOL, fee excluded
+    pub received: TokenAmount,
+}
+
+/// Build an unsigned withdraw (by assets) or redeem (by shares) transaction
+pub async fn lend_withdraw_tx(http: &Client, signer: &str, params: &LendWithdrawParams) -> Result<String> {
+    params.validate()?;
+    let (endpoint, body) = match params.amount {
+        LendWithdrawAmount::Assets(amount) => (
+            "withdraw",
+            serde_json::json!({ "asset": params.asset, "signer": signer, "amount": amount.raw.to_string() }),
+        ),
+        LendWithdrawAmount::Shares(shares) => (
+            "redeem",
+            serde_json::json!({ "asset": params.asset, "signer": signer, "shares": shares.raw.to_string() }),
+        ),
+    };
+    let resp: LendTxResponse = http
+        .post(format!("{}/{}", earn_url(), endpoint))
+        .with_jupiter_key()
+        .json(&body)
+        .send_with_retry().await?
+        .api_json().await?;
+    match resp.transaction {
+        Some(tx) if !tx.is_empty() => Ok(tx),
+        _ => Err(LendError::NoTransaction { endpoint, body: resp.extra }.into()),
+    }
+}
+
+pub async fn lend_withdraw_flow(kp: &dyn TxSigner, params: LendWithdrawParams) -> Result<LendWithdrawResult> {
+    let cfg  = Config::load()?;
+    let http = try_http_client()?;
+    let rpc  = rpc_client(&cfg);
+    mainnet_guard(&rpc).await?;
+    let mint = Pubkey::from_str(&params.asset)?;
+
+    // 1. Convert between shares and assets at the current rate
+    let vault = get_lend_token(&http, &params.asset).await?;
+    let (shares, expected_assets) = match params.amount {
+        LendWithdrawAmount::Assets(assets) => {
+            (TokenAmount::new(vault.assets_to_shares(assets.raw), vault.decimals), assets)
+        }
+        LendWithdrawAmount::Shares(shares) => {
+            (shares, TokenAmount::new(vault.shares_to_assets(shares.raw), vault.asset.decimals))
+        }
+    };
+
+    // 2. Get withdraw/redeem transaction
+    let tx_b64 = lend_withdraw_tx(&http, &kp.address().to_string(), &params).await?;
+
+    // 3. Decode, sign, and send via RPC, measuring what actually arrived
+    let mut tx: VersionedTransaction = deserialize(&decode(&tx_b64)?)?;
+    // SOL arrives unwrapped, less the fee if the wallet pays it; add that back so it isn't counted against the vault
+    let fee = if params.asset == WSOL_MINT && fee_payer_address(kp) == kp.address() {
+        rpc.get_fee_for_message(&tx.message).await?
+    } else {
+        0
+    };
+    let before = withdrawn_balance(&rpc, &kp.address(), &mint).await?;
+    sign_versioned_tx(&mut tx, kp).await?;
+    let signature = send_and_confirm(&rpc, &tx, rpc.commitment()).await?;
+    let received = (withdrawn_balance(&rpc, &kp.address(), &mint).await? + fee).saturating_sub(before);
+    let received = TokenAmount::new(received, vault.asset.decimals);
+    let result = LendWithdrawResult {
+        signature: signature.to_string(),
+        asset: params.asset,
+        shares,
+        expected_assets,
+        received,
+    };
+    emit("lend_withdraw", &result, format!("Lend withdraw confirmed: {}", tx_link(signature)));
+    Ok(result)
+}
+
+/// Where a withdrawal of `mint` lands: lamports for SOL, which Lend unwraps, else the token account
+async fn withdrawn_balance(rpc: &RpcClient, owner: &Pubkey, mint: &Pubkey) -> Result<u64> {
+    if mint.to_string() == WSOL_MINT {
+        Ok(rpc.get_balance(owner).await?)
+    } else {
+        Ok(token_balance(rpc, owner, mint).await?)
+    }
+}
+
+// ─────────────────── Lend positions & earnings (/earn/positions, /earn/earnings) ───────────────────
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LendPositionRaw {
+    pub token: LendToken,
+    pub owner_address: String,
+    pub shares: String,
+    /// Raw underlying assets the shares are worth now
+    pub underlying_assets: String,
+    /// Raw underlying balance still in the wallet
+    #[serde(default)]
+    pub underlying_balance: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LendEarnings {
+    /// Share token mint
+    pub address: String,
+    pub owner_address: String,
+    pub total_deposits: String,
+    pub total_withdraws: String,
+    pub total_assets: String,
+    pub earnings: String,
+}
+
+/// One Lend position with its cost basis and yield, all in raw asset units
+#[derive(Serialize, Debug, Clone)]
+pub struct LendPosition {
+    pub symbol: String,
+    pub asset_mint: String,
+    pub share_mint: String,
+    pub decimals: u8,
+    pub shares: u64,
+    /// Net deposited (deposits - withdrawals)
+    pub deposited: u64,
+    pub current_value: u64,
+    pub accrued_yield: u64,
+}
+
+impl LendPosition {
+    /// Convert a raw amount of this position's asset to UI units
+    pub fn ui(&self, raw: u64) -> f64 {
+        raw as f64 / 10f64.powi(self.decimals as i32)
+    }
+}
+
+fn raw(s: &str) -> u64 {
+    s.parse().unwrap_or(0)
+}
+
+pub async fn get_lend_positions_raw(http: &Client, user: &str) -> Result<Vec<LendPositionRaw>> {
+    Ok(http
+        .get(format!("{}/positions?users={}", earn_url(), user))
+        .with_jupiter_key()
+        .send_with_retry().await?
+        .api_json().await?)
+}
+
+pub async fn get_lend_earnings(http: &Client, user: &str, share_mints: &[&str]) -> Result<Vec<LendEarnings>> {
+    Ok(http
+        .get(format!("{}/earnings?user={}&positions={}", earn_url(), user, share_mints.join(",")))
+        .with_jupiter_key()
+        .send_with_retry().await?
+        .api_json().await?)
+}
+
+/// Every non-empty Lend position of `user` joined with its earnings
+pub async fn get_lend_positions(http: &Client, user: &str) -> Result<Vec<LendPosition>> {
+    let positions: Vec<LendPositionRaw> = get_lend_positions_raw(http, user)
+        .await?
+        .into_iter()
+        .filter(|p| raw(&p.shares) > 0)
+        .collect();
+    if positions.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let share_mints: Vec<&str> = positions.iter().map(|p| p.token.address.as_str()).collect();
+    let earnings = get_lend_earnings(http, user, &share_mints).await?;
+
+    Ok(positions
+        .iter()
+        .map(|p| {
+            let e = earnings.iter().find(|e| e.address == p.token.address);
+            LendPosition {
+                symbol: p.token.asset.symbol.clone(),
+                asset_mint: p.token.asset_address.clone(),
+                share_mint: p.token.address.clone(),
+                decimals: p.token.asset.decimals,
+                shares: raw(&p.shares),
+                deposited: e
+                    .map(|e| raw(&e.total_deposits).saturating_sub(raw(&e.total_withdraws)))
+                    .unwrap_or(0),
+                current_value: raw(&p.underlying_assets),
+                accrued_yield: e.map(|e| raw(&e.earnings)).unwrap_or(0),
+            }
+        })
+        .collect())
+}
+
+pub async fn lend_positions_flow(owner: &Pubkey) -> Result<Vec<LendPosition>> {
+    Config::load()?;
+    let http = try_http_client()?;
+
+    let positions = get_lend_positions(&http, &owner.to_string()).await?;
+    let text = positions
+        .iter()
+        .map(|p| {
+            format!(
+                "{:<8} deposited {:>14.6}  value {:>14.6}  yield {:>12.6}",
+                p.symbol,
+                p.ui(p.deposited),
+                p.ui(p.current_value),
+                p.ui(p.accrued_yield)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    emit("lend_positions", &positions, text);
+    Ok(positions)
+}
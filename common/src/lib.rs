@@ -1,591 +1,291 @@
 // common/src/lib.rs
-use serde::Serialize;
-use anyhow::Result;
-use base64::{decode, encode};
-use bincode::{deserialize, serialize};
-use bs58;
-use dotenv::dotenv;
-use reqwest::Client;
-use serde::Deserialize;
-use serde::Serialize as SerdeSerialize;
-use serde_json::json;
-use solana_client::rpc_client::RpcClient;
-use solana_sdk::{
-    signature::{read_keypair_file, Keypair, Signer},
-    transaction::VersionedTransaction,
-};
-use std::env;
-use solana_program::address_lookup_table::state::AddressLookupTable;
-use solana_sdk::{
-    address_lookup_table_account::AddressLookupTableAccount,
-    instruction::Instruction,
-    message::{v0::Message, VersionedMessage},
-    pubkey::Pubkey,
-};
-use solana_sdk::{
-    instruction::{AccountMeta},
-    
-};
-use std::str::FromStr;
-use solana_program::instruction::CompiledInstruction;
-
-// ─────────────────── Configuration ───────────────────
-
-pub struct Config {
-    pub rpc_url: String,
-    pub keypair_path: String,
-}
-
-pub fn load_config() -> Config {
-    dotenv().ok();
-    let rpc_url = env::var("RPC_URL").expect("RPC_URL must be set");
-    let keypair_path = env::var("KEYPAIR_PATH").unwrap_or_default();
-    Config { rpc_url, keypair_path }
-}
-
-pub fn rpc_client(cfg: &Config) -> RpcClient {
-    RpcClient::new(cfg.rpc_url.clone())
-}
-
-pub fn http_client() -> Client {
-    Client::builder().build().unwrap()
-}
-
-trait JupiterReqExt {
-    fn with_jupiter_key(self) -> Self;
-}
-/// If API_KEY provided as `API_KEY` in env var, attach it as the `X-API-KEY`
-impl JupiterReqExt for reqwest::RequestBuilder {
-    fn with_jupiter_key(self) -> Self {
-        match std::env::var("API_KEY") {
-            Ok(key) if !key.is_empty() => self.header("X-API-KEY", key),
-            _ => self,
-        }
-    }
-}
-
-
-/// Load Keypair from SECRET_KEY (base58) env var or fallback to KEYPAIR_PATH file
-pub fn keypair(cfg: &Config) -> Keypair {
-    if let Ok(secret_b58) = env::var("SECRET_KEY") {
-        let bytes = bs58::decode(secret_b58)
-            .into_vec()
-            .expect("Invalid base58 in SECRET_KEY");
-        Keypair::from_bytes(&bytes).expect("Failed to construct Keypair from SECRET_KEY")
-    } else {
-        read_keypair_file(&cfg.keypair_path).expect("Failed to read keypair from file")
-    }
-}
-
-
-
-// Helper to sign a versioned transaction
-fn sign_versioned_tx(tx: &mut VersionedTransaction, kp: &Keypair) {
-    let message = tx.message.clone();
-    let serialized = message.serialize();
-    let signature = kp.try_sign_message(&serialized)
-        .expect("Failed to sign transaction");
-    tx.signatures = vec![signature];
-}
-
-// ────────── optional integrator-fee helper ──────────
-fn integrator_fee() -> Option<(String, u64)> {
-    let acc = std::env::var("FEE_ACCOUNT").ok().filter(|s| !s.is_empty());
-    let bps = std::env::var("FEE_BPS").ok().and_then(|s| s.parse::<u64>().ok());
-    match (acc, bps) {
-        (Some(a), Some(b)) if b > 0 => Some((a, b)),   // both present & valid
-        _ => None,                                     // fee disabled
-    }
-}
-
-
-// ─────────────────── Swap Flow (/quote -> /swap -> send) ───────────────────
-
-#[derive(Serialize, Deserialize, Debug)]
-pub struct QuoteResponse {
-    pub inputMint: String,
-    pub inAmount: String,
-    pub outputMint: String,
-    pub outAmount: String,
-    pub otherAmountThreshold: String,
-    pub swapMode: String,
-    pub slippageBps: u64,
-    #[serde(default)] pub platformFee: Option<serde_json::Value>,
-    pub priceImpactPct: String,
-    pub routePlan: Vec<serde_json::Value>,
-    pub contextSlot: u64,
-    pub timeTaken: f64,
-}
-
-#[derive(Serialize, Deserialize, Debug)]
-pub struct SwapResponse {
-    #[serde(rename = "swapTransaction")]
-    pub swap_transaction: String,
-    #[serde(rename = "lastValidBlockHeight")]
-    pub last_valid_block_height: u64,
-}
-
-pub async fn swap_flow() -> Result<()> {
-    let cfg = load_config();
-    let http = http_client();
-    let rpc  = rpc_client(&cfg);
-    let mut kp = keypair(&cfg);
-    let user_pubkey = kp.pubkey().to_string();
-
-    // 1. Get quote for 0.05 SOL (50_000_000 lamports)
-    let input_mint  = "So11111111111111111111111111111111111111112";
-    let output_mint = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
-    let amount      = 50_000_000u64;
-    let slippage    = 50;
-    let fee_q = integrator_fee()
-        .map(|(_, bps)| format!("&platformFeeBps={}", bps))
-        .unwrap_or_default();
-    let quote_url = format!(
-        "https://lite-api.jup.ag/swap/v1/quote?inputMint={}&outputMint={}&amount={}&slippageBps={}{}",
-        input_mint, output_mint, amount, slippage, fee_q
-    );
-    let quote: QuoteResponse = http.get(&quote_url).with_jupiter_key().send().await?.json().await?;
-
-    // 2. Build swap transaction
-     let mut swap_body = json!({
-     "quoteResponse": quote,
-     "userPublicKey": user_pubkey,
-     "payer": user_pubkey, // Use same account for both user and payer
-    });
-    if let Some((acc, _)) = integrator_fee() {
-        swap_body["feeAccount"] = acc.into();
-    }
-    let swap_resp: SwapResponse = http
-        .post("https://lite-api.jup.ag/swap/v1/swap")
-        .with_jupiter_key()
-        .json(&swap_body)
-        .send().await?
-        .json().await?;
-
-    // 3. Decode, sign, and send via RPC
-    let mut tx: VersionedTransaction = deserialize(&decode(&swap_resp.swap_transaction)?)?;
-    sign_versioned_tx(&mut tx, &kp);
-    let signature = rpc.send_and_confirm_transaction(&tx)?;
-    println!("Swap confirmed: {}", signature);
-
-    Ok(())
-}
-
-
-// ────────── Swap Instructions Flow (/swap/v1/swap-instructions → build & send) ──────────
-
-
-
-#[derive(Deserialize, Debug)]
-#[serde(untagged)]
-enum Ci {
-    Json(InstructionJson),
-    B64(String), 
-}
-
-#[derive(Deserialize, Debug)]
-struct InstructionJson {
-    #[serde(rename = "programId")]
-    program_id: String,
-    accounts: Vec<AccountMetaJson>,
-    data: String, // base-64
-}
-
-#[derive(Deserialize, Debug)]
-struct AccountMetaJson {
-    pubkey: String,
-    isSigner: bool,
-    isWritable: bool,
-}
-
-impl TryFrom<InstructionJson> for Instruction {
-    type Error = anyhow::Error;
-
-    fn try_from(j: InstructionJson) -> Result<Self, Self::Error> {
-        use std::str::FromStr;
-
-        let program_id = Pubkey::from_str(&j.program_id)?;
-        let accounts = j
-            .accounts
-            .into_iter()
-            .map(|a| {
-                let pk = Pubkey::from_str(&a.pubkey)?;
-                Ok(if a.isWritable {
-                    AccountMeta::new(pk, a.isSigner)
-                } else {
-                    AccountMeta::new_readonly(pk, a.isSigner)
-                })
-            })
-            .collect::<Result<Vec<_>, anyhow::Error>>()?;
-
-        Ok(Instruction {
-            program_id,
-            data: base64::decode(j.data)?,
-            accounts,
-        })
-    }
-}
-
-impl Ci {
-    fn into_instruction(self) -> Result<Instruction, anyhow::Error> {
-        match self {
-            Ci::Json(j) => j.try_into(),
-            Ci::B64(_) => anyhow::bail!(
-                "legacy CompiledInstruction returned – \
-                 re-issue the API call with \"instructionFormat\":\"json\""
-            ),
-        }
-    }
-}
-
-// ───────────────────────── response struct ─────────────────────────
-#[derive(Deserialize, Debug)]
-struct SwapInstructionResponse {
-    #[serde(default, rename = "tokenLedgerInstruction")]
-    token_ledger_instruction: Option<Ci>,
-
-    #[serde(default, rename = "computeBudgetInstructions")]
-    compute_budget_instructions: Option<Vec<Ci>>,
-
-    #[serde(default, rename = "setupInstructions")]
-    setup_instructions: Option<Vec<Ci>>,
-
-    #[serde(default, rename = "swapInstruction")]
-    swap_instruction: Option<Ci>,
-
-    
-    #[serde(default, rename = "cleanupInstruction")]
-    cleanup_instruction: Option<Ci>,
-
-    #[serde(default, rename = "addressLookupTableAddresses")]
-    address_lookup_table_addresses: Option<Vec<String>>,
-}
-
-// ───────────────────────────────── flow ────────────────────────────
-pub async fn swap_instruction_flow() -> Result<()> {
-    let cfg  = load_config();
-    let http = http_client();
-    let rpc  = rpc_client(&cfg);
-    let kp   = keypair(&cfg);
-
-    // ─────────── /quote ─────────────────────────────────────────────
-    let fee_q = integrator_fee()
-        .map(|(_, bps)| format!("&platformFeeBps={}", bps))
-        .unwrap_or_default();
-
-    let quote_url = format!(
-        concat!(
-            "https://lite-api.jup.ag/swap/v1/quote",
-            "?inputMint={}&outputMint={}",
-            "&amount=1000000",
-            "&slippageBps=50{}"
-        ),
-        "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",     // input mint
-        "So11111111111111111111111111111111111111112",      // output mint
-        fee_q
-    );
-
-    let quote: serde_json::Value = http
-        .get(quote_url)
-        .with_jupiter_key()
-        .send()
-        .await?
-        .json()
-        .await?;
-
-    // ─────────── /swap-instructions ─────────────────────────────────
-    let user_pubkey = kp.pubkey().to_string();
-    let mut body = json!({
-        "quoteResponse": quote,
-        "userPublicKey": user_pubkey,
-        "payer": user_pubkey, // Use same account for both user and payer
-        "instructionFormat": "json",
-    });
-    if let Some((acc, _)) = integrator_fee() {
-        body["feeAccount"] = acc.into();
-    }
-
-    let resp: SwapInstructionResponse = http
-        .post("https://lite-api.jup.ag/swap/v1/swap-instructions")
-        .with_jupiter_key()
-        .json(&body)
-        .send()
-        .await?
-        .json()
-        .await?;
-
-
-    // decode every Instruction ----------------------------------------------
-    let mut ix: Vec<Instruction> = Vec::new();
-
-    if let Some(ci)  = resp.token_ledger_instruction  { ix.push(ci.into_instruction()?); }
-    if let Some(lst) = resp.compute_budget_instructions { for ci in lst { ix.push(ci.into_instruction()?); } }
-    if let Some(lst) = resp.setup_instructions        { for ci in lst { ix.push(ci.into_instruction()?); } }
-    if let Some(ci)  = resp.swap_instruction          { ix.push(ci.into_instruction()?); }
-    if let Some(ci)  = resp.cleanup_instruction       { ix.push(ci.into_instruction()?); }
-
-    if ix.is_empty() {
-        anyhow::bail!("swap-instructions API returned no instructions – check amount/slippage");
-    }
-
-    // fetch & build ALT accounts --------------------------------------------
-    let mut alts: Vec<AddressLookupTableAccount> = Vec::new();
-    if let Some(addrs) = resp.address_lookup_table_addresses {
-        for addr in addrs {
-            let key = Pubkey::from_str(&addr)?;
-            if let Ok(raw) = rpc.get_account(&key) {
-                if let Ok(table) = AddressLookupTable::deserialize(&raw.data) {
-                    alts.push(AddressLookupTableAccount {
-                        key,
-                        addresses: table.addresses.to_vec(),
-                    });
-                }
-            }
-        }
-    }
-
-    // compile message & send -------------------------------------------------
-    let payer            = kp.pubkey();  // Use main account as transaction payer
-    let recent_blockhash = rpc.get_latest_blockhash()?;
-    let msg              = Message::try_compile(&payer, &ix, &alts, recent_blockhash)?;
-    let versioned        = VersionedMessage::V0(msg);
-    let tx               = VersionedTransaction::try_new(versioned, &[&kp])?;  // Sign with main keypair only
-
-    let sig = rpc.send_and_confirm_transaction(&tx)?;
-    println!("swap-instructions tx confirmed: {sig}");
-    Ok(())
-}
-
-
-
-
-
-
-
-// ───────────────────────────────── Ultra Flow (/ultra/v1/order -> /ultra/v1/execute) ─────────────────────────────────
-
-#[derive(Deserialize, Debug)]
-pub struct UltraOrderResponse {
-    pub requestId: String,
-    pub transaction: String,
+//
+// One module per Jupiter API; everything is re-exported at the crate root so
+// callers write `jupiter_sdk::swap_flow`, `jupiter_sdk::USDC_MINT`, etc.
+// Each product (swap, ultra, trigger, recurring, price, tokens, lend) is a
+// cargo feature, all on by default; helpers built on one follow its feature.
+//
+// On wasm32 only the typed quote / price / token requests and responses build,
+// plus the `wasm` feature's WebClient over them; everything that needs RPC,
+// signing, the filesystem or a tokio runtime sits in `cfg_native!` below.
+
+//! Rust client for the [Jupiter](https://dev.jup.ag) APIs on Solana.
+//!
+//! Two layers, both re-exported at the crate root:
+//!
+//! - [`JupiterClient`] and its sub-clients (`.swap()`, `.ultra()`, `.trigger()`,
+//!   `.recurring()`, `.price()`, `.tokens()`) make typed API calls and return
+//!   the response types unchanged.
+//! - The `*_flow` functions and helpers such as [`swap_with_params`] run a
+//!   whole round: quote, build, sign with a [`TxSigner`], send and confirm.
+//!
+//! `use jupiter_sdk::prelude::*;` imports the clients, params structs, error
+//! types and traits in one line.
+//!
+//! Settings come from the environment, `.env` and an optional `jupiter.toml`
+//! ([`Config::load`]). Nothing panics on bad configuration; every entry point
+//! returns [`Error`], whose variants a caller can match on.
+//!
+//! ```no_run
+//! use jupiter_sdk::{swap_with_params, try_signer, Config, SwapParams, TokenAmount};
+//!
+//! # async fn run() -> jupiter_sdk::Result<()> {
+//! let cfg = Config::load()?;
+//! let wallet = try_signer(&cfg)?;
+//! let amount = TokenAmount::from_ui("0.01", 9)?;
+//! let result = swap_with_params(&wallet, SwapParams { amount, ..SwapParams::default() }).await?;
+//! println!("{}", result.signature);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Each product is a cargo feature (`swap`, `ultra`, `trigger`, `recurring`,
+//! `price`, `tokens`, `lend`), all on by default. `blocking` adds synchronous
+//! wrappers, `wasm` a fetch-based `WebClient` for wasm32, `ledger` hardware
+//! signing and `metrics` Prometheus counters.
+
+/// Items that only build off wasm32
+macro_rules! cfg_native {
+    ($($item:item)*) => {
+        $( #[cfg(not(target_arch = "wasm32"))] $item )*
+    };
 }
 
-#[derive(Deserialize, Debug)]
-pub struct UltraExecuteResponse {
-    #[serde(default)] pub status: Option<String>,
-    #[serde(default)] pub signature: Option<String>,
-    /// slot as string from API
-    #[serde(default)] pub slot: Option<String>,
-    #[serde(flatten)] pub extra: serde_json::Value,
-}
-
-pub async fn ultra_flow() -> Result<()> {
-    let cfg = load_config();
-    let http = http_client();
-    let rpc  = rpc_client(&cfg);
-    let kp   = keypair(&cfg);
-    let taker = kp.pubkey().to_string();
-     
-
-    let fee_part = integrator_fee()
-        .map(|(acc, bps)| format!("&referralAccount={}&referralFee={}", acc, bps.max(50)))
-        .unwrap_or_default();
-
-    let order_url = format!(
-        "https://lite-api.jup.ag/ultra/v1/order?inputMint={}&outputMint={}&amount={}&taker={}{}",
-        "So11111111111111111111111111111111111111112",
-        "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
-        10_000_000u64,
-        taker,
-        fee_part
-    );
-    let order: UltraOrderResponse = http.get(&order_url).with_jupiter_key().send().await?.json().await?;
-
-    let mut tx: VersionedTransaction = deserialize(&decode(&order.transaction)?)?;
-    sign_versioned_tx(&mut tx, &kp);
-
-    let signed_bytes = bincode::serialize(&tx)?;   // Vec<u8>
-    let signed       = base64::encode(&signed_bytes);
-
-    let exec_body = json!({
-        "signedTransaction": signed,
-        "requestId": order.requestId,
-    });
-    let exec_resp: UltraExecuteResponse = http
-        .post("https://lite-api.jup.ag/ultra/v1/execute")
-        .with_jupiter_key()
-        .json(&exec_body)
-        .send().await?
-        .json().await?;
-
-    println!("Ultra execute: {:#?}", exec_resp);
-    Ok(())
-}
-
-
-// ─────────────────────────────── Trigger Flow (/trigger/v1/createOrder -> /trigger/v1/execute) ──────────────────────────────
-
-
-#[derive(Deserialize, Debug)]
-pub struct CreateTriggerResponse {
-    /// Base64-encoded unsigned transaction
-    #[serde(default, rename = "transaction", alias = "tx", alias = "transactions")]
-    pub transaction: Option<String>,
-
-    /// Request ID for matching with execute
-    #[serde(default, rename = "requestId", alias = "request_id")]
-    pub request_id: Option<String>,
-
-    /// Order public key string
-    #[serde(default, rename = "order")]
-    pub order: Option<String>,
-
-    /// Any additional fields (e.g. code, error)
-    #[serde(flatten)]
-    pub extra: serde_json::Value,
-}
-
-#[derive(Deserialize, Debug)]
-pub struct ExecuteTriggerResponse {
-    pub status: String,
-    pub signature: String,
-    #[serde(flatten)] pub extra: serde_json::Value,
-}
-
-pub async fn trigger_flow() -> Result<()> {
-    let cfg  = load_config();
-    let http = http_client();
-    let rpc  = rpc_client(&cfg);
-    let mut kp = keypair(&cfg);
-    let user = kp.pubkey().to_string();
-
-    // 1. Create order ---------------------------------------------------------
-    let mut create_body = json!({
-        "inputMint":  "So11111111111111111111111111111111111111112",
-        "outputMint": "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
-        "maker":      user,
-        "payer":      user,
-        "params": {
-            "makingAmount": "30000000",
-            "takingAmount": "5000000"
-        }
-    });
-    if let Some((_, bps)) = integrator_fee() {
-        create_body["params"]["feeBps"] = bps.into();
-    }
-
-    let create_resp: CreateTriggerResponse = http
-        .post("https://lite-api.jup.ag/trigger/v1/createOrder")
-        .with_jupiter_key()
-        .json(&create_body)
-        .send().await?
-        .json().await?;
-
-    // 2. Decode, sign, execute -------------------------------------------------
-    if create_resp.transaction.as_deref().unwrap_or("").is_empty() {
-        eprintln!("Trigger createOrder failed: {:#?}", create_resp.extra);
-        return Ok(());
-    }
-    let tx_b64 = create_resp.transaction.as_ref().unwrap();
-    let mut tx: VersionedTransaction = deserialize(&decode(tx_b64)?)?;
-    sign_versioned_tx(&mut tx, &kp);
-    let signed = encode(&serialize(&tx)?);
-
-    let exec_body = json!({
-        "signedTransaction": signed,
-        "requestId": create_resp.request_id.as_ref().unwrap_or(&String::new()),
-    });
-    let exec_resp: ExecuteTriggerResponse = http
-        .post("https://lite-api.jup.ag/trigger/v1/execute")
-        .with_jupiter_key()
-        .json(&exec_body)
-        .send().await?
-        .json().await?;
-
-    println!("Trigger execute: {:#?}", exec_resp);
-    Ok(())
-}
-
-
-
-
-
-
-
-// ─────────────────────────── Recurring Flow (/recurring/v1/createOrder -> /recurring/v1/execute) ──────────────────────────
-
-
-#[derive(Deserialize, Debug)]
-pub struct CreateRecurringResponse {
-    /// Base64-encoded unsigned transaction
-    #[serde(default, rename = "transaction", alias = "tx", alias = "transactions")]
-    pub transaction: Option<String>,
-
-    /// Request ID for matching with execute
-    #[serde(default, rename = "requestId", alias = "request_id")]
-    pub request_id: Option<String>,
-
-    /// Order public key string
-    #[serde(default, rename = "order")]
-    pub order: Option<String>,
-
-    
-    #[serde(flatten)]
-    pub extra: serde_json::Value,
-}
-
-#[derive(Deserialize, Debug)]
-pub struct ExecuteRecurringResponse {
-    pub signature: String,
-    pub status: String,
-    pub order: Option<String>,
-    pub error: Option<String>,
-}
-
-pub async fn recurring_flow() -> Result<()> {
-    let cfg = load_config();
-    let http = http_client();
-    let rpc  = rpc_client(&cfg);
-    let mut kp = keypair(&cfg);
-    let user = kp.pubkey().to_string();
-
-    // 1. Create order
-    let create_body = json!({
-        "user":       user,
-        "inputMint":  "So11111111111111111111111111111111111111112",
-        "outputMint": "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
-        "params": { "time": { "inAmount": 50000000, "numberOfOrders": 2, "interval": 86400 } },
-    });
-    let create_resp: CreateRecurringResponse = http
-        .post("https://lite-api.jup.ag/recurring/v1/createOrder")
-        .with_jupiter_key()
-        .json(&create_body)
-        .send().await?
-        .json().await?;
-
-    // 2. Decode, sign, execute
-    if create_resp.transaction.as_deref().unwrap_or("").is_empty() {
-        eprintln!("Recurring createOrder failed: {:#?}", create_resp.extra);
-        return Ok(());
-    }
-    let tx_b64 = create_resp.transaction.as_ref().unwrap();
-    let mut tx: VersionedTransaction = deserialize(&decode(tx_b64)?)?;
-    sign_versioned_tx(&mut tx, &kp);
-    let signed = encode(&serialize(&tx)?);
-
-    let exec_body = json!({
-        "signedTransaction": signed,
-        "requestId":         create_resp.request_id.as_ref().unwrap_or(&String::new()),
-    });
-    let exec_resp: ExecuteRecurringResponse = http
-        .post("https://lite-api.jup.ag/recurring/v1/execute")
-        .with_jupiter_key()
-        .json(&exec_body)
-        .send().await?
-        .json().await?;
-
-    println!("Recurring execute: {:#?}", exec_resp);
-    
-    Ok(())
+#[cfg(all(target_arch = "wasm32", not(feature = "wasm")))]
+compile_error!("wasm32 builds need the `wasm` feature");
+
+pub mod error;
+pub use error::{ConfigError, Error, JupiterApiError, Result};
+pub mod api;
+pub use api::{JupiterApi, DEFAULT_SLIPPAGE_BPS, JUPITER_API_URL, JUPITER_PRO_API_URL};
+pub mod strict;
+pub use strict::{unknown_fields, KnownExtra, StrictMode};
+pub mod mints;
+pub use mints::{mint_label, USDC_MINT, WSOL_MINT};
+pub mod amount;
+pub use amount::{
+    cached_decimals, format_ui_amount, parse_ui_amount, remember_decimals, ui_amount, Lamports, TokenAmount,
+};
+#[cfg(feature = "swap")]
+pub mod swap;
+#[cfg(feature = "swap")]
+pub use swap::{QuoteResponse, RoutePlanStep, SwapInfo, SwapParams, SwapResponse};
+#[cfg(feature = "price")]
+pub mod price;
+#[cfg(feature = "price")]
+pub use price::{Price, MAX_PRICE_IDS};
+#[cfg(feature = "tokens")]
+pub mod tokens;
+#[cfg(feature = "tokens")]
+pub use tokens::{FirstPool, Token, TokenAudit, TokenIndex, TokenStats, TokenTag};
+#[cfg(feature = "wasm")]
+pub mod web;
+#[cfg(feature = "wasm")]
+pub use web::WebClient;
+pub mod prelude;
+
+cfg_native! {
+    pub(crate) use error::ApiResponseExt;
+    pub mod config;
+    pub use config::{
+        api_url, base_url, config_path, connect_timeout, default_slippage_bps, file_config, dry_run, keystore_path,
+        mainnet_confirmed, network, parse_commitment, proxy_url, request_timeout, try_http_client, Config, FeeSettings,
+        FileConfig, Network, PriorityFeePolicy,
+    };
+    pub(crate) use config::{
+        api_key, checked_integrator_fee, execute_guard, integrator_fee, mainnet_guard, request_api, setting,
+        JupiterReqExt,
+    };
+    pub mod output;
+    pub use output::{emit, emit_error, output_format, progress, EmitErrorExt, OutputFormat};
+    pub mod explorer;
+    pub use explorer::{account_link, explorer, tx_link, Explorer};
+    pub mod metrics;
+    pub use metrics::{start_metrics, SentVia};
+    #[cfg(feature = "metrics")]
+    pub use metrics::{render_metrics, serve_metrics};
+    pub mod shutdown;
+    pub use shutdown::shutdown_token;
+    pub use tokio_util::sync::CancellationToken;
+    pub mod correlation;
+    pub use correlation::{correlation_id, last_request_id, new_correlation_id, with_correlation, CORRELATION_HEADER};
+    pub(crate) use correlation::current_trace;
+    pub mod vcr;
+    pub use vcr::{vcr_dir, vcr_mode, VcrMode, VcrSender, DEFAULT_VCR_DIR};
+    pub use strict::strict_mode;
+    pub mod retry;
+    pub use retry::RetryPolicy;
+    pub mod rate_limit;
+    pub use rate_limit::{plan, rate_limiter, Plan, RateLimiter};
+    pub mod cache;
+    pub use cache::CacheStats;
+    #[cfg(feature = "swap")]
+    pub use cache::QuoteCache;
+    pub mod client;
+    pub use client::JupiterClient;
+    #[cfg(feature = "swap")]
+    pub use client::SwapApi;
+    #[cfg(feature = "ultra")]
+    pub use client::UltraApi;
+    #[cfg(feature = "trigger")]
+    pub use client::TriggerApi;
+    #[cfg(feature = "price")]
+    pub use client::PriceApi;
+    #[cfg(feature = "tokens")]
+    pub use client::TokensApi;
+    #[cfg(feature = "blocking")]
+    pub mod blocking;
+    pub mod rpc;
+    pub use rpc::{
+        confirmation, latest_blockhash, poll_confirmation, rpc_client, rpc_is_mainnet, send_and_confirm,
+        simulate_and_report, ws_url, Confirmation, DRY_RUN_STATUS, MAINNET_GENESIS_HASH,
+    };
+    #[cfg(any(feature = "ultra", feature = "trigger", feature = "recurring"))]
+    pub(crate) use rpc::simulate_execute;
+    pub mod signer;
+    pub use signer::{
+        keypair_from_mnemonic, parse_keypair, pending_signers, read_keypair, sign_versioned_tx, try_keypair, try_payer,
+        try_signer, TxSigner, Wallet, DEFAULT_DERIVATION_PATH,
+    };
+    #[cfg(feature = "ledger")]
+    pub use signer::LedgerSigner;
+    pub(crate) use signer::fee_payer_address;
+    pub mod remote_signer;
+    pub use remote_signer::{RemoteSignRequest, RemoteSignResponse, RemoteSigner};
+    pub mod nonce;
+    pub use nonce::{
+        advance_nonce_account, authorize_nonce_account, create_nonce_account, get_nonce, nonce_account,
+        send_nonce_transaction, with_advance_nonce, NonceInfo,
+    };
+    #[cfg(feature = "keystore")]
+    pub mod keystore;
+    #[cfg(feature = "keystore")]
+    pub use keystore::{install_passphrase_hook, keystore_import_flow, KdfParams, Keystore};
+    pub mod notify;
+    pub use notify::{notifier_from_env, Notifier, StdoutNotifier, WebhookNotifier};
+    #[cfg(feature = "swap")]
+    pub use swap::{get_quote, swap_flow, swap_quote, swap_with_params, swap_with_quote, SwapResult};
+    #[cfg(feature = "swap")]
+    pub mod swap_instructions;
+    #[cfg(feature = "swap")]
+    pub use swap_instructions::{swap_instruction_flow, SwapInstructionResponse};
+    #[cfg(feature = "ultra")]
+    pub mod ultra;
+    #[cfg(feature = "ultra")]
+    pub use ultra::{ultra_flow, ultra_with_params, UltraExecuteResponse, UltraOrderResponse, UltraParams};
+    #[cfg(feature = "trigger")]
+    pub mod trigger;
+    #[cfg(feature = "trigger")]
+    pub use trigger::{
+        cancel_trigger_orders_flow, trigger_flow, trigger_with_params, CancelTriggerResponse, CreateTriggerResponse,
+        ExecuteTriggerResponse, TriggerOrder, TriggerOrderStatus, TriggerParams,
+    };
+    #[cfg(feature = "recurring")]
+    pub mod recurring;
+    #[cfg(feature = "recurring")]
+    pub use recurring::{
+        cancel_recurring_flow, close_and_claim, close_and_claim_flow, create_recurring_batch, parse_interval,
+        preview_recurring_order, price_deposit_flow, price_recurring_flow, recurring_batch_flow, recurring_flow,
+        recurring_monitor_flow, recurring_report_flow, recurring_with_preview, withdraw_flow, BatchOrderResult,
+        BatchReport, ClaimResult, ExecuteRecurringResponse, FillReport, PriceDepositParams, PriceRecurringParams,
+        RecurringClient, RecurringError, RecurringEvent, RecurringMonitor, RecurringOrder, RecurringOrderParams,
+        RecurringOrderStatus, RecurringPreview, RecurringTrade, RecurringTx, RecurringTxResponse, RecurringType,
+        WithdrawParams, WithdrawSide, MAX_RECURRING_INTERVAL_SECS, MIN_RECURRING_INTERVAL_SECS, MIN_RECURRING_ORDERS,
+        MIN_USDC_PER_CYCLE,
+    };
+    #[cfg(feature = "price")]
+    pub use price::{
+        get_price, get_prices, get_prices_concurrent, get_usd_price, PollingPriceFeed, PriceEvent, PriceFeed,
+        PriceUpdate, PriceWatcher, Threshold,
+    };
+    #[cfg(feature = "tokens")]
+    pub use tokens::{
+        get_recent_tokens, get_tagged_tokens, get_token, resolve_mint, search_tokens, RecentTokens, TokenCache,
+        TokenResolver,
+    };
+    pub use amount::{load_decimals, mint_decimals};
+    #[cfg(feature = "lend")]
+    pub mod lend;
+    #[cfg(feature = "lend")]
+    pub use lend::{
+        get_lend_earnings, get_lend_positions, get_lend_positions_raw, get_lend_token, get_lend_tokens,
+        lend_deposit_flow, lend_deposit_tx, lend_positions_flow, lend_withdraw_flow, lend_withdraw_tx, LendAsset,
+        LendDepositParams, LendDepositResult, LendEarnings, LendError, LendPosition, LendPositionRaw, LendToken,
+        LendWithdrawAmount, LendWithdrawParams, LendWithdrawResult,
+    };
+    pub mod perps;
+    pub use perps::{get_perps_positions, PerpsPosition, PerpsPositionView, Side, PERPS_PROGRAM_ID};
+    #[cfg(feature = "price")]
+    pub use perps::perps_positions_flow;
+    pub mod referral;
+    pub use referral::{
+        claim_ix, claim_referral_fees, claim_referral_fees_flow, create_referral_account,
+        ensure_referral_token_accounts, initialize_referral_account_ix, initialize_referral_token_account_ix,
+        referral_account_address, referral_account_flow, referral_account_project, referral_balances,
+        referral_token_account_address, referral_token_account_exists, referral_token_accounts_flow, ClaimedFee,
+        ReferralBalance, ReferralTokenAccount, JUPITER_AGGREGATOR_V6, JUPITER_ULTRA_PROJECT, RECURRING_PROGRAM_ID,
+        REFERRAL_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID, TRIGGER_PROGRAM_ID,
+    };
+    pub mod ata;
+    pub use ata::{
+        ata_address, ata_exists, ata_rent, create_ata_ix, ensure_ata, expected_received, mint_token_program,
+        token_balance, warn_transfer_fee, Ata, ReceivedAmount,
+    };
+    pub mod mint;
+    pub use mint::{check_mint, check_mint_flow, MintInfo, MintPolicy, MintRejected, TransferFee, TransferFeeSchedule};
+    pub mod inspect;
+    pub use inspect::{
+        inspect_transaction, inspect_transaction_flow, inspect_versioned, AccountSource, ComputeBudgetRequest,
+        InspectedAccount, InspectedInstruction, TransactionInspection,
+    };
+    #[cfg(any(feature = "trigger", feature = "recurring"))]
+    pub mod onchain;
+    #[cfg(any(feature = "trigger", feature = "recurring"))]
+    pub use onchain::{
+        decode_anchor_account, get_recurring_accounts, get_trigger_accounts, onchain_orders_flow, RecurringAccount,
+        TriggerAccount,
+    };
+    #[cfg(feature = "price")]
+    pub mod portfolio;
+    #[cfg(feature = "price")]
+    pub use portfolio::{get_balances, portfolio_flow, value_portfolio, Holding, Portfolio, UltraBalance};
+    #[cfg(feature = "swap")]
+    pub mod twap;
+    #[cfg(feature = "swap")]
+    pub use twap::{execute_twap, twap_flow, TwapParams, TwapReport, TwapSlice, MIN_TWAP_INTERVAL};
+    #[cfg(feature = "swap")]
+    pub mod scheduler;
+    #[cfg(feature = "swap")]
+    pub use scheduler::{dca_scheduler_flow, DcaJob, DcaRun, DcaScheduler, MissedRuns};
+    pub mod safety;
+    pub use safety::{
+        install_pre_trade_hook, MintAccountCheck, SafetyBlocked, SafetyCheck, SafetyFinding, SafetyPipeline,
+        SafetyVerdict, Severity, ShieldCheck,
+    };
+    #[cfg(feature = "tokens")]
+    pub use safety::TokenTagCheck;
+    #[cfg(feature = "swap")]
+    pub mod history;
+    #[cfg(feature = "swap")]
+    pub use history::{parse_trade, scan_trades, trade_history_flow, write_trades_csv, HistoricalTrade};
+    #[cfg(feature = "swap")]
+    pub mod routes;
+    #[cfg(feature = "swap")]
+    pub use routes::{route_stats_flow, RouteAnalyzer, RouteReport, RouteSample, VenueStats};
+    #[cfg(feature = "swap")]
+    pub mod quoter;
+    #[cfg(feature = "swap")]
+    pub use quoter::{LiveQuoter, PairKey, QuoteSnapshot, QuoterHandle};
+    #[cfg(feature = "swap")]
+    pub mod payments;
+    #[cfg(feature = "swap")]
+    pub use payments::{pay, payment_flow, PaymentReceipt, PaymentRequest};
+    #[cfg(feature = "swap")]
+    pub mod payroll;
+    #[cfg(feature = "swap")]
+    pub use payroll::{payroll_flow, read_payroll_csv, run_payroll, Payee, PayoutResult, PayrollReport};
+    #[cfg(all(feature = "swap", feature = "price"))]
+    pub mod basket;
+    #[cfg(all(feature = "swap", feature = "price"))]
+    pub use basket::{basket_flow, diversify, BasketLeg, BasketParams, BasketReport};
 }
-
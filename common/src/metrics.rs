@@ -0,0 +1,188 @@
+// common/src/metrics.rs
+use crate::JupiterApi;
+use std::time::Duration;
+
+// ─────────────────── Prometheus metrics ───────────────────
+//
+// With the `metrics` feature the flows count quotes, sent transactions, API
+// errors and retries, and time confirmations; `start_metrics` serves them in
+// the Prometheus text format on `METRICS_ADDR` (e.g. 0.0.0.0:9184) at
+// /metrics. Without the feature every recorder below is a no-op.
+
+/// How a transaction reached the network, for `jupiter_transactions_sent_total`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SentVia {
+    Rpc,
+    Ultra,
+    Trigger,
+    Recurring,
+}
+
+#[cfg(feature = "metrics")]
+impl SentVia {
+    fn label(self) -> &'static str {
+        match self {
+            SentVia::Rpc => "rpc",
+            SentVia::Ultra => "ultra",
+            SentVia::Trigger => "trigger",
+            SentVia::Recurring => "recurring",
+        }
+    }
+}
+
+#[cfg(feature = "metrics")]
+fn api_label(api: Option<JupiterApi>) -> &'static str {
+    api.map(JupiterApi::name).unwrap_or("other")
+}
+
+#[cfg(feature = "metrics")]
+mod prom {
+    use prometheus::{
+        register_histogram_with_registry, register_int_counter_vec_with_registry, register_int_counter_with_registry,
+        Histogram, IntCounter, IntCounterVec, Registry,
+    };
+    use std::sync::OnceLock;
+
+    pub(super) struct Metrics {
+        pub registry: Registry,
+        pub quotes: IntCounter,
+        pub sent: IntCounterVec,
+        pub confirmation: Histogram,
+        pub api_errors: IntCounterVec,
+        pub retries: IntCounterVec,
+    }
+
+    pub(super) fn metrics() -> &'static Metrics {
+        static METRICS: OnceLock<Metrics> = OnceLock::new();
+        METRICS.get_or_init(|| {
+            let registry = Registry::new();
+            let quotes = register_int_counter_with_registry!(
+                "jupiter_quotes_fetched_total",
+                "Quotes fetched from /swap/v1/quote",
+                registry
+            )
+            .unwrap();
+            let sent = register_int_counter_vec_with_registry!(
+                "jupiter_transactions_sent_total",
+                "Signed transactions submitted, by route (rpc, ultra, trigger, recurring)",
+                &["via"],
+                registry
+            )
+            .unwrap();
+            let confirmation = register_histogram_with_registry!(
+                "jupiter_confirmation_seconds",
+                "Time from sending a transaction over RPC to its confirmation",
+                vec![0.5, 1.0, 2.0, 5.0, 10.0, 20.0, 30.0, 60.0, 90.0],
+                registry
+            )
+            .unwrap();
+            let api_errors = register_int_counter_vec_with_registry!(
+                "jupiter_api_errors_total",
+                "Non-success Jupiter API responses, by product and HTTP status",
+                &["api", "status"],
+                registry
+            )
+            .unwrap();
+            let retries = register_int_counter_vec_with_registry!(
+                "jupiter_api_retries_total",
+                "Jupiter API requests retried after a 429/5xx or timeout, by product",
+                &["api"],
+                registry
+            )
+            .unwrap();
+            Metrics { registry, quotes, sent, confirmation, api_errors, retries }
+        })
+    }
+}
+
+#[cfg(feature = "metrics")]
+pub(crate) fn record_quote() {
+    prom::metrics().quotes.inc();
+}
+
+#[cfg(feature = "metrics")]
+pub(crate) fn record_sent(via: SentVia) {
+    prom::metrics().sent.with_label_values(&[via.label()]).inc();
+}
+
+#[cfg(feature = "metrics")]
+pub(crate) fn record_confirmation(elapsed: Duration) {
+    prom::metrics().confirmation.observe(elapsed.as_secs_f64());
+}
+
+#[cfg(feature = "metrics")]
+pub(crate) fn record_api_error(api: Option<JupiterApi>, status: u16) {
+    prom::metrics().api_errors.with_label_values(&[api_label(api), &status.to_string()]).inc();
+}
+
+#[cfg(feature = "metrics")]
+pub(crate) fn record_retry(api: Option<JupiterApi>) {
+    prom::metrics().retries.with_label_values(&[api_label(api)]).inc();
+}
+
+/// Everything recorded so far, in the Prometheus text format
+#[cfg(feature = "metrics")]
+pub fn render_metrics() -> String {
+    use prometheus::Encoder;
+    let mut buf = Vec::new();
+    let _ = prometheus::TextEncoder::new().encode(&prom::metrics().registry.gather(), &mut buf);
+    String::from_utf8(buf).unwrap_or_default()
+}
+
+/// Serve [`render_metrics`] over plain HTTP on `addr` until the process exits
+#[cfg(feature = "metrics")]
+pub async fn serve_metrics(addr: std::net::SocketAddr) -> std::io::Result<()> {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    loop {
+        let (mut stream, _) = listener.accept().await?;
+        tokio::spawn(async move {
+            // every path gets the metrics; only the request line matters
+            let mut request = [0u8; 1024];
+            let _ = stream.read(&mut request).await;
+            let body = render_metrics();
+            let headers = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\n",
+                body.len()
+            );
+            let response = format!("{headers}Connection: close\r\n\r\n{body}");
+            let _ = stream.write_all(response.as_bytes()).await;
+        });
+    }
+}
+
+/// Start serving /metrics on `METRICS_ADDR` in the background, if it is set.
+/// Long-running examples call this first thing.
+pub fn start_metrics() {
+    let Some(addr) = std::env::var("METRICS_ADDR").ok().filter(|s| !s.trim().is_empty()) else {
+        return;
+    };
+    #[cfg(feature = "metrics")]
+    match addr.trim().parse::<std::net::SocketAddr>() {
+        Ok(addr) => {
+            tokio::spawn(async move {
+                if let Err(e) = serve_metrics(addr).await {
+                    eprintln!("metrics server on {addr} stopped: {e}");
+                }
+            });
+        }
+        Err(e) => eprintln!("METRICS_ADDR {addr:?} is not a socket address: {e}"),
+    }
+    #[cfg(not(feature = "metrics"))]
+    eprintln!("METRICS_ADDR={addr} is ignored: build with `--features jupiter-sdk/metrics`");
+}
+
+#[cfg(not(feature = "metrics"))]
+pub(crate) fn record_quote() {}
+
+#[cfg(not(feature = "metrics"))]
+pub(crate) fn record_sent(_via: SentVia) {}
+
+#[cfg(not(feature = "metrics"))]
+pub(crate) fn record_confirmation(_elapsed: Duration) {}
+
+#[cfg(not(feature = "metrics"))]
+pub(crate) fn record_api_error(_api: Option<JupiterApi>, _status: u16) {}
+
+#[cfg(not(feature = "metrics"))]
+pub(crate) fn record_retry(_api: Option<JupiterApi>) {}
@@ -0,0 +1,231 @@
+// common/src/mint.rs
+use crate::referral::{TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID};
+use crate::{Error, Result};
+use serde::Serialize;
+use serde_json::Value;
+use solana_account_decoder::parse_account_data::parse_account_data;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
+
+// ─────────────────── Mint safety checks ───────────────────
+
+/// On-chain state of a mint relevant to trading safety
+#[derive(Serialize, Debug, Clone)]
+pub struct MintInfo {
+    pub mint: Pubkey,
+    pub token_program: Pubkey,
+    pub decimals: u8,
+    /// Raw supply
+    pub supply: u64,
+    pub mint_authority: Option<Pubkey>,
+    pub freeze_authority: Option<Pubkey>,
+    /// Highest of the current/next Token-2022 transfer fee
+    pub transfer_fee_bps: Option<u16>,
+    /// Token-2022 transfer fee schedule, if the mint charges one
+    pub transfer_fee: Option<TransferFeeSchedule>,
+    /// Token-2022 transfer hook program, if one is set
+    pub transfer_hook: Option<Pubkey>,
+    /// Names of all Token-2022 extensions on the mint
+    pub extensions: Vec<String>,
+}
+
+impl MintInfo {
+    pub fn is_token_2022(&self) -> bool {
+        self.token_program == TOKEN_2022_PROGRAM_ID
+    }
+
+    /// Transfer fee withheld from a transfer of `amount` during `epoch` (0 without the extension)
+    pub fn transfer_fee_for(&self, amount: u64, epoch: u64) -> u64 {
+        self.transfer_fee.map(|s| s.for_epoch(epoch).fee(amount)).unwrap_or(0)
+    }
+
+    /// What the recipient of a transfer of `amount` during `epoch` is credited
+    pub fn net_of_transfer_fee(&self, amount: u64, epoch: u64) -> u64 {
+        amount - self.transfer_fee_for(amount, epoch)
+    }
+}
+
+/// One Token-2022 transfer fee setting
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransferFee {
+    /// First epoch this fee applies to
+    pub epoch: u64,
+    pub basis_points: u16,
+    /// Cap per transfer, raw
+    pub maximum_fee: u64,
+}
+
+impl TransferFee {
+    /// ceil(amount × bps / 10 000), capped at `maximum_fee`, as the token program computes it
+    pub fn fee(&self, amount: u64) -> u64 {
+        let fee = (amount as u128 * self.basis_points as u128).div_ceil(10_000);
+        fee.min(self.maximum_fee as u128) as u64
+    }
+}
+
+/// The mint's current and scheduled transfer fees; `newer` takes over from its epoch
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransferFeeSchedule {
+    pub older: TransferFee,
+    pub newer: TransferFee,
+}
+
+impl TransferFeeSchedule {
+    pub fn for_epoch(&self, epoch: u64) -> &TransferFee {
+        if epoch >= self.newer.epoch { &self.newer } else { &self.older }
+    }
+}
+
+fn opt_pubkey(v: &Value) -> Result<Option<Pubkey>> {
+    v.as_str().map(Pubkey::from_str).transpose().map_err(Into::into)
+}
+
+/// Fetch and decode `mint` (SPL Token or Token-2022)
+pub async fn check_mint(rpc: &RpcClient, mint: &Pubkey) -> Result<MintInfo> {
+    let account = rpc.get_account(mint).await?;
+    if account.owner != TOKEN_PROGRAM_ID && account.owner != TOKEN_2022_PROGRAM_ID {
+        return Err(Error::Invalid(format!("{mint} is not owned by a token program ({})", account.owner)));
+    }
+    let parsed = parse_account_data(mint, &account.owner, &account.data, None)
+        .map_err(|e| Error::Deserialize(format!("{mint}: {e}")))?
+        .parsed;
+    if parsed["type"] != "mint" {
+        return Err(Error::Invalid(format!("{mint} is not a mint account")));
+    }
+    let info = &parsed["info"];
+
+    let mut extensions = Vec::new();
+    let mut transfer_fee_bps = None;
+    let mut transfer_fee = None;
+    let mut transfer_hook = None;
+    for ext in info["extensions"].as_array().into_iter().flatten() {
+        let name = ext["extension"].as_str().unwrap_or_default();
+        let state = &ext["state"];
+        match name {
+            "transferFeeConfig" => {
+                let fee = |k: &str| TransferFee {
+                    epoch: state[k]["epoch"].as_u64().unwrap_or(0),
+                    basis_points: state[k]["transferFeeBasisPoints"].as_u64().unwrap_or(0) as u16,
+                    maximum_fee: state[k]["maximumFee"].as_u64().unwrap_or(0),
+                };
+                let schedule = TransferFeeSchedule { older: fee("olderTransferFee"), newer: fee("newerTransferFee") };
+                transfer_fee_bps = Some(schedule.older.basis_points.max(schedule.newer.basis_points));
+                transfer_fee = Some(schedule);
+            }
+            "transferHook" => transfer_hook = opt_pubkey(&state["programId"])?,
+            _ => {}
+        }
+        extensions.push(name.to_string());
+    }
+
+    let decimals = info["decimals"].as_u64().unwrap_or(0) as u8;
+    crate::remember_decimals(&mint.to_string(), decimals);
+    Ok(MintInfo {
+        mint: *mint,
+        token_program: account.owner,
+        decimals,
+        supply: info["supply"].as_str().unwrap_or("0").parse()?,
+        mint_authority: opt_pubkey(&info["mintAuthority"])?,
+        freeze_authority: opt_pubkey(&info["freezeAuthority"])?,
+        transfer_fee_bps,
+        transfer_fee,
+        transfer_hook,
+        extensions,
+    })
+}
+
+/// Which mint properties a flow is willing to trade.
+///
+/// The default accepts authorities (USDC has both) but rejects
+/// transfer hooks and any transfer fee.
+#[derive(Debug, Clone)]
+pub struct MintPolicy {
+    pub allow_mint_authority: bool,
+    pub allow_freeze_authority: bool,
+    pub allow_transfer_hook: bool,
+    pub max_transfer_fee_bps: u16,
+    /// Mints that skip every check
+    pub trusted: Vec<Pubkey>,
+}
+
+impl Default for MintPolicy {
+    fn default() -> Self {
+        Self {
+            allow_mint_authority: true,
+            allow_freeze_authority: true,
+            allow_transfer_hook: false,
+            max_transfer_fee_bps: 0,
+            trusted: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("mint {mint} rejected: {}", reasons.join(", "))]
+pub struct MintRejected {
+    pub mint: Pubkey,
+    pub reasons: Vec<String>,
+}
+
+impl MintPolicy {
+    /// Rejects mint and freeze authorities as well
+    pub fn strict() -> Self {
+        Self { allow_mint_authority: false, allow_freeze_authority: false, ..Self::default() }
+    }
+
+    pub fn trust(mut self, mint: Pubkey) -> Self {
+        self.trusted.push(mint);
+        self
+    }
+
+    pub fn evaluate(&self, info: &MintInfo) -> std::result::Result<(), MintRejected> {
+        if self.trusted.contains(&info.mint) {
+            return Ok(());
+        }
+        let mut reasons = Vec::new();
+        if let (false, Some(a)) = (self.allow_mint_authority, info.mint_authority) {
+            reasons.push(format!("mint authority {a}"));
+        }
+        if let (false, Some(a)) = (self.allow_freeze_authority, info.freeze_authority) {
+            reasons.push(format!("freeze authority {a}"));
+        }
+        if let (false, Some(p)) = (self.allow_transfer_hook, info.transfer_hook) {
+            reasons.push(format!("transfer hook {p}"));
+        }
+        if let Some(bps) = info.transfer_fee_bps.filter(|&b| b > self.max_transfer_fee_bps) {
+            reasons.push(format!("transfer fee {bps} bps > {} bps", self.max_transfer_fee_bps));
+        }
+        if reasons.is_empty() {
+            Ok(())
+        } else {
+            Err(MintRejected { mint: info.mint, reasons })
+        }
+    }
+
+    /// Fetch every mint and fail on the first one the policy rejects
+    pub async fn enforce(&self, rpc: &RpcClient, mints: &[Pubkey]) -> Result<Vec<MintInfo>> {
+        let mut infos = Vec::with_capacity(mints.len());
+        for m in mints {
+            let info = check_mint(rpc, m).await?;
+            self.evaluate(&info)?;
+            infos.push(info);
+        }
+        Ok(infos)
+    }
+}
+
+pub async fn check_mint_flow(mint: &Pubkey) -> Result<MintInfo> {
+    let cfg = crate::Config::load()?;
+    let rpc = crate::rpc_client(&cfg);
+
+    let info = check_mint(&rpc, mint).await?;
+    let default = MintPolicy::default().evaluate(&info).err().map(|e| e.to_string());
+    let strict = MintPolicy::strict().evaluate(&info).err().map(|e| e.to_string());
+    let mut text = format!("Mint: {info:#?}\n{}", default.as_deref().unwrap_or("passes default policy"));
+    if let Some(e) = &strict {
+        text.push_str(&format!("\nstrict policy: {e}"));
+    }
+    crate::emit("mint", &serde_json::json!({ "mint": info, "defaultPolicy": default, "strictPolicy": strict }), text);
+    Ok(info)
+}
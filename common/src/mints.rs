@@ -0,0 +1,27 @@
+// common/src/mints.rs
+
+// ─────────────────── Well-known mints ───────────────────
+
+pub const USDC_MINT: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+pub const WSOL_MINT: &str = "So11111111111111111111111111111111111111112";
+
+/// Symbols for the handful of mints the examples use; anything else renders as a short mint
+fn known_symbol(mint: &str) -> Option<&'static str> {
+    match mint {
+        WSOL_MINT => Some("SOL"),
+        USDC_MINT => Some("USDC"),
+        "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB" => Some("USDT"),
+        "JUPyiwrYJFskUPiHa7hkeR8VUtAeFoSYbKedZNsDvCN" => Some("JUP"),
+        "27G8MtK7VtTcCHkpASjSDdkWWYfoqT6ggEuKidVJidD4" => Some("JLP"),
+        _ => None,
+    }
+}
+
+/// Display label for a mint: its symbol when known, otherwise "ABCD…WXYZ"
+pub fn mint_label(mint: &str) -> String {
+    match known_symbol(mint) {
+        Some(sym) => sym.to_string(),
+        None if mint.len() > 8 => format!("{}…{}", &mint[..4], &mint[mint.len() - 4..]),
+        None => mint.to_string(),
+    }
+}
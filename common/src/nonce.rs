@@ -0,0 +1,158 @@
+// common/src/nonce.rs
+use crate::metrics::{record_sent, SentVia};
+use crate::{
+    dry_run, file_config, latest_blockhash, mainnet_guard, send_and_confirm, setting, simulate_and_report, ConfigError,
+    Error, Lamports, Result, TxSigner,
+};
+use solana_client::client_error::ClientError;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_client::SerializableTransaction;
+use solana_client::rpc_config::RpcSendTransactionConfig;
+use solana_sdk::{
+    commitment_config::CommitmentConfig,
+    hash::Hash,
+    instruction::Instruction,
+    message::Message,
+    nonce::state::{State, Versions},
+    pubkey::Pubkey,
+    signature::{Keypair, Signature},
+    system_instruction,
+    transaction::Transaction,
+};
+use std::str::FromStr;
+use std::time::Duration;
+
+// ─────────────────── Durable nonces ───────────────────
+//
+// A transaction built on a nonce account's stored hash instead of a recent
+// blockhash stays valid until the nonce is advanced, so it can be signed
+// offline (or on a slow custody signer) and submitted whenever. Its first
+// instruction must advance the nonce, signed by the nonce authority.
+
+/// Decoded state of an initialized nonce account
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NonceInfo {
+    pub address: Pubkey,
+    pub authority: Pubkey,
+    /// Use as the transaction's `recent_blockhash`
+    pub blockhash: Hash,
+    pub lamports_per_signature: Lamports,
+}
+
+/// `NONCE_ACCOUNT`, else `nonce_account` in jupiter.toml: flows that compile
+/// their own transactions build them on this nonce instead of a recent blockhash
+pub fn nonce_account() -> Result<Option<Pubkey>, ConfigError> {
+    setting("NONCE_ACCOUNT", &file_config().nonce_account)
+        .map(|s| Pubkey::from_str(s.trim()))
+        .transpose()
+        .map_err(|e| ConfigError::Invalid { var: "NONCE_ACCOUNT", reason: e.to_string() })
+}
+
+/// Current state of the nonce account at `address`
+pub async fn get_nonce(rpc: &RpcClient, address: &Pubkey) -> Result<NonceInfo> {
+    let account = rpc.get_account(address).await?;
+    if account.owner != solana_sdk::system_program::id() {
+        return Err(Error::Deserialize(format!("{address} is not a system-owned nonce account")));
+    }
+    let versions: Versions = bincode::deserialize(&account.data)?;
+    match versions.state() {
+        State::Initialized(data) => Ok(NonceInfo {
+            address: *address,
+            authority: data.authority,
+            blockhash: data.blockhash(),
+            lamports_per_signature: Lamports(data.fee_calculator.lamports_per_signature),
+        }),
+        State::Uninitialized => Err(Error::Deserialize(format!("nonce account {address} is not initialized"))),
+    }
+}
+
+/// `ixs` with the advance-nonce instruction the runtime requires in front
+pub fn with_advance_nonce(ixs: &[Instruction], nonce: &Pubkey, authority: &Pubkey) -> Vec<Instruction> {
+    let mut all = Vec::with_capacity(ixs.len() + 1);
+    all.push(system_instruction::advance_nonce_account(nonce, authority));
+    all.extend_from_slice(ixs);
+    all
+}
+
+/// Create and initialize a nonce account at `nonce`'s address, funded for rent
+/// by `kp` and controlled by `authority`
+pub async fn create_nonce_account(
+    rpc: &RpcClient,
+    kp: &dyn TxSigner,
+    nonce: &Keypair,
+    authority: &Pubkey,
+) -> Result<Signature> {
+    let rent = rpc.get_minimum_balance_for_rent_exemption(State::size()).await?;
+    let nonce: &dyn TxSigner = nonce;
+    let ixs = system_instruction::create_nonce_account(&kp.address(), &nonce.address(), authority, rent);
+    send_signed(rpc, &[kp, nonce], &ixs).await
+}
+
+/// Hand control of `nonce` to `new_authority`; `kp` must be the current authority
+pub async fn authorize_nonce_account(
+    rpc: &RpcClient,
+    kp: &dyn TxSigner,
+    nonce: &Pubkey,
+    new_authority: &Pubkey,
+) -> Result<Signature> {
+    let ix = system_instruction::authorize_nonce_account(nonce, &kp.address(), new_authority);
+    send_signed(rpc, &[kp], &[ix]).await
+}
+
+/// Advance `nonce` to a fresh hash, invalidating anything signed against the old one
+pub async fn advance_nonce_account(rpc: &RpcClient, kp: &dyn TxSigner, nonce: &Pubkey) -> Result<Signature> {
+    let ix = system_instruction::advance_nonce_account(nonce, &kp.address());
+    send_signed(rpc, &[kp], &[ix]).await
+}
+
+/// Send a transaction built on `nonce` and wait for `commitment`. Unlike
+/// [`crate::send_and_confirm`] there is no blockhash to expire: it gives up
+/// only once the nonce has moved on without the transaction landing.
+pub async fn send_nonce_transaction(
+    rpc: &RpcClient,
+    tx: &impl SerializableTransaction,
+    nonce: &NonceInfo,
+    commitment: CommitmentConfig,
+) -> Result<Signature> {
+    if dry_run() {
+        return simulate_and_report(rpc, tx, "nonce transaction").await;
+    }
+    let config = RpcSendTransactionConfig { preflight_commitment: Some(commitment.commitment), ..Default::default() };
+    let signature = rpc.send_transaction_with_config(tx, config).await?;
+    record_sent(SentVia::Rpc);
+    loop {
+        match rpc.get_signature_status_with_commitment(&signature, commitment).await? {
+            Some(Ok(())) => return Ok(signature),
+            Some(Err(e)) => return Err(ClientError::from(e).into()),
+            None if get_nonce(rpc, &nonce.address).await?.blockhash != nonce.blockhash => {
+                // advanced by this transaction at a lower commitment, or by someone else
+                if let Some(status) = rpc.get_signature_status_with_commitment(&signature, commitment).await? {
+                    return status.map(|()| signature).map_err(|e| ClientError::from(e).into());
+                }
+                tokio::time::sleep(Duration::from_secs(2)).await;
+                if rpc.get_signature_status(&signature).await?.is_none() {
+                    return Err(Error::Sign(format!("nonce {} advanced without {signature}", nonce.address)));
+                }
+            }
+            None => tokio::time::sleep(Duration::from_millis(500)).await,
+        }
+    }
+}
+
+/// Legacy transaction of `ixs` paid by the first signer, signed by all of them
+async fn send_signed(rpc: &RpcClient, signers: &[&dyn TxSigner], ixs: &[Instruction]) -> Result<Signature> {
+    mainnet_guard(rpc).await?;
+    let blockhash = latest_blockhash(rpc, rpc.commitment()).await?;
+    let message = Message::new_with_blockhash(ixs, Some(&signers[0].address()), &blockhash);
+    let bytes = message.serialize();
+    let mut signatures = vec![Signature::default(); message.header.num_required_signatures as usize];
+    for (slot, key) in message.account_keys[..signatures.len()].iter().enumerate() {
+        let signer = signers
+            .iter()
+            .find(|s| s.address() == *key)
+            .ok_or_else(|| Error::Sign(format!("no signer for {key}")))?;
+        signatures[slot] = signer.sign(&bytes).await?;
+    }
+    let tx = Transaction { signatures, message };
+    send_and_confirm(rpc, &tx, rpc.commitment()).await
+}
@@ -0,0 +1,58 @@
+// common/src/notify.rs
+use crate::{try_http_client, Result};
+use async_trait::async_trait;
+use reqwest::Client;
+use serde_json::json;
+use std::env;
+use std::sync::Arc;
+
+// ─────────────────────────── Notifications ──────────────────────────
+
+/// Sink for operator alerts emitted by long-running tasks
+#[async_trait]
+pub trait Notifier: Send + Sync {
+    async fn notify(&self, message: &str) -> Result<()>;
+}
+
+/// Prints every notification to stdout (stderr under OUTPUT=json)
+pub struct StdoutNotifier;
+
+#[async_trait]
+impl Notifier for StdoutNotifier {
+    async fn notify(&self, message: &str) -> Result<()> {
+        crate::progress(format!("[notify] {message}"));
+        Ok(())
+    }
+}
+
+/// POSTs `{"content": .., "text": ..}` to a Discord/Slack-style webhook
+pub struct WebhookNotifier {
+    http: Client,
+    url: String,
+}
+
+impl WebhookNotifier {
+    pub fn new(http: Client, url: impl Into<String>) -> Self {
+        Self { http, url: url.into() }
+    }
+}
+
+#[async_trait]
+impl Notifier for WebhookNotifier {
+    async fn notify(&self, message: &str) -> Result<()> {
+        self.http
+            .post(&self.url)
+            .json(&json!({ "content": message, "text": message }))
+            .send().await?
+            .error_for_status()?;
+        Ok(())
+    }
+}
+
+/// Webhook notifier if `NOTIFY_WEBHOOK_URL` is set, stdout otherwise
+pub fn notifier_from_env() -> Result<Arc<dyn Notifier>> {
+    Ok(match env::var("NOTIFY_WEBHOOK_URL") {
+        Ok(url) if !url.is_empty() => Arc::new(WebhookNotifier::new(try_http_client()?, url)),
+        _ => Arc::new(StdoutNotifier),
+    })
+}
@@ -0,0 +1,205 @@
+// common/src/onchain.rs
+use crate::perps::discriminator;
+use crate::{emit, load_decimals, mint_label, rpc_client, ui_amount, Config, Error, Result};
+use borsh::BorshDeserialize;
+use serde::Serialize;
+use serde_json::json;
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::{
+    nonblocking::rpc_client::RpcClient,
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::{Memcmp, RpcFilterType},
+};
+use solana_sdk::pubkey::Pubkey;
+
+pub use crate::referral::{RECURRING_PROGRAM_ID, TRIGGER_PROGRAM_ID};
+
+// ─────────────── On-chain Recurring & Trigger orders (no REST) ───────────────
+
+/// `Dca` account of the recurring program
+#[derive(BorshDeserialize, Serialize, Debug, Clone)]
+pub struct RecurringAccount {
+    pub user: Pubkey,
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    pub idx: u64,
+    pub next_cycle_at: i64,
+    pub in_deposited: u64,
+    pub in_withdrawn: u64,
+    pub out_withdrawn: u64,
+    pub in_used: u64,
+    pub out_received: u64,
+    pub in_amount_per_cycle: u64,
+    pub cycle_frequency: i64,
+    pub next_cycle_amount_left: u64,
+    pub in_account: Pubkey,
+    pub out_account: Pubkey,
+    pub min_out_amount: u64,
+    pub max_out_amount: u64,
+    pub keeper_in_balance_before_borrow: u64,
+    pub dca_out_balance_before_swap: u64,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl RecurringAccount {
+    /// Raw input still to be swapped
+    pub fn in_left(&self) -> u64 {
+        self.in_deposited.saturating_sub(self.in_withdrawn).saturating_sub(self.in_used)
+    }
+
+    /// Raw output received but not yet withdrawn
+    pub fn out_unclaimed(&self) -> u64 {
+        self.out_received.saturating_sub(self.out_withdrawn)
+    }
+}
+
+/// `Order` account of the trigger program
+#[derive(BorshDeserialize, Serialize, Debug, Clone)]
+pub struct TriggerAccount {
+    pub maker: Pubkey,
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    pub input_token_program: Pubkey,
+    pub output_token_program: Pubkey,
+    pub input_mint_reserve: Pubkey,
+    pub unique_id: u64,
+    pub ori_making_amount: u64,
+    pub ori_taking_amount: u64,
+    pub making_amount: u64,
+    pub taking_amount: u64,
+    pub borrow_making_amount: u64,
+    pub expired_at: Option<i64>,
+    pub fee_bps: u16,
+    pub fee_account: Pubkey,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl TriggerAccount {
+    /// Fraction of the original making amount already filled
+    pub fn filled_ratio(&self) -> f64 {
+        if self.ori_making_amount == 0 {
+            return 0.0;
+        }
+        1.0 - self.making_amount as f64 / self.ori_making_amount as f64
+    }
+}
+
+/// Check the anchor discriminator and borsh-decode the rest.
+/// Trailing bytes (account padding) are ignored.
+pub fn decode_anchor_account<T: BorshDeserialize>(name: &str, address: &Pubkey, data: &[u8]) -> Result<T> {
+    if data.len() < 8 || data[..8] != discriminator(name) {
+        return Err(Error::Deserialize(format!("{address} is not a {name} account")));
+    }
+    T::deserialize(&mut &data[8..]).map_err(|e| Error::Deserialize(format!("{address}: {e}")))
+}
+
+/// All `name` accounts of `program` whose first field is `owner`
+async fn accounts_by_owner<T: BorshDeserialize>(
+    rpc: &RpcClient,
+    program: &Pubkey,
+    name: &str,
+    owner: &Pubkey,
+) -> Result<Vec<(Pubkey, T)>> {
+    let config = RpcProgramAccountsConfig {
+        filters: Some(vec![
+            RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, discriminator(name).to_vec())),
+            RpcFilterType::Memcmp(Memcmp::new_raw_bytes(8, owner.to_bytes().to_vec())),
+        ]),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    rpc.get_program_accounts_with_config(program, config).await?
+        .into_iter()
+        .map(|(address, account)| Ok((address, decode_anchor_account(name, &address, &account.data)?)))
+        .collect()
+}
+
+/// Open recurring orders of `user`, read straight from the program
+pub async fn get_recurring_accounts(rpc: &RpcClient, user: &Pubkey) -> Result<Vec<(Pubkey, RecurringAccount)>> {
+    accounts_by_owner(rpc, &RECURRING_PROGRAM_ID, "Dca", user).await
+}
+
+/// Open trigger orders of `maker`, read straight from the program
+pub async fn get_trigger_accounts(rpc: &RpcClient, maker: &Pubkey) -> Result<Vec<(Pubkey, TriggerAccount)>> {
+    accounts_by_owner(rpc, &TRIGGER_PROGRAM_ID, "Order", maker).await
+}
+
+pub async fn onchain_orders_flow(owner: &Pubkey) -> Result<()> {
+    let cfg = Config::load()?;
+    let rpc = rpc_client(&cfg);
+
+    let recurring = get_recurring_accounts(&rpc, owner).await?;
+    let trigger = get_trigger_accounts(&rpc, owner).await?;
+    let mints: Vec<String> = recurring
+        .iter()
+        .flat_map(|(_, o)| [o.input_mint, o.output_mint])
+        .chain(trigger.iter().flat_map(|(_, o)| [o.input_mint, o.output_mint]))
+        .map(|m| m.to_string())
+        .collect();
+    // amounts fall back to raw units for any mint whose decimals can't be fetched
+    load_decimals(&rpc, &mints).await.ok();
+
+    let mut text = format!("Recurring orders ({}):", recurring.len());
+    for (address, o) in &recurring {
+        text.push_str(&format!(
+            "\n  {address} {} → {}: {} per cycle every {}s, {} left, {} unclaimed, next at {}",
+            mint_label(&o.input_mint.to_string()),
+            mint_label(&o.output_mint.to_string()),
+            ui_amount(&o.input_mint.to_string(), o.in_amount_per_cycle),
+            o.cycle_frequency,
+            ui_amount(&o.input_mint.to_string(), o.in_left()),
+            ui_amount(&o.output_mint.to_string(), o.out_unclaimed()),
+            o.next_cycle_at,
+        ));
+    }
+
+    text.push_str(&format!("\nTrigger orders ({}):", trigger.len()));
+    for (address, o) in &trigger {
+        text.push_str(&format!(
+            "\n  {address} {} → {}: {:.1}% filled{}",
+            ui_amount(&o.input_mint.to_string(), o.ori_making_amount),
+            ui_amount(&o.output_mint.to_string(), o.ori_taking_amount),
+            o.filled_ratio() * 100.0,
+            o.expired_at.map(|t| format!(", expires at {t}")).unwrap_or_default(),
+        ));
+    }
+
+    // Pubkeys serialize as byte arrays; give scripts base58 strings
+    let recurring_json: Vec<_> = recurring
+        .iter()
+        .map(|(address, o)| {
+            json!({
+                "address": address.to_string(),
+                "inputMint": o.input_mint.to_string(),
+                "outputMint": o.output_mint.to_string(),
+                "inAmountPerCycle": o.in_amount_per_cycle,
+                "cycleFrequency": o.cycle_frequency,
+                "inLeft": o.in_left(),
+                "outUnclaimed": o.out_unclaimed(),
+                "nextCycleAt": o.next_cycle_at,
+            })
+        })
+        .collect();
+    let trigger_json: Vec<_> = trigger
+        .iter()
+        .map(|(address, o)| {
+            json!({
+                "address": address.to_string(),
+                "inputMint": o.input_mint.to_string(),
+                "outputMint": o.output_mint.to_string(),
+                "makingAmount": o.ori_making_amount,
+                "takingAmount": o.ori_taking_amount,
+                "filledRatio": o.filled_ratio(),
+                "expiredAt": o.expired_at,
+            })
+        })
+        .collect();
+    emit("onchain_orders", &json!({ "recurring": recurring_json, "trigger": trigger_json }), text);
+    Ok(())
+}
@@ -0,0 +1,93 @@
+// common/src/output.rs
+use crate::{correlation_id, file_config, last_request_id, setting, ConfigError};
+use serde::Serialize;
+use serde_json::json;
+use std::fmt::Display;
+use std::str::FromStr;
+
+// ─────────────────── Output ───────────────────
+//
+// Flows print a human-readable summary by default. With OUTPUT=json every
+// result is one JSON object per line on stdout, `{"event": "...", "data": ...}`,
+// errors are `{"event": "error", "error": "..."}`, and progress messages move to
+// stderr, so stdout can be piped straight into jq or parsed from another language.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = ConfigError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "text" | "human" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            other => Err(ConfigError::Invalid { var: "OUTPUT", reason: format!("{other:?} is not text or json") }),
+        }
+    }
+}
+
+/// `OUTPUT`, else `output` in jupiter.toml, else text. An invalid value falls
+/// back to text here; [`crate::Config::load`] reports it.
+pub fn output_format() -> OutputFormat {
+    setting("OUTPUT", &file_config().output)
+        .and_then(|s| s.parse().ok())
+        .unwrap_or_default()
+}
+
+/// Print a flow's result: `text` as is, or `data` under `event` as one line of JSON
+pub fn emit<T: Serialize + ?Sized>(event: &str, data: &T, text: impl Display) {
+    match output_format() {
+        OutputFormat::Text => println!("{text}"),
+        OutputFormat::Json => println!("{}", json!({ "event": event, "data": data })),
+    }
+}
+
+/// A line that isn't part of the result (progress, per-item detail): stdout
+/// in text mode, stderr under OUTPUT=json so stdout stays parseable
+pub fn progress(text: impl Display) {
+    match output_format() {
+        OutputFormat::Text => println!("{text}"),
+        OutputFormat::Json => eprintln!("{text}"),
+    }
+}
+
+/// Print `err` as an `error` event under OUTPUT=json; in text mode the error
+/// itself is left to the caller (usually `main` returning it) and only the
+/// identifiers support asks for are printed, to stderr
+pub fn emit_error(err: &dyn Display) {
+    let (correlation_id, request_id) = (correlation_id(), last_request_id());
+    match output_format() {
+        OutputFormat::Text => match &request_id {
+            Some(request_id) => eprintln!("correlation id {correlation_id}, last Jupiter request id {request_id}"),
+            None => eprintln!("correlation id {correlation_id}"),
+        },
+        OutputFormat::Json => println!(
+            "{}",
+            json!({
+                "event": "error",
+                "error": format!("{err:#}"),
+                "correlationId": correlation_id,
+                "requestId": request_id,
+            })
+        ),
+    }
+}
+
+/// `.or_emit()` on a flow's result, so failures reach JSON consumers too
+pub trait EmitErrorExt {
+    fn or_emit(self) -> Self;
+}
+
+impl<T, E: Display> EmitErrorExt for Result<T, E> {
+    fn or_emit(self) -> Self {
+        if let Err(e) = &self {
+            emit_error(e);
+        }
+        self
+    }
+}
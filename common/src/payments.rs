@@ -0,0 +1,160 @@
+// common/src/payments.rs
+use crate::referral::send_instructions;
+use crate::{
+    default_slippage_bps, emit, ensure_ata, mainnet_guard, mint_decimals, mint_label, progress, rpc_client, safety,
+    send_and_confirm, sign_versioned_tx, try_http_client, tx_link, warn_transfer_fee, ApiResponseExt, Config, Error,
+    JupiterApi, JupiterReqExt, Lamports, QuoteResponse, Result, SwapResponse, TokenAmount, TxSigner, USDC_MINT,
+};
+use base64::decode;
+use bincode::deserialize;
+use serde::Serialize;
+use serde_json::json;
+use solana_sdk::{pubkey::Pubkey, transaction::VersionedTransaction};
+use std::str::FromStr;
+
+// ─────────────────── ExactOut payments (/quote?swapMode=ExactOut → /swap) ───────────────────
+
+/// Customer pays with `pay_mint`; the merchant receives exactly `amount` of `settle_mint`
+#[derive(Debug, Clone)]
+pub struct PaymentRequest {
+    /// Merchant wallet; the payment lands in its ATA for `settle_mint`
+    pub merchant: String,
+    pub settle_mint: String,
+    /// What the merchant must receive, in `settle_mint`'s decimals
+    pub amount: TokenAmount,
+    pub pay_mint: String,
+    pub slippage_bps: u64,
+}
+
+impl PaymentRequest {
+    /// `amount` USDC to `merchant`, paid in SOL
+    pub fn usdc(merchant: impl Into<String>, amount: TokenAmount) -> Self {
+        Self {
+            merchant: merchant.into(),
+            settle_mint: USDC_MINT.to_string(),
+            amount,
+            pay_mint: crate::WSOL_MINT.to_string(),
+            slippage_bps: default_slippage_bps(),
+        }
+    }
+
+    pub fn paid_with(mut self, mint: impl Into<String>) -> Self {
+        self.pay_mint = mint.into();
+        self
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct PaymentReceipt {
+    pub signature: String,
+    pub merchant: String,
+    /// Merchant token account credited
+    pub destination: String,
+    pub paid_mint: String,
+    /// What the customer was quoted to pay (upper bound is in `max_paid`)
+    pub paid_amount: TokenAmount,
+    /// Input ceiling after slippage
+    pub max_paid: TokenAmount,
+    pub settle_mint: String,
+    pub delivered_amount: TokenAmount,
+    /// Rent the customer paid to create `destination` (0 if it already existed)
+    pub account_rent: Lamports,
+}
+
+/// Quote, build and send one ExactOut payment
+pub async fn pay(kp: &dyn TxSigner, req: &PaymentRequest) -> Result<PaymentReceipt> {
+    if req.pay_mint == req.settle_mint {
+        let mint = mint_label(&req.pay_mint);
+        return Err(Error::Invalid(format!("paying {mint} with itself needs a transfer, not a swap")));
+    }
+    let cfg  = Config::load()?;
+    let http = try_http_client()?;
+    let rpc  = rpc_client(&cfg);
+    mainnet_guard(&rpc).await?;
+    let user = kp.address().to_string();
+    let settle_decimals = mint_decimals(&rpc, &req.settle_mint).await?;
+    if req.amount.decimals != settle_decimals {
+        return Err(Error::Invalid(format!(
+            "amount has {} decimals but {} has {settle_decimals}",
+            req.amount.decimals,
+            mint_label(&req.settle_mint)
+        )));
+    }
+
+    // The swap can only deliver into an existing token account, so create the merchant's first
+    let merchant = Pubkey::from_str(&req.merchant)?;
+    let settle_mint = Pubkey::from_str(&req.settle_mint)?;
+    let ata = ensure_ata(&rpc, &kp.address(), &merchant, &settle_mint).await?;
+    let destination = ata.address;
+    if let Some(create_ix) = &ata.create_ix {
+        progress(format!(
+            "Creating {} token account {destination} for {merchant} ({} rent)",
+            mint_label(&req.settle_mint),
+            ata.rent,
+        ));
+        send_instructions(&rpc, kp, std::slice::from_ref(create_ix)).await?;
+    }
+
+    // 1. ExactOut quote: `amount` is the output
+    let quote_url = format!(
+        "{}/quote?inputMint={}&outputMint={}&amount={}&slippageBps={}&swapMode=ExactOut",
+        cfg.api_url(JupiterApi::Swap),
+        req.pay_mint, req.settle_mint, req.amount.raw, req.slippage_bps
+    );
+    let quote: QuoteResponse = http.get(&quote_url).with_jupiter_key().send_with_retry().await?.api_json().await?;
+    let pay_decimals = mint_decimals(&rpc, &req.pay_mint).await?;
+    let paid_amount = TokenAmount::new(quote.inAmount.parse()?, pay_decimals);
+    let max_paid = TokenAmount::new(quote.otherAmountThreshold.parse()?, pay_decimals);
+    let delivered_amount = TokenAmount::new(quote.outAmount.parse()?, settle_decimals);
+    // a transfer fee on the settle mint comes out of what the merchant is credited
+    warn_transfer_fee(&rpc, &settle_mint, delivered_amount.raw).await?;
+
+    // 2. Swap straight into the merchant's account
+    let mut swap_body = json!({
+        "quoteResponse": quote,
+        "userPublicKey": user,
+        "destinationTokenAccount": destination.to_string(),
+    });
+    if let Some(priority) = cfg.priority_fee.to_swap_param() {
+        swap_body["prioritizationFeeLamports"] = priority;
+    }
+    let swap_resp: SwapResponse = http
+        .post(format!("{}/swap", cfg.api_url(JupiterApi::Swap)))
+        .with_jupiter_key()
+        .json(&swap_body)
+        .send_with_retry().await?
+        .api_json().await?;
+
+    // 3. Sign and send
+    safety::pre_trade_check(&req.settle_mint).await?;
+    let mut tx: VersionedTransaction = deserialize(&decode(&swap_resp.swap_transaction)?)?;
+    sign_versioned_tx(&mut tx, kp).await?;
+    let signature = send_and_confirm(&rpc, &tx, rpc.commitment()).await?;
+
+    Ok(PaymentReceipt {
+        signature: signature.to_string(),
+        merchant: req.merchant.clone(),
+        destination: destination.to_string(),
+        paid_mint: req.pay_mint.clone(),
+        paid_amount,
+        max_paid,
+        settle_mint: req.settle_mint.clone(),
+        delivered_amount,
+        account_rent: ata.rent,
+    })
+}
+
+pub async fn payment_flow(kp: &dyn TxSigner, req: PaymentRequest) -> Result<PaymentReceipt> {
+    let receipt = pay(kp, &req).await?;
+    let text = format!(
+        "Paid {} {} → {} {} to {} ({})",
+        receipt.paid_amount,
+        mint_label(&receipt.paid_mint),
+        receipt.delivered_amount,
+        mint_label(&receipt.settle_mint),
+        receipt.merchant,
+        tx_link(&receipt.signature),
+    );
+    emit("payment", &receipt, text);
+    Ok(receipt)
+}
@@ -0,0 +1,284 @@
+// common/src/payroll.rs
+use crate::referral::send_instructions;
+use crate::{
+    ata_address, check_mint, create_ata_ix, default_slippage_bps, emit, mint_label, pay, progress, rpc_client,
+    ui_amount, Config, Error, MintInfo, PaymentRequest, Result, TokenAmount, TxSigner, USDC_MINT,
+};
+use serde::Serialize;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::Duration;
+
+// ─────────────────── Cross-token payroll ───────────────────
+//
+// Every payout is funded from the treasury's USDC: USDC rows are plain
+// transfers, any other token is bought with an ExactOut swap that delivers
+// straight into the recipient's token account.
+
+/// Attempts per row before it is reported as failed
+const PAYOUT_ATTEMPTS: u32 = 3;
+
+/// One CSV row: `address,token_mint,amount` (amount in UI units)
+#[derive(Serialize, Debug, Clone)]
+pub struct Payee {
+    pub line: usize,
+    pub address: String,
+    pub mint: String,
+    pub amount: f64,
+}
+
+/// Parse a payroll CSV. A header line and blank lines are skipped.
+pub fn read_payroll_csv(path: impl AsRef<Path>) -> Result<Vec<Payee>> {
+    let text = std::fs::read_to_string(path)?;
+    let mut payees = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        let cols: Vec<&str> = line.split(',').map(str::trim).collect();
+        if line.trim().is_empty() || (i == 0 && cols.first() == Some(&"address")) {
+            continue;
+        }
+        let [address, mint, amount] = cols[..] else {
+            return Err(Error::Invalid(format!("line {}: expected address,token,amount", i + 1)));
+        };
+        Pubkey::from_str(address).map_err(|_| Error::Invalid(format!("line {}: invalid address {address}", i + 1)))?;
+        Pubkey::from_str(mint).map_err(|_| Error::Invalid(format!("line {}: invalid mint {mint}", i + 1)))?;
+        let amount = amount.parse().map_err(|_| Error::Invalid(format!("line {}: invalid amount {amount}", i + 1)))?;
+        payees.push(Payee { line: i + 1, address: address.into(), mint: mint.into(), amount });
+    }
+    Ok(payees)
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct PayoutResult {
+    pub payee: Payee,
+    /// `None` with no error when an attempt reported failure but the payee's balance shows it landed
+    pub signature: Option<String>,
+    /// Raw amount delivered to the payee
+    pub delivered: u64,
+    /// Raw USDC spent on this row
+    pub usdc_spent: u64,
+    pub attempts: u32,
+    pub error: Option<String>,
+}
+
+/// SPL `TransferChecked` (works for Token and Token-2022)
+fn transfer_checked_ix(
+    token_program: &Pubkey,
+    source: &Pubkey,
+    mint: &Pubkey,
+    destination: &Pubkey,
+    owner: &Pubkey,
+    amount: u64,
+    decimals: u8,
+) -> Instruction {
+    let mut data = vec![12];
+    data.extend_from_slice(&amount.to_le_bytes());
+    data.push(decimals);
+    Instruction {
+        program_id: *token_program,
+        accounts: vec![
+            AccountMeta::new(*source, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(*destination, false),
+            AccountMeta::new_readonly(*owner, true),
+        ],
+        data,
+    }
+}
+
+/// A row resolved against the chain: who gets what, in raw units
+struct Payout {
+    owner: Pubkey,
+    mint: Pubkey,
+    info: MintInfo,
+    raw: u64,
+}
+
+impl Payout {
+    async fn resolve(rpc: &RpcClient, payee: &Payee) -> Result<Self> {
+        let owner = Pubkey::from_str(&payee.address)?;
+        let mint = Pubkey::from_str(&payee.mint)?;
+        let info = check_mint(rpc, &mint).await?;
+        let raw = (payee.amount * 10f64.powi(info.decimals as i32)).round() as u64;
+        Ok(Self { owner, mint, info, raw })
+    }
+
+    /// Raw balance of the payee's token account, 0 while it doesn't exist. Unlike
+    /// [`crate::token_balance`] a failed lookup is an error, never a 0 that would invite a second payment.
+    async fn balance(&self, rpc: &RpcClient) -> Result<u64> {
+        let ata = ata_address(&self.owner, &self.mint, &self.info.token_program);
+        match rpc.get_account_with_commitment(&ata, rpc.commitment()).await?.value {
+            // SPL token account layout (Token and Token-2022): mint, owner, then the u64 amount
+            Some(account) if account.data.len() >= 72 => Ok(u64::from_le_bytes(account.data[64..72].try_into()?)),
+            Some(_) => Err(Error::Deserialize(format!("{ata} is not a token account"))),
+            None => Ok(0),
+        }
+    }
+}
+
+/// Pay one row once: create the payee's token account if needed, then transfer or swap
+async fn payout_once(rpc: &RpcClient, kp: &dyn TxSigner, payee: &Payee, payout: &Payout) -> Result<(String, u64, u64)> {
+    let Payout { owner, mint, ref info, raw } = *payout;
+
+    if payee.mint == USDC_MINT {
+        let create_ata = create_ata_ix(&kp.address(), &owner, &mint, &info.token_program);
+        let source = ata_address(&kp.address(), &mint, &info.token_program);
+        let destination = ata_address(&owner, &mint, &info.token_program);
+        let transfer =
+            transfer_checked_ix(&info.token_program, &source, &mint, &destination, &kp.address(), raw, info.decimals);
+        let sig = send_instructions(rpc, kp, &[create_ata, transfer]).await?;
+        return Ok((sig.to_string(), raw, raw));
+    }
+
+    // pay() creates the payee's token account if it is missing
+    let receipt = pay(kp, &PaymentRequest {
+        merchant: payee.address.clone(),
+        settle_mint: payee.mint.clone(),
+        amount: TokenAmount::new(raw, info.decimals),
+        pay_mint: USDC_MINT.to_string(),
+        slippage_bps: default_slippage_bps(),
+    })
+    .await?;
+    Ok((receipt.signature, receipt.delivered_amount.raw, receipt.paid_amount.raw))
+}
+
+/// Pay one row, retrying up to [`PAYOUT_ATTEMPTS`] times. A failed send or
+/// confirmation can still have landed, so after the backoff and right before each
+/// retry the payee's balance is compared with the one read before the first
+/// attempt; a row is never retried when that can't be checked. Returns the
+/// outcome and the attempts made.
+async fn pay_row(rpc: &RpcClient, kp: &dyn TxSigner, payee: &Payee) -> (Result<(Option<String>, u64, u64)>, u32) {
+    let resolved = async {
+        let payout = Payout::resolve(rpc, payee).await?;
+        let before = payout.balance(rpc).await?;
+        Ok::<_, Error>((payout, before))
+    };
+    let (payout, before) = match resolved.await {
+        Ok(resolved) => resolved,
+        Err(e) => return (Err(e), 0),
+    };
+    let mut attempts = 0;
+    loop {
+        attempts += 1;
+        let e = match payout_once(rpc, kp, payee, &payout).await {
+            Ok((signature, delivered, usdc_spent)) => return (Ok((Some(signature), delivered, usdc_spent)), attempts),
+            Err(e) => e,
+        };
+        let retry = attempts < PAYOUT_ATTEMPTS;
+        if retry {
+            // a transaction that lands late does so during the backoff, so wait before reading the balance
+            eprintln!("line {} attempt {attempts} failed: {e:#}", payee.line);
+            tokio::time::sleep(Duration::from_secs(2 << attempts)).await;
+        }
+        match payout.balance(rpc).await {
+            // landed despite the error; what it cost in USDC isn't known for a swap
+            Ok(now) if now.saturating_sub(before) >= payout.raw => {
+                let usdc_spent = if payee.mint == USDC_MINT { payout.raw } else { 0 };
+                return (Ok((None, now - before, usdc_spent)), attempts);
+            }
+            Ok(_) if retry => {}
+            Ok(_) => return (Err(e), attempts),
+            Err(check) => {
+                let e = anyhow::Error::new(e).context(format!("not retried, delivery unknown: {check:#}"));
+                return (Err(e.into()), attempts);
+            }
+        }
+    }
+}
+
+/// Pay every row in order, retrying each per [`pay_row`]
+pub async fn run_payroll(rpc: &RpcClient, kp: &dyn TxSigner, payees: Vec<Payee>) -> Vec<PayoutResult> {
+    let mut results = Vec::with_capacity(payees.len());
+    for payee in payees {
+        let (outcome, attempts) = pay_row(rpc, kp, &payee).await;
+        let result = match outcome {
+            Ok((signature, delivered, usdc_spent)) => PayoutResult {
+                payee,
+                signature,
+                delivered,
+                usdc_spent,
+                attempts,
+                error: None,
+            },
+            Err(e) => PayoutResult {
+                payee,
+                signature: None,
+                delivered: 0,
+                usdc_spent: 0,
+                attempts,
+                error: Some(format!("{e:#}")),
+            },
+        };
+        results.push(result);
+    }
+    results
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct PayrollReport {
+    pub paid: usize,
+    pub failed: usize,
+    /// Raw USDC spent across all rows
+    pub usdc_spent: u64,
+    /// mint → (requested UI amount, delivered raw amount)
+    pub by_mint: BTreeMap<String, (f64, u64)>,
+    pub results: Vec<PayoutResult>,
+}
+
+impl PayrollReport {
+    pub fn reconcile(results: Vec<PayoutResult>) -> Self {
+        let mut report = PayrollReport::default();
+        for r in &results {
+            let entry = report.by_mint.entry(r.payee.mint.clone()).or_default();
+            entry.0 += r.payee.amount;
+            entry.1 += r.delivered;
+            report.usdc_spent += r.usdc_spent;
+            if r.error.is_some() {
+                report.failed += 1;
+            } else {
+                report.paid += 1;
+            }
+        }
+        report.results = results;
+        report
+    }
+}
+
+impl std::fmt::Display for PayrollReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for r in &self.results {
+            let row = format!("{} {} → {}", r.payee.amount, mint_label(&r.payee.mint), r.payee.address);
+            match (&r.signature, &r.error) {
+                (Some(sig), _) => writeln!(f, "line {:>3} ok   {row} ({sig})", r.payee.line)?,
+                (_, Some(err)) => writeln!(f, "line {:>3} FAIL {row}: {err}", r.payee.line)?,
+                (None, None) => writeln!(f, "line {:>3} ok   {row} (landed after a reported failure)", r.payee.line)?,
+            }
+        }
+        for (mint, (requested, delivered)) in &self.by_mint {
+            writeln!(f, "{}: requested {requested}, delivered {}", mint_label(mint), ui_amount(mint, *delivered))?;
+        }
+        write!(
+            f,
+            "{} paid, {} failed, {:.6} USDC spent",
+            self.paid,
+            self.failed,
+            self.usdc_spent as f64 / 1_000_000.0
+        )
+    }
+}
+
+pub async fn payroll_flow(kp: &dyn TxSigner, csv_path: impl AsRef<Path>) -> Result<PayrollReport> {
+    let cfg = Config::load()?;
+    let rpc = rpc_client(&cfg);
+
+    let payees = read_payroll_csv(csv_path)?;
+    progress(format!("{} payouts from {}", payees.len(), kp.address()));
+    let report = PayrollReport::reconcile(run_payroll(&rpc, kp, payees).await);
+    emit("payroll", &report, &report);
+    Ok(report)
+}
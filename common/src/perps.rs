@@ -0,0 +1,226 @@
+// common/src/perps.rs
+use crate::{Error, Result};
+#[cfg(feature = "price")]
+use crate::{emit, get_prices, rpc_client, try_http_client, Config};
+use serde::Serialize;
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::{
+    nonblocking::rpc_client::RpcClient,
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::{Memcmp, RpcFilterType},
+};
+use solana_sdk::{hash::hash, pubkey, pubkey::Pubkey};
+
+// ─────────────────── Perps positions (on-chain) ───────────────────
+
+pub const PERPS_PROGRAM_ID: Pubkey = pubkey!("PERPHjGBqRHArX4DySjwM6UJHiR3sWAatqfdBS2qQJu");
+
+/// USD amounts in perps accounts carry 6 decimals
+const USD_DECIMALS: f64 = 1_000_000.0;
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Long,
+    Short,
+}
+
+/// Decoded `Position` account
+#[derive(Serialize, Debug, Clone)]
+pub struct PerpsPosition {
+    pub address: String,
+    pub owner: String,
+    pub pool: String,
+    /// Custody of the traded market
+    pub custody: String,
+    pub collateral_custody: String,
+    pub open_time: i64,
+    pub side: Side,
+    pub entry_price: f64,
+    pub size_usd: f64,
+    pub collateral_usd: f64,
+    pub realised_pnl_usd: f64,
+}
+
+/// A position valued at the current mark price
+#[derive(Serialize, Debug, Clone)]
+pub struct PerpsPositionView {
+    pub position: PerpsPosition,
+    pub market_mint: String,
+    pub mark_price: Option<f64>,
+    pub leverage: f64,
+    pub unrealised_pnl_usd: Option<f64>,
+}
+
+/// Little-endian reader over an account's data
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8]> {
+        let bytes = self
+            .data
+            .get(self.pos..self.pos + n)
+            .ok_or_else(|| Error::Deserialize("account data too short".into()))?;
+        self.pos += n;
+        Ok(bytes)
+    }
+
+    fn pubkey(&mut self) -> Result<Pubkey> {
+        Ok(Pubkey::try_from(self.take(32)?)?)
+    }
+
+    fn u8(&mut self) -> Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u64(&mut self) -> Result<u64> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into()?))
+    }
+
+    fn i64(&mut self) -> Result<i64> {
+        Ok(i64::from_le_bytes(self.take(8)?.try_into()?))
+    }
+}
+
+/// Anchor account discriminator: first 8 bytes of sha256("account:<Name>")
+pub(crate) fn discriminator(name: &str) -> [u8; 8] {
+    let mut disc = [0u8; 8];
+    disc.copy_from_slice(&hash(format!("account:{name}").as_bytes()).to_bytes()[..8]);
+    disc
+}
+
+impl PerpsPosition {
+    pub fn decode(address: &Pubkey, data: &[u8]) -> Result<Self> {
+        let mut r = Reader { data, pos: 0 };
+        if r.take(8)? != discriminator("Position") {
+            return Err(Error::Deserialize(format!("{address} is not a perps Position account")));
+        }
+        let owner              = r.pubkey()?;
+        let pool               = r.pubkey()?;
+        let custody            = r.pubkey()?;
+        let collateral_custody = r.pubkey()?;
+        let open_time          = r.i64()?;
+        let _update_time       = r.i64()?;
+        let side = match r.u8()? {
+            1 => Side::Long,
+            2 => Side::Short,
+            other => return Err(Error::Deserialize(format!("unknown position side {other}"))),
+        };
+        let price            = r.u64()?;
+        let size_usd         = r.u64()?;
+        let collateral_usd   = r.u64()?;
+        let realised_pnl_usd = r.i64()?;
+
+        Ok(PerpsPosition {
+            address: address.to_string(),
+            owner: owner.to_string(),
+            pool: pool.to_string(),
+            custody: custody.to_string(),
+            collateral_custody: collateral_custody.to_string(),
+            open_time,
+            side,
+            entry_price: price as f64 / USD_DECIMALS,
+            size_usd: size_usd as f64 / USD_DECIMALS,
+            collateral_usd: collateral_usd as f64 / USD_DECIMALS,
+            realised_pnl_usd: realised_pnl_usd as f64 / USD_DECIMALS,
+        })
+    }
+
+    /// Unrealised PnL in USD at `mark`
+    pub fn unrealised_pnl(&self, mark: f64) -> f64 {
+        let change = (mark - self.entry_price) / self.entry_price;
+        match self.side {
+            Side::Long  => self.size_usd * change,
+            Side::Short => -self.size_usd * change,
+        }
+    }
+
+    pub fn leverage(&self) -> f64 {
+        if self.collateral_usd > 0.0 { self.size_usd / self.collateral_usd } else { 0.0 }
+    }
+}
+
+/// Open positions owned by `owner`, via getProgramAccounts
+pub async fn get_perps_positions(rpc: &RpcClient, owner: &Pubkey) -> Result<Vec<PerpsPosition>> {
+    let config = RpcProgramAccountsConfig {
+        filters: Some(vec![
+            RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, discriminator("Position").to_vec())),
+            RpcFilterType::Memcmp(Memcmp::new_raw_bytes(8, owner.to_bytes().to_vec())),
+        ]),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let accounts = rpc.get_program_accounts_with_config(&PERPS_PROGRAM_ID, config).await?;
+
+    let mut positions = Vec::new();
+    for (address, account) in accounts {
+        let position = PerpsPosition::decode(&address, &account.data)?;
+        // closed positions keep their account with zero size
+        if position.size_usd > 0.0 {
+            positions.push(position);
+        }
+    }
+    Ok(positions)
+}
+
+/// Mint traded by a custody account (stored right after the pool key)
+#[cfg(feature = "price")]
+async fn custody_mint(rpc: &RpcClient, custody: &str) -> Result<Pubkey> {
+    let data = rpc.get_account_data(&custody.parse()?).await?;
+    let mut r = Reader { data: &data, pos: 8 + 32 };
+    r.pubkey()
+}
+
+/// Open positions valued at Price API mark prices (needs the `price` feature)
+#[cfg(feature = "price")]
+pub async fn perps_positions_flow(owner: &Pubkey) -> Result<Vec<PerpsPositionView>> {
+    let cfg  = Config::load()?;
+    let http = try_http_client()?;
+    let rpc  = rpc_client(&cfg);
+
+    let positions = get_perps_positions(&rpc, owner).await?;
+    let mints = futures::future::try_join_all(
+        positions.iter().map(|p| async { custody_mint(&rpc, &p.custody).await.map(|m| m.to_string()) }),
+    )
+    .await?;
+    let prices = get_prices(&http, &mints).await?;
+
+    let views: Vec<PerpsPositionView> = positions
+        .into_iter()
+        .zip(mints)
+        .map(|(position, market_mint)| {
+            let mark_price = prices.get(&market_mint).map(|p| p.usd_price);
+            PerpsPositionView {
+                leverage: position.leverage(),
+                unrealised_pnl_usd: mark_price.map(|m| position.unrealised_pnl(m)),
+                position,
+                market_mint,
+                mark_price,
+            }
+        })
+        .collect();
+
+    let text = views
+        .iter()
+        .map(|v| {
+            format!(
+                "{:?} {} size ${:.2} entry ${:.4} mark {} lev {:.2}x uPnL {}",
+                v.position.side,
+                crate::mint_label(&v.market_mint),
+                v.position.size_usd,
+                v.position.entry_price,
+                v.mark_price.map(|m| format!("${m:.4}")).unwrap_or_else(|| "-".into()),
+                v.leverage,
+                v.unrealised_pnl_usd.map(|p| format!("{p:+.2}")).unwrap_or_else(|| "-".into()),
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    emit("perps_positions", &views, text);
+    Ok(views)
+}
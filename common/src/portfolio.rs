@@ -0,0 +1,131 @@
+// common/src/portfolio.rs
+use crate::{
+    api_url, emit, get_prices, mint_label, output_format, try_http_client, ApiResponseExt, Config, JupiterApi,
+    JupiterReqExt, OutputFormat, Result, WSOL_MINT,
+};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashMap;
+
+// ─────────────────── Portfolio valuation (/ultra/v1/balances + /price/v3) ───────────────────
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UltraBalance {
+    /// Raw amount as a string
+    pub amount: String,
+    pub ui_amount: f64,
+    pub slot: u64,
+    pub is_frozen: bool,
+}
+
+/// Balances of `owner` keyed by mint; native SOL is keyed as `"SOL"`
+pub async fn get_balances(http: &Client, owner: &str) -> Result<HashMap<String, UltraBalance>> {
+    let url = format!("{}/balances/{owner}", api_url(JupiterApi::Ultra));
+    Ok(http.get(&url).with_jupiter_key().send_with_retry().await?.api_json().await?)
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct Holding {
+    pub mint: String,
+    pub symbol: String,
+    pub amount: f64,
+    pub usd_price: Option<f64>,
+    pub usd_value: f64,
+    /// Fraction of the portfolio's USD value, 0.0–1.0
+    pub share: f64,
+    pub is_frozen: bool,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct Portfolio {
+    pub owner: String,
+    pub total_usd: f64,
+    /// Sorted by USD value, largest first
+    pub holdings: Vec<Holding>,
+}
+
+/// Value every non-zero balance of `owner` at Price API prices.
+/// Mints without a price are kept with a value of 0.
+pub async fn value_portfolio(http: &Client, owner: &str) -> Result<Portfolio> {
+    let balances = get_balances(http, owner).await?;
+
+    let mut holdings: Vec<Holding> = balances
+        .into_iter()
+        .filter(|(_, b)| b.ui_amount > 0.0)
+        .map(|(key, b)| {
+            let mint = if key == "SOL" { WSOL_MINT.to_string() } else { key };
+            Holding {
+                symbol: mint_label(&mint),
+                mint,
+                amount: b.ui_amount,
+                usd_price: None,
+                usd_value: 0.0,
+                share: 0.0,
+                is_frozen: b.is_frozen,
+            }
+        })
+        .collect();
+
+    let mints: Vec<&str> = holdings.iter().map(|h| h.mint.as_str()).collect();
+    let prices = get_prices(http, &mints).await?;
+    for h in &mut holdings {
+        h.usd_price = prices.get(&h.mint).map(|p| p.usd_price);
+        h.usd_value = h.usd_price.map(|p| p * h.amount).unwrap_or(0.0);
+    }
+
+    // SOL and WSOL both map to the WSOL mint; merge them
+    holdings.sort_by(|a, b| a.mint.cmp(&b.mint));
+    holdings.dedup_by(|next, kept| {
+        let same = next.mint == kept.mint;
+        if same {
+            kept.amount += next.amount;
+            kept.usd_value += next.usd_value;
+        }
+        same
+    });
+
+    let total_usd: f64 = holdings.iter().map(|h| h.usd_value).sum();
+    for h in &mut holdings {
+        h.share = if total_usd > 0.0 { h.usd_value / total_usd } else { 0.0 };
+    }
+    holdings.sort_by(|a, b| b.usd_value.total_cmp(&a.usd_value));
+
+    Ok(Portfolio { owner: owner.to_string(), total_usd, holdings })
+}
+
+impl std::fmt::Display for Portfolio {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Portfolio of {}", self.owner)?;
+        writeln!(f, "{:<12} {:>18} {:>14} {:>14} {:>7}", "token", "amount", "price", "value", "share")?;
+        for h in &self.holdings {
+            writeln!(
+                f,
+                "{:<12} {:>18.6} {:>14} {:>14.2} {:>6.2}%{}",
+                h.symbol,
+                h.amount,
+                h.usd_price.map(|p| format!("{p:.6}")).unwrap_or_else(|| "-".into()),
+                h.usd_value,
+                h.share * 100.0,
+                if h.is_frozen { " (frozen)" } else { "" },
+            )?;
+        }
+        write!(f, "{:<12} {:>18} {:>14} {:>14.2}", "total", "", "", self.total_usd)
+    }
+}
+
+/// Print the wallet's portfolio as a table, or as pretty JSON when `json` is set
+/// (OUTPUT=json emits it as a `portfolio` event instead)
+pub async fn portfolio_flow(owner: &Pubkey, json: bool) -> Result<Portfolio> {
+    Config::load()?;
+    let http = try_http_client()?;
+
+    let portfolio = value_portfolio(&http, &owner.to_string()).await?;
+    if json && output_format() == OutputFormat::Text {
+        println!("{}", serde_json::to_string_pretty(&portfolio)?);
+    } else {
+        emit("portfolio", &portfolio, &portfolio);
+    }
+    Ok(portfolio)
+}
@@ -0,0 +1,37 @@
+// common/src/prelude.rs
+//
+// `use jupiter_sdk::prelude::*;` brings in what most programs touch: the
+// clients, the params structs the flows take, the error types and the traits
+// a caller implements or calls methods through. Response types and the
+// one-off helpers stay at the crate root.
+
+pub use crate::{
+    ConfigError, Error, JupiterApi, JupiterApiError, KnownExtra, Lamports, Result, TokenAmount, USDC_MINT, WSOL_MINT,
+};
+#[cfg(feature = "swap")]
+pub use crate::{QuoteResponse, SwapParams};
+#[cfg(feature = "wasm")]
+pub use crate::WebClient;
+
+cfg_native! {
+    pub use crate::{try_signer, Config, EmitErrorExt, JupiterClient, Notifier, SafetyCheck, TxSigner, Wallet};
+    #[cfg(feature = "swap")]
+    pub use crate::{PaymentRequest, SwapApi, TwapParams};
+    #[cfg(feature = "ultra")]
+    pub use crate::{UltraApi, UltraParams};
+    #[cfg(feature = "trigger")]
+    pub use crate::{TriggerApi, TriggerParams};
+    #[cfg(feature = "recurring")]
+    pub use crate::{
+        PriceDepositParams, PriceRecurringParams, RecurringClient, RecurringError, RecurringOrderParams,
+        WithdrawParams,
+    };
+    #[cfg(feature = "price")]
+    pub use crate::{PriceApi, PriceFeed};
+    #[cfg(feature = "tokens")]
+    pub use crate::TokensApi;
+    #[cfg(feature = "lend")]
+    pub use crate::{LendDepositParams, LendError, LendWithdrawParams};
+    #[cfg(all(feature = "swap", feature = "price"))]
+    pub use crate::BasketParams;
+}
@@ -0,0 +1,392 @@
+// common/src/price.rs
+use serde::{Deserialize, Serialize};
+#[cfg(not(target_arch = "wasm32"))]
+use {
+    crate::{api_url, mint_label, ApiResponseExt, JupiterApi, JupiterReqExt, Notifier},
+    anyhow::Result,
+    chrono::{DateTime, Utc},
+    futures::stream::{self, BoxStream, StreamExt, TryStreamExt},
+    reqwest::{Client, Response},
+    solana_sdk::pubkey::Pubkey,
+    std::collections::{HashMap, HashSet, VecDeque},
+    std::str::FromStr,
+    std::sync::Arc,
+    std::time::{Duration, Instant},
+    tokio::sync::mpsc,
+    tokio::task::JoinHandle,
+    tokio_util::sync::CancellationToken,
+};
+
+// ─────────────────── Price API (/price/v3) ───────────────────
+
+/// Most mints the Price API accepts in one `ids=` list
+pub const MAX_PRICE_IDS: usize = 50;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Price {
+    pub usd_price: f64,
+    pub decimals: u8,
+    /// Block the price was observed at
+    #[serde(default)]
+    pub block_id: Option<u64>,
+    #[serde(default, rename = "priceChange24h")]
+    pub price_change_24h: Option<f64>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+/// Prices for `mints`, keyed by mint. Mints Jupiter can't price are absent
+/// from the map. Lists longer than [`MAX_PRICE_IDS`] are split into batches.
+pub async fn get_prices<S: AsRef<str>>(http: &Client, mints: &[S]) -> Result<HashMap<String, Price>> {
+    let mut seen = HashSet::new();
+    let unique: Vec<&str> = mints
+        .iter()
+        .map(|m| m.as_ref())
+        .filter(|m| seen.insert(*m))
+        .collect();
+
+    let mut prices = HashMap::with_capacity(unique.len());
+    for batch in unique.chunks(MAX_PRICE_IDS) {
+        prices.extend(fetch_batch(http, batch).await?);
+    }
+    Ok(prices)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+/// Prices for hundreds of mints: batches of [`MAX_PRICE_IDS`] are fetched with
+/// up to `max_concurrency` requests in flight, backing off on 429s
+pub async fn get_prices_concurrent(
+    http: &Client,
+    mints: &[Pubkey],
+    max_concurrency: usize,
+) -> Result<HashMap<Pubkey, Price>> {
+    let unique: Vec<String> = mints
+        .iter()
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .map(|m| m.to_string())
+        .collect();
+
+    let batches: Vec<HashMap<String, Price>> = stream::iter(unique.chunks(MAX_PRICE_IDS))
+        .map(|batch| async move {
+            let ids: Vec<&str> = batch.iter().map(String::as_str).collect();
+            fetch_batch(http, &ids).await
+        })
+        .buffer_unordered(max_concurrency.max(1))
+        .try_collect()
+        .await?;
+
+    let mut prices = HashMap::new();
+    for (mint, price) in batches.into_iter().flatten() {
+        prices.insert(Pubkey::from_str(&mint)?, price);
+    }
+    Ok(prices)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn fetch_batch(http: &Client, mints: &[&str]) -> Result<HashMap<String, Price>> {
+    let url = format!("{}?ids={}", api_url(JupiterApi::Price), mints.join(","));
+    parse_prices(http.get(&url).with_jupiter_key().send_with_retry().await?).await
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn parse_prices(resp: Response) -> Result<HashMap<String, Price>> {
+    // unpriced mints come back as `null`
+    let resp: HashMap<String, Option<Price>> = resp.api_json().await?;
+    Ok(resp.into_iter().filter_map(|(mint, p)| Some((mint, p?))).collect())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+/// Price of a single mint, `None` if Jupiter has no price for it
+pub async fn get_price(http: &Client, mint: &str) -> Result<Option<Price>> {
+    Ok(get_prices(http, &[mint]).await?.remove(mint))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+/// USD price of a single mint, `None` if Jupiter has no price for it
+pub async fn get_usd_price(http: &Client, mint: &str) -> Result<Option<f64>> {
+    Ok(get_price(http, mint).await?.map(|p| p.usd_price))
+}
+
+
+// ─────────────────── Price feeds ───────────────────
+
+#[cfg(not(target_arch = "wasm32"))]
+/// One observed price
+#[derive(Serialize, Debug, Clone)]
+pub struct PriceUpdate {
+    pub mint: String,
+    pub usd_price: f64,
+    /// Block the price was observed at, when the source reports one
+    pub block_id: Option<u64>,
+    pub received_at: DateTime<Utc>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+/// A source of live prices. Strategies consume the stream and stay agnostic
+/// of whether it is backed by polling or a push connection.
+pub trait PriceFeed: Send + Sync {
+    /// Updates for `mints` until the stream is dropped. Source errors are
+    /// yielded as items so the consumer decides whether to stop.
+    fn subscribe(&self, mints: Vec<String>) -> BoxStream<'static, Result<PriceUpdate>>;
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+/// [`PriceFeed`] that polls the Price API every `interval`
+#[derive(Clone)]
+pub struct PollingPriceFeed {
+    http: Client,
+    interval: Duration,
+    only_changes: bool,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl PollingPriceFeed {
+    pub fn new(http: Client, interval: Duration) -> Self {
+        Self { http, interval, only_changes: false }
+    }
+
+    /// Skip updates whose price equals the previous one for that mint
+    pub fn only_changes(mut self) -> Self {
+        self.only_changes = true;
+        self
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+struct PollState {
+    feed: PollingPriceFeed,
+    mints: Vec<String>,
+    ticker: tokio::time::Interval,
+    last: HashMap<String, f64>,
+    pending: VecDeque<PriceUpdate>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl PriceFeed for PollingPriceFeed {
+    fn subscribe(&self, mints: Vec<String>) -> BoxStream<'static, Result<PriceUpdate>> {
+        let state = PollState {
+            feed: self.clone(),
+            mints,
+            ticker: tokio::time::interval(self.interval),
+            last: HashMap::new(),
+            pending: VecDeque::new(),
+        };
+        stream::unfold(state, |mut st| async move {
+            loop {
+                if let Some(update) = st.pending.pop_front() {
+                    return Some((Ok(update), st));
+                }
+                st.ticker.tick().await;
+                let prices = match get_prices(&st.feed.http, &st.mints).await {
+                    Ok(prices) => prices,
+                    Err(e) => return Some((Err(e), st)),
+                };
+                let received_at = Utc::now();
+                for (mint, price) in prices {
+                    let unchanged = st.last.insert(mint.clone(), price.usd_price) == Some(price.usd_price);
+                    if st.feed.only_changes && unchanged {
+                        continue;
+                    }
+                    st.pending.push_back(PriceUpdate {
+                        mint,
+                        usd_price: price.usd_price,
+                        block_id: price.block_id,
+                        received_at,
+                    });
+                }
+            }
+        })
+        .boxed()
+    }
+}
+
+
+// ─────────────────── Price Watcher ───────────────────
+
+#[cfg(not(target_arch = "wasm32"))]
+/// Price level that fires once each time the price crosses it
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Threshold {
+    /// Fires when the price rises to or above the level
+    Above(f64),
+    /// Fires when the price falls to or below the level
+    Below(f64),
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Serialize, Debug, Clone)]
+pub enum PriceEvent {
+    Crossed {
+        mint: String,
+        threshold: f64,
+        /// "above" or "below"
+        direction: &'static str,
+        price: f64,
+    },
+    Moved {
+        mint: String,
+        from: f64,
+        to: f64,
+        change_pct: f64,
+        window_secs: u64,
+    },
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl std::fmt::Display for PriceEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PriceEvent::Crossed { mint, threshold, direction, price } => {
+                write!(f, "{} crossed {direction} ${threshold} (now ${price:.6})", mint_label(mint))
+            }
+            PriceEvent::Moved { mint, from, to, change_pct, window_secs } => write!(
+                f,
+                "{} moved {change_pct:+.2}% in {window_secs}s (${from:.6} → ${to:.6})",
+                mint_label(mint)
+            ),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+/// Polls the Price API for a set of mints and reports threshold crossings
+/// and large moves within a rolling window
+pub struct PriceWatcher {
+    feed: Arc<dyn PriceFeed>,
+    mints: Vec<String>,
+    thresholds: HashMap<String, Vec<Threshold>>,
+    /// (minimum absolute % change, window)
+    move_alert: Option<(f64, Duration)>,
+    notifier: Option<Arc<dyn Notifier>>,
+    cancel: CancellationToken,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl PriceWatcher {
+    /// Watch prices polled from the Price API every `poll_interval`
+    pub fn new(http: Client, poll_interval: Duration) -> Self {
+        Self::with_feed(Arc::new(PollingPriceFeed::new(http, poll_interval)))
+    }
+
+    pub fn with_feed(feed: Arc<dyn PriceFeed>) -> Self {
+        Self {
+            feed,
+            mints: Vec::new(),
+            thresholds: HashMap::new(),
+            move_alert: None,
+            notifier: None,
+            cancel: CancellationToken::new(),
+        }
+    }
+
+    /// Poll `mint` (needed for move alerts; thresholds add their mint automatically)
+    pub fn watch(mut self, mint: impl Into<String>) -> Self {
+        let mint = mint.into();
+        if !self.mints.contains(&mint) {
+            self.mints.push(mint);
+        }
+        self
+    }
+
+    pub fn threshold(mut self, mint: impl Into<String>, threshold: Threshold) -> Self {
+        let mint = mint.into();
+        self.thresholds.entry(mint.clone()).or_default().push(threshold);
+        self.watch(mint)
+    }
+
+    /// Fire when any watched mint moves at least `pct` percent within `window`
+    pub fn alert_on_move(mut self, pct: f64, window: Duration) -> Self {
+        self.move_alert = Some((pct, window));
+        self
+    }
+
+    pub fn with_notifier(mut self, notifier: Arc<dyn Notifier>) -> Self {
+        self.notifier = Some(notifier);
+        self
+    }
+
+    /// Stop watching once `token` is cancelled
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancel = token;
+        self
+    }
+
+    /// Start polling in the background. The task ends when the receiver is dropped or on cancellation.
+    pub fn spawn(self) -> (JoinHandle<Result<()>>, mpsc::Receiver<PriceEvent>) {
+        let (tx, rx) = mpsc::channel(64);
+        (tokio::spawn(self.run(tx)), rx)
+    }
+
+    async fn run(self, events: mpsc::Sender<PriceEvent>) -> Result<()> {
+        let mut last: HashMap<String, f64> = HashMap::new();
+        let mut history: HashMap<String, VecDeque<(Instant, f64)>> = HashMap::new();
+        let mut updates = self.feed.subscribe(self.mints.clone());
+
+        loop {
+            let update = tokio::select! {
+                update = updates.next() => update,
+                _ = self.cancel.cancelled() => None,
+            };
+            let Some(update) = update else { break };
+            let update = match update {
+                Ok(update) => update,
+                Err(e) => {
+                    eprintln!("price watcher: feed error: {e:#}");
+                    continue;
+                }
+            };
+
+            let now = Instant::now();
+            let mut pending = Vec::new();
+            let (mint, price) = (&update.mint, update.usd_price);
+
+            if let (Some(&prev), Some(levels)) = (last.get(mint), self.thresholds.get(mint)) {
+                for level in levels {
+                    let crossed = match *level {
+                        Threshold::Above(t) => (prev < t && price >= t).then_some((t, "above")),
+                        Threshold::Below(t) => (prev > t && price <= t).then_some((t, "below")),
+                    };
+                    if let Some((threshold, direction)) = crossed {
+                        pending.push(PriceEvent::Crossed { mint: mint.clone(), threshold, direction, price });
+                    }
+                }
+            }
+            last.insert(mint.clone(), price);
+
+            if let Some((pct, window)) = self.move_alert {
+                let samples = history.entry(mint.clone()).or_default();
+                while samples.front().map_or(false, |(t, _)| now.duration_since(*t) > window) {
+                    samples.pop_front();
+                }
+                if let Some(&(_, from)) = samples.front() {
+                    let change_pct = (price - from) / from * 100.0;
+                    if change_pct.abs() >= pct {
+                        pending.push(PriceEvent::Moved {
+                            mint: mint.clone(),
+                            from,
+                            to: price,
+                            change_pct,
+                            window_secs: window.as_secs(),
+                        });
+                        // start a fresh window so one move is reported once
+                        samples.clear();
+                    }
+                }
+                samples.push_back((now, price));
+            }
+
+            for event in pending {
+                if let Some(notifier) = &self.notifier {
+                    if let Err(e) = notifier.notify(&event.to_string()).await {
+                        eprintln!("price watcher: notify failed: {e:#}");
+                    }
+                }
+                if events.send(event).await.is_err() {
+                    return Ok(());
+                }
+            }
+        }
+        Ok(())
+    }
+}
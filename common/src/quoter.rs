@@ -0,0 +1,188 @@
+// common/src/quoter.rs
+use crate::{get_quote, mint_label, ui_amount, Result, SwapParams};
+use chrono::{DateTime, Utc};
+use futures::stream::{self, StreamExt};
+use reqwest::{Client, StatusCode};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+
+// ─────────────────── Multi-pair live quoter ───────────────────
+
+/// Longest the refresh interval is stretched to while rate limited
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Identifies one quoted pair and size
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PairKey {
+    pub input_mint: String,
+    pub output_mint: String,
+    /// Raw input amount
+    pub amount: u64,
+}
+
+impl From<&SwapParams> for PairKey {
+    fn from(p: &SwapParams) -> Self {
+        Self { input_mint: p.input_mint.clone(), output_mint: p.output_mint.clone(), amount: p.amount.raw }
+    }
+}
+
+impl std::fmt::Display for PairKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} → {}", ui_amount(&self.input_mint, self.amount), mint_label(&self.output_mint))
+    }
+}
+
+/// Latest quote for a [`PairKey`]
+#[derive(Serialize, Debug, Clone)]
+pub struct QuoteSnapshot {
+    pub pair: PairKey,
+    /// Raw output
+    pub out_amount: u64,
+    pub price_impact_pct: f64,
+    /// DEX labels in route order
+    pub route: Vec<String>,
+    pub context_slot: u64,
+    pub fetched_at: DateTime<Utc>,
+}
+
+impl QuoteSnapshot {
+    /// Raw output per raw input
+    pub fn rate(&self) -> f64 {
+        self.out_amount as f64 / self.pair.amount as f64
+    }
+
+    pub fn age(&self) -> chrono::Duration {
+        Utc::now() - self.fetched_at
+    }
+}
+
+type Book = Arc<RwLock<HashMap<PairKey, QuoteSnapshot>>>;
+
+/// Keeps quotes for a fixed set of pairs fresh in the background.
+///
+/// Every `interval` all pairs are re-quoted, at most `max_concurrency` at a
+/// time. A 429 from the Quote API doubles the interval (up to a minute)
+/// until a round succeeds without one.
+pub struct LiveQuoter {
+    http: Client,
+    pairs: Vec<SwapParams>,
+    interval: Duration,
+    max_concurrency: usize,
+    cancel: CancellationToken,
+}
+
+impl LiveQuoter {
+    pub fn new(http: Client, interval: Duration) -> Self {
+        Self { http, pairs: Vec::new(), interval, max_concurrency: 4, cancel: CancellationToken::new() }
+    }
+
+    pub fn pair(mut self, params: SwapParams) -> Self {
+        self.pairs.push(params);
+        self
+    }
+
+    pub fn max_concurrency(mut self, n: usize) -> Self {
+        self.max_concurrency = n.max(1);
+        self
+    }
+
+    /// Stop refreshing once `token` is cancelled; the handle keeps serving the last quotes
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancel = token;
+        self
+    }
+
+    /// Start refreshing; the handle answers queries and hands out update channels
+    pub fn spawn(self) -> QuoterHandle {
+        let book: Book = Arc::default();
+        let (tx, _) = broadcast::channel(self.pairs.len().max(1) * 4);
+
+        let task = {
+            let (book, tx) = (book.clone(), tx.clone());
+            tokio::spawn(async move {
+                let mut wait = self.interval;
+                loop {
+                    let results: Vec<(PairKey, crate::Result<QuoteSnapshot>)> = stream::iter(&self.pairs)
+                        .map(|p| async {
+                            let key = PairKey::from(p);
+                            let snap = get_quote(&self.http, p).await.and_then(|q| {
+                                Ok(QuoteSnapshot {
+                                    pair: key.clone(),
+                                    out_amount: q.outAmount.parse()?,
+                                    price_impact_pct: q.priceImpactPct.parse().unwrap_or(0.0),
+                                    route: q.routePlan.iter().map(|s| s.swap_info.label.clone()).collect(),
+                                    context_slot: q.contextSlot,
+                                    fetched_at: Utc::now(),
+                                })
+                            });
+                            (key, snap)
+                        })
+                        .buffer_unordered(self.max_concurrency)
+                        .collect()
+                        .await;
+
+                    let mut limited = false;
+                    for (key, snap) in results {
+                        match snap {
+                            Ok(snap) => {
+                                book.write().unwrap().insert(key, snap.clone());
+                                // no subscribers is fine
+                                let _ = tx.send(snap);
+                            }
+                            Err(e) => {
+                                limited |= e.status() == Some(StatusCode::TOO_MANY_REQUESTS);
+                                eprintln!("quote {key} failed: {e}");
+                            }
+                        }
+                    }
+                    wait = if limited { (wait * 2).min(MAX_BACKOFF) } else { self.interval };
+                    tokio::select! {
+                        _ = tokio::time::sleep(wait) => {}
+                        _ = self.cancel.cancelled() => break,
+                    }
+                }
+            })
+        };
+
+        QuoterHandle { book, tx, task }
+    }
+}
+
+pub struct QuoterHandle {
+    book: Book,
+    tx: broadcast::Sender<QuoteSnapshot>,
+    task: JoinHandle<()>,
+}
+
+impl QuoterHandle {
+    /// Latest quote for `input → output` at `amount`, if one has arrived
+    pub fn get(&self, input_mint: &str, output_mint: &str, amount: u64) -> Option<QuoteSnapshot> {
+        let key = PairKey { input_mint: input_mint.to_string(), output_mint: output_mint.to_string(), amount };
+        self.book.read().unwrap().get(&key).cloned()
+    }
+
+    /// Every latest quote
+    pub fn snapshot(&self) -> Vec<QuoteSnapshot> {
+        self.book.read().unwrap().values().cloned().collect()
+    }
+
+    /// Each refreshed quote as it lands
+    pub fn subscribe(&self) -> broadcast::Receiver<QuoteSnapshot> {
+        self.tx.subscribe()
+    }
+
+    pub fn stop(&self) {
+        self.task.abort();
+    }
+}
+
+impl Drop for QuoterHandle {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
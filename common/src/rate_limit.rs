@@ -0,0 +1,157 @@
+// common/src/rate_limit.rs
+use crate::{api_key, file_config, request_api, ConfigError, JupiterApi};
+use dotenv::dotenv;
+use reqwest::RequestBuilder;
+use std::collections::HashMap;
+use std::env;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+// ─────────────────── Client-side rate limiting ───────────────────
+//
+// One key shares one request budget across every product except Price, which
+// Jupiter meters separately. Pacing calls here keeps bulk work (portfolio
+// pricing, batch quotes) under the plan limit instead of leaning on 429 retries.
+
+/// Jupiter API plan, for its requests-per-second budget
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Plan {
+    /// lite-api.jup.ag without a key: 60 requests / minute
+    Lite,
+    Pro1,
+    Pro2,
+    Pro3,
+    Pro4,
+}
+
+impl Plan {
+    pub fn requests_per_second(self) -> f64 {
+        match self {
+            Plan::Lite => 1.0,
+            Plan::Pro1 => 10.0,
+            Plan::Pro2 => 50.0,
+            Plan::Pro3 => 100.0,
+            Plan::Pro4 => 500.0,
+        }
+    }
+}
+
+impl FromStr for Plan {
+    type Err = ConfigError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().replace(['-', '_', ' '], "").as_str() {
+            "lite" | "free" => Ok(Plan::Lite),
+            "pro1" | "proi" => Ok(Plan::Pro1),
+            "pro2" | "proii" => Ok(Plan::Pro2),
+            "pro3" | "proiii" => Ok(Plan::Pro3),
+            "pro4" | "proiv" => Ok(Plan::Pro4),
+            _ => Err(ConfigError::Invalid {
+                var: "JUPITER_PLAN",
+                reason: format!("{s:?} is not one of lite, pro1, pro2, pro3, pro4"),
+            }),
+        }
+    }
+}
+
+/// `JUPITER_PLAN`, else `plan` in jupiter.toml, else Pro I with an API key and Lite without
+pub fn plan() -> Plan {
+    dotenv().ok();
+    env::var("JUPITER_PLAN")
+        .ok()
+        .filter(|s| !s.trim().is_empty())
+        .or_else(|| file_config().plan.clone())
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(if api_key().is_some() { Plan::Pro1 } else { Plan::Lite })
+}
+
+/// Token bucket: refills at `rate` tokens a second up to `burst`; each request takes one
+#[derive(Debug)]
+pub struct RateLimiter {
+    rate: f64,
+    burst: f64,
+    bucket: Mutex<Bucket>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    /// Negative when callers have reserved tokens that haven't refilled yet
+    tokens: f64,
+    refilled: Instant,
+}
+
+impl RateLimiter {
+    /// `rps` requests a second with a burst of one second's worth
+    pub fn new(rps: f64) -> Self {
+        Self::with_burst(rps, rps.max(1.0))
+    }
+
+    pub fn with_burst(rps: f64, burst: f64) -> Self {
+        let bucket = Mutex::new(Bucket { tokens: burst, refilled: Instant::now() });
+        Self { rate: rps, burst, bucket }
+    }
+
+    pub fn requests_per_second(&self) -> f64 {
+        self.rate
+    }
+
+    /// Wait for a token. Callers are served in the order they arrive.
+    pub async fn acquire(&self) {
+        let wait = {
+            let mut b = self.bucket.lock().unwrap();
+            let now = Instant::now();
+            b.tokens = (b.tokens + now.duration_since(b.refilled).as_secs_f64() * self.rate).min(self.burst);
+            b.refilled = now;
+            b.tokens -= 1.0;
+            if b.tokens >= 0.0 {
+                return;
+            }
+            Duration::from_secs_f64(-b.tokens / self.rate)
+        };
+        tokio::time::sleep(wait).await;
+    }
+}
+
+/// Requests per second for `api`: `JUPITER_<PRODUCT>_RPS`, else `[rate_limits]`
+/// in jupiter.toml, else the plan's. 0 turns limiting off for that product.
+fn product_rps(api: JupiterApi) -> Option<f64> {
+    env::var(format!("JUPITER_{}_RPS", api.name().to_ascii_uppercase()))
+        .ok()
+        .and_then(|s| s.trim().parse().ok())
+        .or_else(|| file_config().rate_limits.get(api.name()).copied())
+}
+
+/// The limiter every call to `api` goes through, or `None` when it's unlimited.
+/// Products without their own setting share one bucket; Price always has its own.
+pub fn rate_limiter(api: JupiterApi) -> Option<Arc<RateLimiter>> {
+    static LIMITERS: OnceLock<HashMap<JupiterApi, Option<Arc<RateLimiter>>>> = OnceLock::new();
+    LIMITERS
+        .get_or_init(|| {
+            dotenv().ok();
+            let plan_rps = plan().requests_per_second();
+            let shared = Arc::new(RateLimiter::new(plan_rps));
+            JupiterApi::ALL
+                .into_iter()
+                .map(|api| {
+                    let limiter = match product_rps(api) {
+                        Some(rps) if rps <= 0.0 => None,
+                        Some(rps) => Some(Arc::new(RateLimiter::new(rps))),
+                        None if api == JupiterApi::Price => Some(Arc::new(RateLimiter::new(plan_rps))),
+                        None => Some(shared.clone()),
+                    };
+                    (api, limiter)
+                })
+                .collect()
+        })
+        .get(&api)
+        .cloned()
+        .flatten()
+}
+
+/// Wait for the limiter of whichever product `req` is addressed to
+pub(crate) async fn throttle(req: &RequestBuilder) {
+    if let Some(limiter) = request_api(req).and_then(rate_limiter) {
+        limiter.acquire().await;
+    }
+}
@@ -0,0 +1,1497 @@
+// common/src/recurring.rs
+use crate::metrics::{record_sent, SentVia};
+use crate::{
+    api_url, dry_run, emit, execute_guard, get_price, mainnet_guard, mint_label, notifier_from_env, progress,
+    rpc_client, safety, sign_versioned_tx, simulate_execute, tx_link, ApiResponseExt, Config, Error, JupiterApi,
+    JupiterClient, JupiterReqExt, KnownExtra, Notifier, Result, TokenAmount, TxSigner, DRY_RUN_STATUS, USDC_MINT,
+    WSOL_MINT,
+};
+use base64::{decode, encode};
+use bincode::{deserialize, serialize};
+use chrono::{DateTime, TimeZone, Utc};
+use futures::stream::{self, StreamExt};
+use reqwest::Client;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use solana_sdk::{pubkey::Pubkey, transaction::VersionedTransaction};
+use std::collections::HashSet;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+use tokio::task::JoinHandle;
+
+// ─────────────────────────── Recurring Flow (/recurring/v1/createOrder -> /recurring/v1/execute) ──────────────────────────
+
+
+/// Transaction payload returned by createOrder, cancelOrder, priceDeposit and priceWithdraw
+#[derive(Deserialize, Debug)]
+pub struct RecurringTxResponse {
+    /// Base64-encoded unsigned transaction
+    #[serde(default, rename = "transaction", alias = "tx", alias = "transactions")]
+    pub transaction: Option<String>,
+
+    /// Request ID for matching with execute
+    #[serde(default, rename = "requestId", alias = "request_id")]
+    pub request_id: Option<String>,
+
+    /// Order public key string
+    #[serde(default, rename = "order")]
+    pub order: Option<String>,
+
+    /// Any additional fields (e.g. code, error)
+    #[serde(flatten)]
+    pub extra: serde_json::Value,
+}
+
+/// Unsigned recurring transaction ready to be signed and executed
+#[derive(Debug, Clone)]
+pub struct RecurringTx {
+    pub request_id: String,
+    /// Base64-encoded unsigned transaction
+    pub transaction: String,
+    pub order: Option<String>,
+}
+
+impl KnownExtra for RecurringTxResponse {
+    const KNOWN_EXTRA: &'static [&'static str] = &["code", "error", "message"];
+
+    fn extra(&self) -> &serde_json::Value {
+        &self.extra
+    }
+}
+
+impl RecurringTxResponse {
+    /// The transaction payload, or the API error that replaced it
+    pub fn into_tx(self) -> Result<RecurringTx, RecurringError> {
+        match self.transaction {
+            Some(transaction) if !transaction.is_empty() => Ok(RecurringTx {
+                request_id: self.request_id.unwrap_or_default(),
+                transaction,
+                order: self.order,
+            }),
+            _ => Err(RecurringError::from_response(&self.extra)),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ExecuteRecurringResponse {
+    #[serde(default)]
+    pub signature: String,
+    pub status: String,
+    pub order: Option<String>,
+    pub error: Option<String>,
+}
+
+impl ExecuteRecurringResponse {
+    /// Debug dump plus an explorer link for the flows' text output
+    fn summary(&self, label: &str) -> String {
+        format!("{label}: {self:#?}\n{}", tx_link(&self.signature))
+    }
+}
+
+/// Which recurring strategy an order uses
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum RecurringType {
+    Time,
+    Price,
+}
+
+
+/// Fewest cycles the Recurring API accepts for a time-based order
+pub const MIN_RECURRING_ORDERS: u64 = 2;
+/// Shortest allowed gap between cycles (1 minute)
+pub const MIN_RECURRING_INTERVAL_SECS: u64 = 60;
+/// Longest allowed gap between cycles (1 year)
+pub const MAX_RECURRING_INTERVAL_SECS: u64 = 365 * 86_400;
+/// Smallest per-cycle amount when paying in USDC (50 USDC, raw units)
+pub const MIN_USDC_PER_CYCLE: u64 = 50_000_000;
+
+/// Parse a human interval such as "1d", "6h" or "45m" into seconds, checked
+/// against the Recurring API's interval bounds
+pub fn parse_interval(interval: &str) -> Result<u64> {
+    let secs = humantime::parse_duration(interval.trim())
+        .map_err(|e| Error::Invalid(format!("invalid interval {interval:?}: {e}")))?
+        .as_secs();
+    if !(MIN_RECURRING_INTERVAL_SECS..=MAX_RECURRING_INTERVAL_SECS).contains(&secs) {
+        return Err(Error::Invalid(format!(
+            "interval {interval:?} ({secs}s) must be between {} and {}",
+            humantime::format_duration(Duration::from_secs(MIN_RECURRING_INTERVAL_SECS)),
+            humantime::format_duration(Duration::from_secs(MAX_RECURRING_INTERVAL_SECS)),
+        )));
+    }
+    Ok(secs)
+}
+
+/// Time-based recurring (DCA) order
+#[derive(Debug, Clone)]
+pub struct RecurringOrderParams {
+    pub input_mint: String,
+    pub output_mint: String,
+    /// Total amount of the input mint to spend across all cycles
+    pub in_amount: TokenAmount,
+    pub number_of_orders: u64,
+    /// Seconds between cycles
+    pub interval_secs: u64,
+    /// Lower price bound; cycles priced below it are skipped
+    pub min_price: Option<f64>,
+    /// Upper price bound; cycles priced above it are skipped
+    pub max_price: Option<f64>,
+    /// First cycle time; `None` starts immediately
+    pub start_at: Option<DateTime<Utc>>,
+}
+
+impl Default for RecurringOrderParams {
+    /// 0.05 SOL → USDC over 2 daily cycles
+    fn default() -> Self {
+        Self {
+            input_mint: WSOL_MINT.to_string(),
+            output_mint: USDC_MINT.to_string(),
+            in_amount: TokenAmount::new(50_000_000, 9),
+            number_of_orders: 2,
+            interval_secs: 86_400,
+            min_price: None,
+            max_price: None,
+            start_at: None,
+        }
+    }
+}
+
+impl RecurringOrderParams {
+    /// Only execute cycles while the price stays inside `[min, max]`
+    pub fn with_price_bounds(mut self, min: Option<f64>, max: Option<f64>) -> Self {
+        self.min_price = min;
+        self.max_price = max;
+        self
+    }
+
+    /// Set the gap between cycles from a human interval ("1d", "6h", "45m")
+    pub fn with_interval(mut self, interval: &str) -> Result<Self> {
+        self.interval_secs = parse_interval(interval)?;
+        Ok(self)
+    }
+
+    /// Delay the first cycle until `at` (any chrono timezone)
+    pub fn starting_at<Tz: TimeZone>(mut self, at: DateTime<Tz>) -> Self {
+        self.start_at = Some(at.with_timezone(&Utc));
+        self
+    }
+
+    /// Raw input amount spent on each cycle
+    pub fn amount_per_cycle(&self) -> u64 {
+        self.in_amount.raw / self.number_of_orders.max(1)
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        Pubkey::from_str(&self.input_mint)
+            .map_err(|_| Error::Invalid(format!("invalid input mint: {}", self.input_mint)))?;
+        Pubkey::from_str(&self.output_mint)
+            .map_err(|_| Error::Invalid(format!("invalid output mint: {}", self.output_mint)))?;
+        if self.input_mint == self.output_mint {
+            return Err(Error::Invalid("input and output mint must differ".into()));
+        }
+        if self.number_of_orders < MIN_RECURRING_ORDERS {
+            return Err(Error::Invalid(format!(
+                "numberOfOrders must be at least {MIN_RECURRING_ORDERS} (got {})",
+                self.number_of_orders
+            )));
+        }
+        if !(MIN_RECURRING_INTERVAL_SECS..=MAX_RECURRING_INTERVAL_SECS).contains(&self.interval_secs) {
+            return Err(Error::Invalid(format!(
+                "interval must be between {MIN_RECURRING_INTERVAL_SECS}s and {MAX_RECURRING_INTERVAL_SECS}s (got {}s)",
+                self.interval_secs
+            )));
+        }
+        let per_cycle = self.amount_per_cycle();
+        if per_cycle == 0 {
+            return Err(Error::Invalid(format!(
+                "inAmount {} is too small to split across {} orders",
+                self.in_amount.raw, self.number_of_orders
+            )));
+        }
+        for (name, bound) in [("minPrice", self.min_price), ("maxPrice", self.max_price)] {
+            if let Some(p) = bound {
+                if !p.is_finite() || p <= 0.0 {
+                    return Err(Error::Invalid(format!("{name} must be a positive number (got {p})")));
+                }
+            }
+        }
+        if let (Some(min), Some(max)) = (self.min_price, self.max_price) {
+            if min > max {
+                return Err(Error::Invalid(format!("minPrice {min} is above maxPrice {max}")));
+            }
+        }
+        if let Some(start_at) = self.start_at {
+            if start_at <= Utc::now() {
+                return Err(Error::Invalid(format!("startAt {start_at} is not in the future")));
+            }
+        }
+        if self.input_mint == USDC_MINT && per_cycle < MIN_USDC_PER_CYCLE {
+            return Err(Error::Invalid(format!(
+                "each cycle must spend at least {} USDC (got {} raw units per cycle)",
+                MIN_USDC_PER_CYCLE / 1_000_000, per_cycle
+            )));
+        }
+        Ok(())
+    }
+
+    fn to_request<'a>(&'a self, user: &'a str, payer: Option<&'a str>) -> CreateRecurringRequest<'a> {
+        CreateRecurringRequest {
+            user,
+            payer,
+            input_mint: &self.input_mint,
+            output_mint: &self.output_mint,
+            params: CreateRecurringStrategy::Time(TimeRecurringRequest {
+                in_amount: self.in_amount.raw,
+                number_of_orders: self.number_of_orders,
+                interval: self.interval_secs,
+                min_price: self.min_price,
+                max_price: self.max_price,
+                start_at: self.start_at.map(|t| t.timestamp()),
+            }),
+        }
+    }
+}
+
+/// What a recurring order will do, for confirmation before signing
+#[derive(Serialize, Debug, Clone)]
+pub struct RecurringPreview {
+    /// Raw input amount spent each cycle
+    pub amount_per_cycle: u64,
+    pub number_of_cycles: u64,
+    /// Raw input amount deposited up front: all of `in_amount`
+    pub total_deposit: u64,
+    /// Raw input the even split across cycles leaves over; part of the deposit, but of no cycle's amount
+    pub remainder: u64,
+    pub interval_secs: u64,
+    pub first_execution: DateTime<Utc>,
+    pub estimated_completion: DateTime<Utc>,
+    /// USD value of one cycle at the current price, if the input mint is priced
+    pub per_cycle_usd: Option<f64>,
+    pub total_usd: Option<f64>,
+}
+
+/// Validate `params` and describe the resulting schedule without creating anything
+pub async fn preview_recurring_order(http: &Client, params: &RecurringOrderParams) -> Result<RecurringPreview> {
+    params.validate()?;
+
+    let amount_per_cycle = params.amount_per_cycle();
+    let total_deposit    = params.in_amount.raw;
+    let remainder        = total_deposit - amount_per_cycle * params.number_of_orders;
+    let first_execution  = params.start_at.unwrap_or_else(Utc::now);
+    // first cycle runs at startAt (or immediately), the rest one interval apart
+    let estimated_completion = first_execution
+        + chrono::Duration::seconds((params.interval_secs * (params.number_of_orders - 1)) as i64);
+
+    let usd_per_raw = get_price(http, &params.input_mint)
+        .await?
+        .map(|p| p.usd_price / 10f64.powi(p.decimals as i32));
+
+    Ok(RecurringPreview {
+        amount_per_cycle,
+        number_of_cycles: params.number_of_orders,
+        total_deposit,
+        remainder,
+        interval_secs: params.interval_secs,
+        first_execution,
+        estimated_completion,
+        per_cycle_usd: usd_per_raw.map(|usd| usd * amount_per_cycle as f64),
+        total_usd: usd_per_raw.map(|usd| usd * total_deposit as f64),
+    })
+}
+
+pub async fn recurring_flow(kp: &dyn TxSigner, params: RecurringOrderParams) -> Result<()> {
+    let cfg = Config::load()?;
+
+    // 0. Preview (also validates params)
+    let preview = preview_recurring_order(JupiterClient::new(&cfg)?.http(), &params).await?;
+    progress(format!("Recurring preview: {preview:#?}"));
+
+    recurring_with_preview(kp, params, preview).await?;
+    Ok(())
+}
+
+/// Create, sign and execute the order `preview` was made for, once it has been
+/// shown (and confirmed); the emitted `recurring` event carries both
+pub async fn recurring_with_preview(
+    kp: &dyn TxSigner,
+    params: RecurringOrderParams,
+    preview: RecurringPreview,
+) -> Result<ExecuteRecurringResponse> {
+    let cfg = Config::load()?;
+    mainnet_guard(&rpc_client(&cfg)).await?;
+    let user = kp.address().to_string();
+
+    // 1. Create order
+    let recurring = JupiterClient::new(&cfg)?.recurring();
+    let payer = kp.fee_payer().map(|p| p.address().to_string());
+    let tx = recurring.create_order(&user, payer.as_deref(), &params).await?;
+
+    // 2. Decode, sign, execute
+    safety::pre_trade_check(&params.output_mint).await?;
+    let exec_resp = recurring.sign_and_execute(kp, &tx).await?;
+
+    let text = exec_resp.summary("Recurring execute");
+    emit("recurring", &json!({ "preview": preview, "execute": exec_resp }), text);
+    Ok(exec_resp)
+}
+
+/// Price-based recurring order: each cycle buys enough to raise the position's
+/// USD value by `increment_usdc_value`, until the deposit is spent
+#[derive(Debug, Clone)]
+pub struct PriceRecurringParams {
+    pub input_mint: String,
+    pub output_mint: String,
+    /// Amount of the input mint deposited up front
+    pub deposit_amount: TokenAmount,
+    /// USDC value the position should grow by each cycle
+    pub increment_usdc_value: TokenAmount,
+    /// Seconds between cycles
+    pub interval_secs: u64,
+    /// First cycle time; `None` starts immediately
+    pub start_at: Option<DateTime<Utc>>,
+}
+
+impl Default for PriceRecurringParams {
+    /// 100 USDC → SOL, growing by 10 USDC a day
+    fn default() -> Self {
+        Self {
+            input_mint: USDC_MINT.to_string(),
+            output_mint: WSOL_MINT.to_string(),
+            deposit_amount: TokenAmount::new(100_000_000, 6),
+            increment_usdc_value: TokenAmount::new(10_000_000, 6),
+            interval_secs: 86_400,
+            start_at: None,
+        }
+    }
+}
+
+impl PriceRecurringParams {
+    /// Set the gap between cycles from a human interval ("1d", "6h", "45m")
+    pub fn with_interval(mut self, interval: &str) -> Result<Self> {
+        self.interval_secs = parse_interval(interval)?;
+        Ok(self)
+    }
+
+    /// Delay the first cycle until `at` (any chrono timezone)
+    pub fn starting_at<Tz: TimeZone>(mut self, at: DateTime<Tz>) -> Self {
+        self.start_at = Some(at.with_timezone(&Utc));
+        self
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        Pubkey::from_str(&self.input_mint)
+            .map_err(|_| Error::Invalid(format!("invalid input mint: {}", self.input_mint)))?;
+        Pubkey::from_str(&self.output_mint)
+            .map_err(|_| Error::Invalid(format!("invalid output mint: {}", self.output_mint)))?;
+        if self.input_mint == self.output_mint {
+            return Err(Error::Invalid("input and output mint must differ".into()));
+        }
+        if self.deposit_amount.raw == 0 {
+            return Err(Error::Invalid("depositAmount must be greater than zero".into()));
+        }
+        if self.increment_usdc_value.raw == 0 {
+            return Err(Error::Invalid("incrementUsdcValue must be greater than zero".into()));
+        }
+        if !(MIN_RECURRING_INTERVAL_SECS..=MAX_RECURRING_INTERVAL_SECS).contains(&self.interval_secs) {
+            return Err(Error::Invalid(format!(
+                "interval must be between {MIN_RECURRING_INTERVAL_SECS}s and {MAX_RECURRING_INTERVAL_SECS}s (got {}s)",
+                self.interval_secs
+            )));
+        }
+        if let Some(start_at) = self.start_at {
+            if start_at <= Utc::now() {
+                return Err(Error::Invalid(format!("startAt {start_at} is not in the future")));
+            }
+        }
+        Ok(())
+    }
+
+    fn to_request<'a>(&'a self, user: &'a str, payer: Option<&'a str>) -> CreateRecurringRequest<'a> {
+        CreateRecurringRequest {
+            user,
+            payer,
+            input_mint: &self.input_mint,
+            output_mint: &self.output_mint,
+            params: CreateRecurringStrategy::Price(PriceRecurringRequest {
+                deposit_amount: self.deposit_amount.raw,
+                increment_usdc_value: self.increment_usdc_value.raw,
+                interval: self.interval_secs,
+                start_at: self.start_at.map(|t| t.timestamp()),
+            }),
+        }
+    }
+}
+
+pub async fn price_recurring_flow(kp: &dyn TxSigner, params: PriceRecurringParams) -> Result<ExecuteRecurringResponse> {
+    let cfg  = Config::load()?;
+    mainnet_guard(&rpc_client(&cfg)).await?;
+    let user = kp.address().to_string();
+
+    // 1. Create order (also validates params)
+    let recurring = JupiterClient::new(&cfg)?.recurring();
+    let payer = kp.fee_payer().map(|p| p.address().to_string());
+    let tx = recurring.create_price_order(&user, payer.as_deref(), &params).await?;
+
+    // 2. Decode, sign, execute
+    safety::pre_trade_check(&params.output_mint).await?;
+    let exec_resp = recurring.sign_and_execute(kp, &tx).await?;
+
+    emit("recurring", &exec_resp, exec_resp.summary("Recurring execute"));
+    Ok(exec_resp)
+}
+
+/// Failure reported by a recurring endpoint instead of a transaction
+#[derive(Debug, thiserror::Error)]
+pub enum RecurringError {
+    #[error("order amount is below the Recurring API minimum: {0}")]
+    AmountTooSmall(String),
+    #[error("numberOfOrders rejected by the Recurring API: {0}")]
+    TooManyOrders(String),
+    #[error("recurring API error (code {code:?}): {message}")]
+    Api { code: Option<i64>, message: String },
+    #[error("recurring API returned neither a transaction nor an error")]
+    MissingTransaction,
+    #[error("recurring execute failed with status {status}: {message}")]
+    ExecuteFailed { status: String, message: String },
+}
+
+impl RecurringError {
+    /// Classify the `error`/`code` fields of a recurring response body
+    pub fn from_response(body: &serde_json::Value) -> Self {
+        let code = match &body["code"] {
+            serde_json::Value::Number(n) => n.as_i64(),
+            serde_json::Value::String(s) => s.parse().ok(),
+            _ => None,
+        };
+        let message = match body["error"].as_str().or_else(|| body["message"].as_str()) {
+            Some(m) => m.to_string(),
+            None if code.is_none() => return RecurringError::MissingTransaction,
+            None => body.to_string(),
+        };
+
+        let lower = message.to_ascii_lowercase();
+        if lower.contains("numberoforders") || lower.contains("number of orders") || lower.contains("too many") {
+            RecurringError::TooManyOrders(message)
+        } else if lower.contains("minimum") || lower.contains("too small") || lower.contains("at least") {
+            RecurringError::AmountTooSmall(message)
+        } else {
+            RecurringError::Api { code, message }
+        }
+    }
+}
+
+
+// ─────────────────────────── Recurring Client ──────────────────────────
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CreateRecurringRequest<'a> {
+    user: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    payer: Option<&'a str>,
+    input_mint: &'a str,
+    output_mint: &'a str,
+    params: CreateRecurringStrategy,
+}
+
+/// `{ "time": {…} }` or `{ "price": {…} }`
+#[derive(Serialize)]
+#[serde(rename_all = "lowercase")]
+enum CreateRecurringStrategy {
+    Time(TimeRecurringRequest),
+    Price(PriceRecurringRequest),
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TimeRecurringRequest {
+    in_amount: u64,
+    number_of_orders: u64,
+    interval: u64,
+    min_price: Option<f64>,
+    max_price: Option<f64>,
+    /// unix seconds
+    start_at: Option<i64>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PriceRecurringRequest {
+    deposit_amount: u64,
+    increment_usdc_value: u64,
+    interval: u64,
+    /// unix seconds
+    start_at: Option<i64>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ExecuteRecurringRequest<'a> {
+    signed_transaction: &'a str,
+    request_id: &'a str,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CancelRecurringRequest<'a> {
+    order: &'a str,
+    user: &'a str,
+    recurring_type: RecurringType,
+}
+
+#[derive(Serialize)]
+struct PriceDepositRequest<'a> {
+    order: &'a str,
+    user: &'a str,
+    amount: u64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PriceWithdrawRequest<'a> {
+    order: &'a str,
+    user: &'a str,
+    input_or_output: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    amount: Option<u64>,
+}
+
+/// Typed access to every /recurring/v1 endpoint over one shared HTTP client
+#[derive(Clone)]
+pub struct RecurringClient {
+    http: Client,
+    base_url: String,
+    api_key: Option<String>,
+}
+
+impl RecurringClient {
+    pub fn new(http: Client) -> Self {
+        Self { http, base_url: api_url(JupiterApi::Recurring), api_key: None }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Key to send instead of reading `API_KEY` on every request
+    pub fn with_api_key(mut self, api_key: Option<String>) -> Self {
+        self.api_key = api_key;
+        self
+    }
+
+    async fn post<B: Serialize, R: DeserializeOwned + 'static>(&self, endpoint: &str, body: &B) -> Result<R> {
+        let resp = self.http
+            .post(format!("{}/{}", self.base_url, endpoint))
+            .with_api_key(self.api_key.as_deref())
+            .json(body)
+            .send_with_retry().await?;
+        match resp.api_json().await {
+            // rejected orders come back as 4xx with a recurring error code; anything else (429, 5xx, a proxy's
+            // error page) keeps its status, request id and Retry-After
+            Err(Error::Status(e)) if e.status.is_client_error() && !e.is_rate_limited() && e.code.is_some() => {
+                Err(RecurringError::from_response(&e.body).into())
+            }
+            other => other,
+        }
+    }
+
+    /// createOrder for a validated time-based order
+    /// `payer`, when not the user, covers the order's fees and rent and must co-sign
+    pub async fn create_order(
+        &self,
+        user: &str,
+        payer: Option<&str>,
+        params: &RecurringOrderParams,
+    ) -> Result<RecurringTx> {
+        params.validate()?;
+        let resp: RecurringTxResponse = self.post("createOrder", &params.to_request(user, payer)).await?;
+        Ok(resp.into_tx()?)
+    }
+
+    /// createOrder for a validated price-based order; `payer` as in [`create_order`](Self::create_order)
+    pub async fn create_price_order(
+        &self,
+        user: &str,
+        payer: Option<&str>,
+        params: &PriceRecurringParams,
+    ) -> Result<RecurringTx> {
+        params.validate()?;
+        let resp: RecurringTxResponse = self.post("createOrder", &params.to_request(user, payer)).await?;
+        Ok(resp.into_tx()?)
+    }
+
+    pub async fn cancel_order(&self, user: &str, order: &str, recurring_type: RecurringType) -> Result<RecurringTx> {
+        let body = CancelRecurringRequest { order, user, recurring_type };
+        let resp: RecurringTxResponse = self.post("cancelOrder", &body).await?;
+        Ok(resp.into_tx()?)
+    }
+
+    pub async fn price_deposit(&self, user: &str, params: &PriceDepositParams) -> Result<RecurringTx> {
+        params.validate()?;
+        let body = PriceDepositRequest { order: &params.order, user, amount: params.amount.raw };
+        let resp: RecurringTxResponse = self.post("priceDeposit", &body).await?;
+        Ok(resp.into_tx()?)
+    }
+
+    pub async fn price_withdraw(&self, user: &str, params: &WithdrawParams) -> Result<RecurringTx> {
+        params.validate()?;
+        let body = PriceWithdrawRequest {
+            order: &params.order,
+            user,
+            input_or_output: params.side.as_api(),
+            amount: params.amount.map(|a| a.raw),
+        };
+        let resp: RecurringTxResponse = self.post("priceWithdraw", &body).await?;
+        Ok(resp.into_tx()?)
+    }
+
+    /// Submit an already signed transaction; a non-"Success" status is an error.
+    /// Under `DRY_RUN` the transaction is only simulated; otherwise it needs `I_UNDERSTAND_MAINNET`.
+    pub async fn execute(&self, signed_transaction: &str, request_id: &str) -> Result<ExecuteRecurringResponse> {
+        if dry_run() {
+            let signature = simulate_execute("/recurring/v1/execute", signed_transaction).await?;
+            return Ok(ExecuteRecurringResponse {
+                signature: signature.to_string(),
+                status: DRY_RUN_STATUS.into(),
+                order: None,
+                error: None,
+            });
+        }
+        execute_guard()?;
+        let body = ExecuteRecurringRequest { signed_transaction, request_id };
+        let resp: ExecuteRecurringResponse = self.post("execute", &body).await?;
+        record_sent(SentVia::Recurring);
+        if resp.status != "Success" {
+            return Err(RecurringError::ExecuteFailed {
+                status: resp.status,
+                message: resp.error.unwrap_or_default(),
+            }.into());
+        }
+        Ok(resp)
+    }
+
+    /// Sign `tx` with `kp` and execute it
+    pub async fn sign_and_execute(&self, kp: &dyn TxSigner, tx: &RecurringTx) -> Result<ExecuteRecurringResponse> {
+        let mut versioned: VersionedTransaction = deserialize(&decode(&tx.transaction)?)?;
+        sign_versioned_tx(&mut versioned, kp).await?;
+        let signed = encode(&serialize(&versioned)?);
+        self.execute(&signed, &tx.request_id).await
+    }
+
+    /// Every time- and price-based recurring order of `user` with the given status
+    pub async fn get_orders(&self, user: &str, status: RecurringOrderStatus) -> Result<Vec<RecurringOrder>> {
+        let mut orders = Vec::new();
+        let mut page = 1;
+        loop {
+            let url = format!(
+                "{}/getRecurringOrders?user={}&orderStatus={}&recurringType=all&includeFailedTx=false&page={}",
+                self.base_url, user, status.as_api(), page
+            );
+            let resp: RecurringOrdersResponse = self.http.get(&url).with_api_key(self.api_key.as_deref()).send_with_retry().await?.api_json().await?;
+            orders.extend(resp.all);
+            orders.extend(resp.time);
+            orders.extend(resp.price);
+            if resp.page >= resp.total_pages {
+                break;
+            }
+            page += 1;
+        }
+        Ok(orders)
+    }
+}
+
+pub async fn cancel_recurring_flow(
+    kp: &dyn TxSigner,
+    order: &str,
+    recurring_type: RecurringType,
+) -> Result<ExecuteRecurringResponse> {
+    let cfg  = Config::load()?;
+    mainnet_guard(&rpc_client(&cfg)).await?;
+    let user = kp.address().to_string();
+
+    let recurring = JupiterClient::new(&cfg)?.recurring();
+    let tx = recurring.cancel_order(&user, order, recurring_type).await?;
+    let exec_resp = recurring.sign_and_execute(kp, &tx).await?;
+
+    emit("recurring_cancel", &exec_resp, exec_resp.summary("Recurring cancel"));
+    Ok(exec_resp)
+}
+
+
+// ─────────────────────────── Recurring Price Deposit (/recurring/v1/priceDeposit -> /recurring/v1/execute) ──────────────────────────
+
+/// Top-up of an active price-based recurring order
+#[derive(Debug, Clone)]
+pub struct PriceDepositParams {
+    /// Recurring order public key
+    pub order: String,
+    /// Amount of the order's input mint
+    pub amount: TokenAmount,
+}
+
+impl PriceDepositParams {
+    pub fn validate(&self) -> Result<()> {
+        Pubkey::from_str(&self.order)
+            .map_err(|_| Error::Invalid(format!("invalid recurring order pubkey: {}", self.order)))?;
+        if self.amount.raw == 0 {
+            return Err(Error::Invalid("deposit amount must be greater than zero".into()));
+        }
+        Ok(())
+    }
+}
+
+pub async fn price_deposit_flow(kp: &dyn TxSigner, params: PriceDepositParams) -> Result<ExecuteRecurringResponse> {
+    params.validate()?;
+
+    let cfg  = Config::load()?;
+    mainnet_guard(&rpc_client(&cfg)).await?;
+    let user = kp.address().to_string();
+
+    // 1. Request deposit transaction
+    let recurring = JupiterClient::new(&cfg)?.recurring();
+    let tx = recurring.price_deposit(&user, &params).await?;
+
+    // 2. Decode, sign, execute
+    let exec_resp = recurring.sign_and_execute(kp, &tx).await?;
+
+    emit("recurring_deposit", &exec_resp, exec_resp.summary("Recurring deposit"));
+    Ok(exec_resp)
+}
+
+
+// ─────────────────────────── Recurring Withdraw (/recurring/v1/priceWithdraw -> /recurring/v1/execute) ──────────────────────────
+
+/// Which side of a recurring position to withdraw
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WithdrawSide {
+    /// Unspent input tokens still held by the order
+    Input,
+    /// Output tokens accumulated by executed cycles
+    Output,
+}
+
+impl WithdrawSide {
+    fn as_api(&self) -> &'static str {
+        match self {
+            WithdrawSide::Input  => "In",
+            WithdrawSide::Output => "Out",
+        }
+    }
+}
+
+impl FromStr for WithdrawSide {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "in" | "input"   => Ok(WithdrawSide::Input),
+            "out" | "output" => Ok(WithdrawSide::Output),
+            other => Err(Error::Invalid(format!("unknown withdraw side {other:?} (expected \"in\" or \"out\")"))),
+        }
+    }
+}
+
+/// Withdrawal from an open recurring order
+#[derive(Debug, Clone)]
+pub struct WithdrawParams {
+    /// Recurring order public key
+    pub order: String,
+    pub side: WithdrawSide,
+    /// Amount to withdraw; `None` withdraws everything on that side
+    pub amount: Option<TokenAmount>,
+}
+
+impl WithdrawParams {
+    pub fn validate(&self) -> Result<()> {
+        Pubkey::from_str(&self.order)
+            .map_err(|_| Error::Invalid(format!("invalid recurring order pubkey: {}", self.order)))?;
+        if self.amount.is_some_and(|a| a.raw == 0) {
+            return Err(Error::Invalid(
+                "withdraw amount must be greater than zero (omit it to withdraw everything)".into(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+pub async fn withdraw_flow(kp: &dyn TxSigner, params: WithdrawParams) -> Result<ExecuteRecurringResponse> {
+    params.validate()?;
+
+    let cfg  = Config::load()?;
+    mainnet_guard(&rpc_client(&cfg)).await?;
+    let user = kp.address().to_string();
+
+    // 1. Request withdraw transaction
+    let recurring = JupiterClient::new(&cfg)?.recurring();
+    let tx = recurring.price_withdraw(&user, &params).await?;
+
+    // 2. Decode, sign, execute
+    let exec_resp = recurring.sign_and_execute(kp, &tx).await?;
+
+    emit("recurring_withdraw", &exec_resp, exec_resp.summary("Recurring withdraw"));
+    Ok(exec_resp)
+}
+
+
+// ─────────────────────────── Recurring Orders & Fill Report (/recurring/v1/getRecurringOrders) ──────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecurringOrderStatus {
+    Active,
+    History,
+}
+
+impl RecurringOrderStatus {
+    fn as_api(&self) -> &'static str {
+        match self {
+            RecurringOrderStatus::Active  => "active",
+            RecurringOrderStatus::History => "history",
+        }
+    }
+}
+
+/// One executed cycle of a recurring order. Amounts are UI (decimal) strings,
+/// the `raw_*` fields carry base units.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RecurringTrade {
+    pub order_key: String,
+    pub input_mint: String,
+    pub output_mint: String,
+    pub input_amount: String,
+    pub output_amount: String,
+    pub raw_input_amount: String,
+    pub raw_output_amount: String,
+    pub fee_mint: String,
+    pub fee_amount: String,
+    pub tx_id: String,
+    pub confirmed_at: String,
+    pub action: String,
+}
+
+/// Time- or price-based recurring order as returned by getRecurringOrders
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RecurringOrder {
+    pub user_pubkey: String,
+    pub order_key: String,
+    pub input_mint: String,
+    pub output_mint: String,
+    pub in_deposited: String,
+    pub in_withdrawn: String,
+    pub in_used: String,
+    pub out_received: String,
+    pub out_withdrawn: String,
+    /// time-based only: seconds between cycles
+    pub cycle_frequency: Option<String>,
+    /// time-based only
+    pub in_amount_per_cycle: Option<String>,
+    /// time-based only: lower out-amount bound per cycle ("0" when unset)
+    pub min_out_amount: Option<String>,
+    /// time-based only: upper out-amount bound per cycle ("0" when unset)
+    pub max_out_amount: Option<String>,
+    /// price-based only: seconds between cycles
+    pub order_interval: Option<String>,
+    /// price-based only
+    pub in_left: Option<String>,
+    pub open_tx: String,
+    pub close_tx: String,
+    pub user_closed: bool,
+    pub created_at: String,
+    pub updated_at: String,
+    pub trades: Vec<RecurringTrade>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+struct RecurringOrdersResponse {
+    time: Vec<RecurringOrder>,
+    price: Vec<RecurringOrder>,
+    all: Vec<RecurringOrder>,
+    total_pages: u32,
+    page: u32,
+}
+
+fn ui_amount(s: &str) -> f64 {
+    s.parse().unwrap_or(0.0)
+}
+
+/// Amounts below this are treated as dust when checking order balances
+const DUST: f64 = 1e-9;
+
+impl RecurringOrder {
+    pub fn recurring_type(&self) -> RecurringType {
+        if self.order_interval.is_some() || self.in_left.is_some() {
+            RecurringType::Price
+        } else {
+            RecurringType::Time
+        }
+    }
+
+    /// Input still held by the order (UI units)
+    pub fn remaining_in(&self) -> f64 {
+        match &self.in_left {
+            Some(left) => ui_amount(left),
+            None => ui_amount(&self.in_deposited) - ui_amount(&self.in_withdrawn) - ui_amount(&self.in_used),
+        }
+    }
+
+    /// Output received by the order but not yet withdrawn (UI units)
+    pub fn unclaimed_out(&self) -> f64 {
+        ui_amount(&self.out_received) - ui_amount(&self.out_withdrawn)
+    }
+
+    /// All input has been spent or withdrawn
+    pub fn is_completed(&self) -> bool {
+        self.remaining_in() <= DUST
+    }
+
+    /// Seconds between cycles, for either strategy
+    pub fn cycle_secs(&self) -> Option<u64> {
+        self.cycle_frequency
+            .as_deref()
+            .or(self.order_interval.as_deref())
+            .and_then(|s| s.parse().ok())
+    }
+
+    /// Whether cycles can be skipped because of min/max price bounds
+    pub fn has_price_bounds(&self) -> bool {
+        [&self.min_out_amount, &self.max_out_amount]
+            .iter()
+            .any(|b| b.as_deref().map(ui_amount).unwrap_or(0.0) > 0.0)
+    }
+
+    /// Time of the latest fill, or of order creation when nothing has filled yet
+    pub fn last_activity(&self) -> Option<DateTime<Utc>> {
+        self.trades
+            .iter()
+            .filter_map(|t| parse_api_time(&t.confirmed_at))
+            .max()
+            .or_else(|| parse_api_time(&self.created_at))
+    }
+}
+
+fn parse_api_time(s: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(s).ok().map(|t| t.with_timezone(&Utc))
+}
+
+
+// ─────────────────────────── Recurring Status Rendering ──────────────────────────
+
+/// "3h 12m", "2d 4h", "45m"
+fn format_duration_short(d: chrono::Duration) -> String {
+    let mins = d.num_minutes().max(0);
+    let (days, hours, mins) = (mins / 1440, mins / 60 % 24, mins % 60);
+    match (days, hours) {
+        (0, 0) => format!("{mins}m"),
+        (0, _) => format!("{hours}h {mins}m"),
+        _ => format!("{days}d {hours}h"),
+    }
+}
+
+impl RecurringOrder {
+    pub fn cycles_done(&self) -> usize {
+        self.trades.len()
+    }
+
+    /// Total cycles of a time-based order
+    pub fn total_cycles(&self) -> Option<u64> {
+        let per_cycle = ui_amount(self.in_amount_per_cycle.as_deref()?);
+        (per_cycle > 0.0).then(|| (ui_amount(&self.in_deposited) / per_cycle).round() as u64)
+    }
+
+    /// When the next cycle is due, `None` once the order is completed
+    pub fn next_execution(&self) -> Option<DateTime<Utc>> {
+        if self.is_completed() {
+            return None;
+        }
+        Some(self.last_activity()? + chrono::Duration::seconds(self.cycle_secs()? as i64))
+    }
+
+    /// Average fill price so far, input per output
+    pub fn average_price(&self) -> Option<f64> {
+        FillReport::from_order(self, None).average_price
+    }
+
+    /// One-line status, e.g. "2/12 cycles done, 0.1 SOL per cycle, next execution in 3h 12m, avg price $212.44"
+    pub fn summary(&self) -> String {
+        self.summary_with_symbols(&mint_label(&self.input_mint), &mint_label(&self.output_mint))
+    }
+
+    /// [`summary`](Self::summary) with caller-provided token symbols
+    pub fn summary_with_symbols(&self, input_symbol: &str, output_symbol: &str) -> String {
+        let mut parts = Vec::new();
+
+        parts.push(match self.total_cycles() {
+            Some(total) => format!("{}/{} cycles done", self.cycles_done(), total),
+            None => format!("{} cycles done", self.cycles_done()),
+        });
+        if let Some(per_cycle) = &self.in_amount_per_cycle {
+            parts.push(format!("{} {} per cycle", per_cycle, input_symbol));
+        }
+        match self.next_execution() {
+            Some(next) if next > Utc::now() => {
+                parts.push(format!("next execution in {}", format_duration_short(next - Utc::now())))
+            }
+            Some(_) => parts.push("next execution due".to_string()),
+            None => parts.push("completed".to_string()),
+        }
+        if let Some(avg) = self.average_price() {
+            parts.push(match input_symbol {
+                "USDC" | "USDT" => format!("avg price ${avg:.2}"),
+                _ => format!("avg price {avg:.6} {input_symbol}/{output_symbol}"),
+            });
+        }
+        parts.join(", ")
+    }
+}
+
+impl std::fmt::Display for RecurringOrder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {}→{}: {}",
+            self.order_key,
+            mint_label(&self.input_mint),
+            mint_label(&self.output_mint),
+            self.summary()
+        )
+    }
+}
+
+/// Fill summary for a single recurring order. Prices are quoted as input per output.
+#[derive(Serialize, Debug, Clone)]
+pub struct FillReport {
+    pub order_key: String,
+    pub input_mint: String,
+    pub output_mint: String,
+    pub fills: usize,
+    pub total_spent: f64,
+    pub total_received: f64,
+    pub average_price: Option<f64>,
+    pub benchmark_price: Option<f64>,
+    /// Positive when the DCA bought cheaper than the benchmark
+    pub vs_benchmark_pct: Option<f64>,
+}
+
+impl FillReport {
+    /// Build a report from an order's trades, optionally against a benchmark
+    /// price (e.g. the price when the order was created)
+    pub fn from_order(order: &RecurringOrder, benchmark_price: Option<f64>) -> Self {
+        let total_spent: f64 = order.trades.iter().map(|t| ui_amount(&t.input_amount)).sum();
+        let total_received: f64 = order.trades.iter().map(|t| ui_amount(&t.output_amount)).sum();
+        let average_price = (total_received > 0.0).then(|| total_spent / total_received);
+        let vs_benchmark_pct = match (average_price, benchmark_price) {
+            (Some(avg), Some(bench)) if bench > 0.0 => Some((bench - avg) / bench * 100.0),
+            _ => None,
+        };
+        FillReport {
+            order_key: order.order_key.clone(),
+            input_mint: order.input_mint.clone(),
+            output_mint: order.output_mint.clone(),
+            fills: order.trades.len(),
+            total_spent,
+            total_received,
+            average_price,
+            benchmark_price,
+            vs_benchmark_pct,
+        }
+    }
+}
+
+pub async fn recurring_report_flow(
+    owner: &Pubkey,
+    order_key: &str,
+    benchmark_price: Option<f64>,
+) -> Result<FillReport> {
+    let cfg  = Config::load()?;
+    let user = owner.to_string();
+
+    let recurring = JupiterClient::new(&cfg)?.recurring();
+    let mut orders = recurring.get_orders(&user, RecurringOrderStatus::Active).await?;
+    orders.extend(recurring.get_orders(&user, RecurringOrderStatus::History).await?);
+    let order = orders
+        .iter()
+        .find(|o| o.order_key == order_key)
+        .ok_or_else(|| Error::Invalid(format!("recurring order {order_key} not found for {user}")))?;
+
+    let report = FillReport::from_order(order, benchmark_price);
+    emit("recurring_report", &report, format!("Recurring fill report: {report:#?}"));
+    Ok(report)
+}
+
+
+// ─────────────────────────── Recurring Monitor ──────────────────────────
+
+/// Something that happened to one of the wallet's recurring orders
+#[derive(Serialize, Debug, Clone)]
+pub enum RecurringEvent {
+    CycleExecuted {
+        order_key: String,
+        input_mint: String,
+        output_mint: String,
+        /// UI amount of input spent
+        in_amount: f64,
+        /// UI amount of output received
+        out_amount: f64,
+        /// Input per output
+        price: Option<f64>,
+        tx_id: String,
+        confirmed_at: String,
+    },
+    /// A due cycle of a price-bounded order did not fill
+    CycleSkipped {
+        order_key: String,
+        due_at: DateTime<Utc>,
+    },
+    /// The order left the active list or has no input left
+    OrderCompleted {
+        order_key: String,
+    },
+    /// Polling the Recurring API started failing
+    Failure {
+        error: String,
+    },
+}
+
+impl std::fmt::Display for RecurringEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RecurringEvent::CycleExecuted { order_key, in_amount, out_amount, price, tx_id, .. } => {
+                write!(f, "cycle executed on {order_key}: {in_amount} in → {out_amount} out")?;
+                if let Some(p) = price {
+                    write!(f, " @ {p:.6}")?;
+                }
+                write!(f, " ({tx_id})")
+            }
+            RecurringEvent::CycleSkipped { order_key, due_at } => {
+                write!(f, "cycle due {due_at} on {order_key} skipped (price outside bounds)")
+            }
+            RecurringEvent::OrderCompleted { order_key } => write!(f, "recurring order {order_key} completed"),
+            RecurringEvent::Failure { error } => write!(f, "recurring monitor failing: {error}"),
+        }
+    }
+}
+
+/// Grace period after a cycle is due before it is reported as skipped
+const SKIP_GRACE_SECS: i64 = 300;
+
+/// Polls the wallet's active recurring orders and reports fills, skipped
+/// cycles, completions and polling failures
+pub struct RecurringMonitor {
+    client: RecurringClient,
+    user: String,
+    poll_interval: Duration,
+    notifier: Option<Arc<dyn Notifier>>,
+    cancel: CancellationToken,
+}
+
+#[derive(Default)]
+struct MonitorState {
+    seen_fills: HashSet<String>,
+    active: HashSet<String>,
+    completed: HashSet<String>,
+    /// (order, due time) pairs already reported as skipped
+    skipped: HashSet<(String, i64)>,
+    failing: bool,
+}
+
+impl RecurringMonitor {
+    pub fn new(client: RecurringClient, user: impl Into<String>, poll_interval: Duration) -> Self {
+        Self { client, user: user.into(), poll_interval, notifier: None, cancel: CancellationToken::new() }
+    }
+
+    pub fn with_notifier(mut self, notifier: Arc<dyn Notifier>) -> Self {
+        self.notifier = Some(notifier);
+        self
+    }
+
+    /// Stop polling once `token` is cancelled; events of the poll in progress are still delivered
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancel = token;
+        self
+    }
+
+    /// Start polling in the background. The task ends when the receiver is dropped or on cancellation.
+    pub fn spawn(self) -> (JoinHandle<Result<()>>, mpsc::Receiver<RecurringEvent>) {
+        let (tx, rx) = mpsc::channel(64);
+        (tokio::spawn(self.run(tx)), rx)
+    }
+
+    async fn run(self, events: mpsc::Sender<RecurringEvent>) -> Result<()> {
+        let mut state = MonitorState::default();
+        let mut first_poll = true;
+        let mut ticker = tokio::time::interval(self.poll_interval);
+
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => {}
+                _ = self.cancel.cancelled() => return Ok(()),
+            }
+            let pending = match self.client.get_orders(&self.user, RecurringOrderStatus::Active).await {
+                Ok(orders) => {
+                    state.failing = false;
+                    let finished = self.finished_orders(&state, &orders).await;
+                    let pending = Self::diff(&mut state, &orders, &finished);
+                    // fills and completions already on the books at startup are not news
+                    if std::mem::take(&mut first_poll) { Vec::new() } else { pending }
+                }
+                Err(e) if !state.failing => {
+                    state.failing = true;
+                    vec![RecurringEvent::Failure { error: format!("{e:#}") }]
+                }
+                Err(e) => {
+                    eprintln!("recurring monitor: poll failed: {e:#}");
+                    continue;
+                }
+            };
+
+            for event in pending {
+                if let Some(notifier) = &self.notifier {
+                    if let Err(e) = notifier.notify(&event.to_string()).await {
+                        eprintln!("recurring monitor: notify failed: {e:#}");
+                    }
+                }
+                if events.send(event).await.is_err() {
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    /// History entries of tracked orders that left the active list, so the fills
+    /// of a last cycle that ran between polls are still reported
+    async fn finished_orders(&self, state: &MonitorState, active: &[RecurringOrder]) -> Vec<RecurringOrder> {
+        let gone: HashSet<&String> =
+            state.active.iter().filter(|key| !active.iter().any(|o| &o.order_key == *key)).collect();
+        if gone.is_empty() {
+            return Vec::new();
+        }
+        match self.client.get_orders(&self.user, RecurringOrderStatus::History).await {
+            Ok(history) => history.into_iter().filter(|o| gone.contains(&o.order_key)).collect(),
+            Err(e) => {
+                // `diff` still reports them as completed, just without their last fills
+                eprintln!("recurring monitor: history lookup failed: {e:#}");
+                Vec::new()
+            }
+        }
+    }
+
+    /// Update `state` from a fresh listing of active orders, plus the history
+    /// entries of orders that left it, and return what changed
+    fn diff(state: &mut MonitorState, orders: &[RecurringOrder], finished: &[RecurringOrder]) -> Vec<RecurringEvent> {
+        let mut events = Vec::new();
+        let now = Utc::now();
+
+        let listed = orders.iter().map(|o| (o, false)).chain(finished.iter().map(|o| (o, true)));
+        for (order, gone) in listed {
+            for trade in &order.trades {
+                if !state.seen_fills.insert(trade.tx_id.clone()) {
+                    continue;
+                }
+                let in_amount  = ui_amount(&trade.input_amount);
+                let out_amount = ui_amount(&trade.output_amount);
+                events.push(RecurringEvent::CycleExecuted {
+                    order_key: order.order_key.clone(),
+                    input_mint: trade.input_mint.clone(),
+                    output_mint: trade.output_mint.clone(),
+                    in_amount,
+                    out_amount,
+                    price: (out_amount > 0.0).then(|| in_amount / out_amount),
+                    tx_id: trade.tx_id.clone(),
+                    confirmed_at: trade.confirmed_at.clone(),
+                });
+            }
+
+            if gone || order.is_completed() {
+                if state.completed.insert(order.order_key.clone()) {
+                    events.push(RecurringEvent::OrderCompleted { order_key: order.order_key.clone() });
+                }
+                continue;
+            }
+
+            if order.has_price_bounds() {
+                if let (Some(last), Some(secs)) = (order.last_activity(), order.cycle_secs()) {
+                    let due_at = last + chrono::Duration::seconds(secs as i64);
+                    let overdue = now > due_at + chrono::Duration::seconds(SKIP_GRACE_SECS);
+                    if overdue && state.skipped.insert((order.order_key.clone(), due_at.timestamp())) {
+                        events.push(RecurringEvent::CycleSkipped { order_key: order.order_key.clone(), due_at });
+                    }
+                }
+            }
+        }
+
+        // orders that dropped off the active list have finished (or were cancelled),
+        // whether or not the history lookup found them
+        let active: HashSet<String> = orders.iter().map(|o| o.order_key.clone()).collect();
+        for gone in state.active.difference(&active) {
+            if state.completed.insert(gone.clone()) {
+                events.push(RecurringEvent::OrderCompleted { order_key: gone.clone() });
+            }
+        }
+        state.active = active;
+
+        events
+    }
+}
+
+pub async fn recurring_monitor_flow(owner: &Pubkey, poll_interval: Duration, cancel: CancellationToken) -> Result<()> {
+    let cfg  = Config::load()?;
+    let user = owner.to_string();
+
+    let monitor = RecurringMonitor::new(JupiterClient::new(&cfg)?.recurring(), user, poll_interval)
+        .with_notifier(notifier_from_env()?)
+        .with_cancellation(cancel);
+    let (handle, mut events) = monitor.spawn();
+    while let Some(event) = events.recv().await {
+        emit("recurring_event", &event, format!("Recurring event: {event:#?}"));
+    }
+    handle.await.map_err(anyhow::Error::from)?
+}
+
+
+// ─────────────────────────── Recurring Close & Claim ──────────────────────────
+
+/// Outcome of sweeping one completed recurring order
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct ClaimResult {
+    pub order_key: String,
+    /// UI amount of output tokens claimed
+    pub claimed_out: f64,
+    pub withdraw_signature: Option<String>,
+    pub close_signature: Option<String>,
+    pub error: Option<String>,
+}
+
+/// Withdraw unclaimed output from every completed active order of `kp`, then close it.
+/// Failures are recorded per order so one bad order doesn't stop the sweep.
+pub async fn close_and_claim(client: &RecurringClient, kp: &dyn TxSigner) -> Result<Vec<ClaimResult>> {
+    let user = kp.address().to_string();
+    let orders = client.get_orders(&user, RecurringOrderStatus::Active).await?;
+
+    let mut results = Vec::new();
+    for order in orders.iter().filter(|o| o.is_completed()) {
+        let mut result = ClaimResult { order_key: order.order_key.clone(), ..Default::default() };
+        if let Err(e) = claim_one(client, kp, &user, order, &mut result).await {
+            result.error = Some(format!("{e:#}"));
+        }
+        results.push(result);
+    }
+    Ok(results)
+}
+
+async fn claim_one(
+    client: &RecurringClient,
+    kp: &dyn TxSigner,
+    user: &str,
+    order: &RecurringOrder,
+    result: &mut ClaimResult,
+) -> Result<()> {
+    let recurring_type = order.recurring_type();
+    let unclaimed = order.unclaimed_out();
+
+    // price-based orders keep their output until withdrawn; closing a
+    // time-based order returns everything it still holds
+    if recurring_type == RecurringType::Price && unclaimed > DUST {
+        let params = WithdrawParams { order: order.order_key.clone(), side: WithdrawSide::Output, amount: None };
+        let tx = client.price_withdraw(user, &params).await?;
+        result.withdraw_signature = Some(client.sign_and_execute(kp, &tx).await?.signature);
+    }
+    result.claimed_out = unclaimed.max(0.0);
+
+    let tx = client.cancel_order(user, &order.order_key, recurring_type).await?;
+    result.close_signature = Some(client.sign_and_execute(kp, &tx).await?.signature);
+    Ok(())
+}
+
+pub async fn close_and_claim_flow(kp: &dyn TxSigner) -> Result<Vec<ClaimResult>> {
+    let cfg = Config::load()?;
+    mainnet_guard(&rpc_client(&cfg)).await?;
+
+    let results = close_and_claim(&JupiterClient::new(&cfg)?.recurring(), kp).await?;
+    emit("recurring_close_and_claim", &results, format!("Recurring close & claim: {results:#?}"));
+    Ok(results)
+}
+
+
+// ─────────────────────────── Recurring Batch Creation ──────────────────────────
+
+/// Outcome of one order in a batch
+#[derive(Serialize, Debug, Clone)]
+pub struct BatchOrderResult {
+    pub input_mint: String,
+    pub output_mint: String,
+    pub order: Option<String>,
+    pub signature: Option<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct BatchReport {
+    pub succeeded: usize,
+    pub failed: usize,
+    /// One entry per requested order, in request order
+    pub results: Vec<BatchOrderResult>,
+}
+
+/// Create and execute every order in `basket`, at most `max_concurrency` at a time.
+/// A failing order is reported in the result list and does not abort the others.
+pub async fn create_recurring_batch(
+    client: &RecurringClient,
+    kp: &dyn TxSigner,
+    basket: Vec<RecurringOrderParams>,
+    max_concurrency: usize,
+) -> BatchReport {
+    let user = kp.address().to_string();
+    let payer = kp.fee_payer().map(|p| p.address().to_string());
+
+    let results: Vec<BatchOrderResult> = stream::iter(basket)
+        .map(|params| {
+            let (user, payer) = (&user, payer.as_deref());
+            async move {
+                let outcome = async {
+                    let tx = client.create_order(user, payer, &params).await?;
+                    safety::pre_trade_check(&params.output_mint).await?;
+                    let exec = client.sign_and_execute(kp, &tx).await?;
+                    Ok::<_, Error>((exec.order.or(tx.order), exec.signature))
+                }
+                .await;
+                let (order, signature, error) = match outcome {
+                    Ok((order, signature)) => (order, Some(signature), None),
+                    Err(e) => (None, None, Some(format!("{e:#}"))),
+                };
+                BatchOrderResult {
+                    input_mint: params.input_mint,
+                    output_mint: params.output_mint,
+                    order,
+                    signature,
+                    error,
+                }
+            }
+        })
+        .buffered(max_concurrency.max(1))
+        .collect()
+        .await;
+
+    let failed = results.iter().filter(|r| r.error.is_some()).count();
+    BatchReport { succeeded: results.len() - failed, failed, results }
+}
+
+pub async fn recurring_batch_flow(
+    kp: &dyn TxSigner,
+    basket: Vec<RecurringOrderParams>,
+    max_concurrency: usize,
+) -> Result<BatchReport> {
+    let cfg = Config::load()?;
+    mainnet_guard(&rpc_client(&cfg)).await?;
+
+    let report = create_recurring_batch(&JupiterClient::new(&cfg)?.recurring(), kp, basket, max_concurrency).await;
+    emit("recurring_batch", &report, format!("Recurring batch: {report:#?}"));
+    Ok(report)
+}
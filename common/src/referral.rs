@@ -0,0 +1,388 @@
+// common/src/referral.rs
+use crate::{
+    ata_address, emit, latest_blockhash, mainnet_guard, rpc_client, send_and_confirm, tx_link, Config, Error, Result,
+    TxSigner,
+};
+use serde_json::json;
+use solana_account_decoder::UiAccountData;
+use solana_client::{nonblocking::rpc_client::RpcClient, rpc_request::TokenAccountsFilter};
+use solana_sdk::{
+    hash::hash,
+    instruction::{AccountMeta, Instruction},
+    message::Message,
+    pubkey,
+    pubkey::Pubkey,
+    signature::Signature,
+    system_program,
+    transaction::Transaction,
+};
+use std::str::FromStr;
+
+// ─────────────────── Program ids ───────────────────
+//
+// Here rather than in the modules that decode these programs, which only exist
+// with their product's feature, so inspect and the token helpers always have them.
+
+pub const REFERRAL_PROGRAM_ID: Pubkey = pubkey!("REFER4ZgmyYx9c6He5XfaTMiGfdLwRnkV4RPp9t9iF3");
+pub const TOKEN_PROGRAM_ID: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+pub const TOKEN_2022_PROGRAM_ID: Pubkey = pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+pub const JUPITER_AGGREGATOR_V6: Pubkey = pubkey!("JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4");
+/// Program behind time-based Recurring orders
+pub const RECURRING_PROGRAM_ID: Pubkey = pubkey!("DCA265Vj8a9CEuX1eb1LWRnDT7uK6q1xMipnNyatn23M");
+/// Program behind Trigger (limit) orders
+pub const TRIGGER_PROGRAM_ID: Pubkey = pubkey!("j1o2qRpjcyUwEvwtcfhEQefh773ZgjxcVRry7LDqg5X");
+
+// ─────────────────── Referral Program ───────────────────
+
+/// Referral project used by the Ultra API (and accepted by swap/trigger platform fees)
+pub const JUPITER_ULTRA_PROJECT: Pubkey = pubkey!("DkiqsTrw1u1bYFumumC7sCG2S8K25qc2vemJFHyW2wJc");
+
+/// Anchor instruction discriminator: first 8 bytes of sha256("global:<name>")
+pub(crate) fn ix_discriminator(name: &str) -> Vec<u8> {
+    hash(format!("global:{name}").as_bytes()).to_bytes()[..8].to_vec()
+}
+
+/// Named referral account PDA of `project`
+pub fn referral_account_address(project: &Pubkey, name: &str) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"referral", project.as_ref(), name.as_bytes()],
+        &REFERRAL_PROGRAM_ID,
+    )
+    .0
+}
+
+/// `initialize_referral_account_with_name`: creates the referral account PDA
+/// for `partner` (the wallet that will receive fees)
+pub fn initialize_referral_account_ix(
+    payer: &Pubkey,
+    partner: &Pubkey,
+    project: &Pubkey,
+    name: &str,
+) -> Instruction {
+    let referral_account = referral_account_address(project, name);
+
+    // borsh: discriminator | u32 len | utf8 name
+    let mut data = ix_discriminator("initialize_referral_account_with_name");
+    data.extend_from_slice(&(name.len() as u32).to_le_bytes());
+    data.extend_from_slice(name.as_bytes());
+
+    Instruction {
+        program_id: REFERRAL_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(*partner, false),
+            AccountMeta::new_readonly(*project, false),
+            AccountMeta::new(referral_account, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data,
+    }
+}
+
+/// Sign `ixs` with `kp` as fee payer and send them in one legacy transaction
+pub(crate) async fn send_instructions(rpc: &RpcClient, kp: &dyn TxSigner, ixs: &[Instruction]) -> Result<Signature> {
+    mainnet_guard(rpc).await?;
+    let blockhash = latest_blockhash(rpc, rpc.commitment()).await?;
+    let message = Message::new_with_blockhash(ixs, Some(&kp.address()), &blockhash);
+    let signature = kp.sign(&message.serialize()).await?;
+    let tx = Transaction { signatures: vec![signature], message };
+    Ok(send_and_confirm(rpc, &tx, rpc.commitment()).await?)
+}
+
+/// Create the named referral account for `kp` under `project` unless it
+/// already exists. Returns the account and the signature if one was sent.
+pub async fn create_referral_account(
+    rpc: &RpcClient,
+    kp: &dyn TxSigner,
+    project: &Pubkey,
+    name: &str,
+) -> Result<(Pubkey, Option<Signature>)> {
+    let referral_account = referral_account_address(project, name);
+    if rpc.get_account(&referral_account).await.is_ok() {
+        return Ok((referral_account, None));
+    }
+    let ix = initialize_referral_account_ix(&kp.address(), &kp.address(), project, name);
+    let signature = send_instructions(rpc, kp, &[ix]).await?;
+    Ok((referral_account, Some(signature)))
+}
+
+pub async fn referral_account_flow(kp: &dyn TxSigner, name: &str, project: Option<Pubkey>) -> Result<Pubkey> {
+    let cfg = Config::load()?;
+    let rpc = rpc_client(&cfg);
+
+    let project = project.unwrap_or(JUPITER_ULTRA_PROJECT);
+    let (referral_account, signature) = create_referral_account(&rpc, kp, &project, name).await?;
+    let text = match signature {
+        Some(sig) => format!("Referral account {referral_account} created: {}", tx_link(sig)),
+        None => format!("Referral account {referral_account} already exists"),
+    };
+    let data = json!({ "address": referral_account.to_string(), "signature": signature.map(|s| s.to_string()) });
+    emit("referral_account", &data, text);
+    Ok(referral_account)
+}
+
+
+// ─────────────────── Referral token accounts ───────────────────
+
+/// Fee-collecting token account PDA of `referral_account` for `mint`
+pub fn referral_token_account_address(referral_account: &Pubkey, mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"referral_ata", referral_account.as_ref(), mint.as_ref()],
+        &REFERRAL_PROGRAM_ID,
+    )
+    .0
+}
+
+/// `initialize_referral_token_account` for `mint`, owned by the token program
+/// that owns the mint (SPL Token or Token-2022)
+pub fn initialize_referral_token_account_ix(
+    payer: &Pubkey,
+    project: &Pubkey,
+    referral_account: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: REFERRAL_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(*project, false),
+            AccountMeta::new_readonly(*referral_account, false),
+            AccountMeta::new(referral_token_account_address(referral_account, mint), false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(*token_program, false),
+        ],
+        data: ix_discriminator("initialize_referral_token_account"),
+    }
+}
+
+/// Project a referral account belongs to (stored after the partner key)
+pub async fn referral_account_project(rpc: &RpcClient, referral_account: &Pubkey) -> Result<Pubkey> {
+    let data = rpc.get_account_data(referral_account).await?;
+    let project = data
+        .get(8 + 32..8 + 64)
+        .ok_or_else(|| Error::Deserialize(format!("{referral_account} is not a referral account")))?;
+    Ok(Pubkey::try_from(project)?)
+}
+
+/// State of one fee mint after [`ensure_referral_token_accounts`]
+#[derive(Debug, Clone)]
+pub struct ReferralTokenAccount {
+    pub mint: Pubkey,
+    pub address: Pubkey,
+    /// Set when the account had to be created
+    pub signature: Option<Signature>,
+}
+
+/// Create the referral token account for every mint in `mints` that doesn't have one yet
+pub async fn ensure_referral_token_accounts(
+    rpc: &RpcClient,
+    kp: &dyn TxSigner,
+    referral_account: &Pubkey,
+    mints: &[Pubkey],
+) -> Result<Vec<ReferralTokenAccount>> {
+    let project = referral_account_project(rpc, referral_account).await?;
+
+    let mut accounts = Vec::new();
+    for mint in mints {
+        let address = referral_token_account_address(referral_account, mint);
+        let signature = if referral_token_account_exists(rpc, referral_account, mint).await {
+            None
+        } else {
+            let token_program = rpc.get_account(mint).await?.owner;
+            let ix = initialize_referral_token_account_ix(&kp.address(), &project, referral_account, mint, &token_program);
+            Some(send_instructions(rpc, kp, &[ix]).await?)
+        };
+        accounts.push(ReferralTokenAccount { mint: *mint, address, signature });
+    }
+    Ok(accounts)
+}
+
+/// Whether fees in `mint` have somewhere to accrue for `referral_account`
+pub async fn referral_token_account_exists(rpc: &RpcClient, referral_account: &Pubkey, mint: &Pubkey) -> bool {
+    rpc.get_account(&referral_token_account_address(referral_account, mint)).await.is_ok()
+}
+
+pub async fn referral_token_accounts_flow(
+    kp: &dyn TxSigner,
+    referral_account: &Pubkey,
+    mints: &[Pubkey],
+) -> Result<Vec<ReferralTokenAccount>> {
+    let cfg = Config::load()?;
+    let rpc = rpc_client(&cfg);
+
+    let accounts = ensure_referral_token_accounts(&rpc, kp, referral_account, mints).await?;
+    let text = accounts
+        .iter()
+        .map(|acc| match acc.signature {
+            Some(sig) => format!("{} → {} created: {}", acc.mint, acc.address, tx_link(sig)),
+            None => format!("{} → {} already exists", acc.mint, acc.address),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let data: Vec<_> = accounts
+        .iter()
+        .map(|acc| {
+            json!({
+                "mint": acc.mint.to_string(),
+                "address": acc.address.to_string(),
+                "signature": acc.signature.map(|s| s.to_string()),
+            })
+        })
+        .collect();
+    emit("referral_token_accounts", &data, text);
+    Ok(accounts)
+}
+
+
+// ─────────────────── Claim referral fees ───────────────────
+
+/// Claims per transaction; each claim may create up to two ATAs
+const CLAIMS_PER_TX: usize = 3;
+
+/// A referral token account holding unclaimed fees
+#[derive(Debug, Clone)]
+pub struct ReferralBalance {
+    pub address: Pubkey,
+    pub mint: Pubkey,
+    pub token_program: Pubkey,
+    /// Raw amount
+    pub amount: u64,
+    pub ui_amount: String,
+}
+
+/// Every token account owned by `referral_account` with a non-zero balance
+pub async fn referral_balances(rpc: &RpcClient, referral_account: &Pubkey) -> Result<Vec<ReferralBalance>> {
+    let mut balances = Vec::new();
+    for token_program in [TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID] {
+        let accounts = rpc.get_token_accounts_by_owner(referral_account, TokenAccountsFilter::ProgramId(token_program)).await?;
+        for keyed in accounts {
+            let UiAccountData::Json(parsed) = keyed.account.data else { continue };
+            let info = &parsed.parsed["info"];
+            let amount: u64 = info["tokenAmount"]["amount"].as_str().unwrap_or("0").parse()?;
+            if amount == 0 {
+                continue;
+            }
+            balances.push(ReferralBalance {
+                address: Pubkey::from_str(&keyed.pubkey)?,
+                mint: Pubkey::from_str(info["mint"].as_str().unwrap_or_default())?,
+                token_program,
+                amount,
+                ui_amount: info["tokenAmount"]["uiAmountString"].as_str().unwrap_or("0").to_string(),
+            });
+        }
+    }
+    Ok(balances)
+}
+
+async fn read_pubkey_at(rpc: &RpcClient, account: &Pubkey, offset: usize) -> Result<Pubkey> {
+    let data = rpc.get_account_data(account).await?;
+    let bytes = data
+        .get(offset..offset + 32)
+        .ok_or_else(|| Error::Deserialize(format!("{account}: account data too short")))?;
+    Ok(Pubkey::try_from(bytes)?)
+}
+
+/// `claim`: pays the partner's share of `balance` to its ATA and the
+/// project's share to the project admin
+pub fn claim_ix(
+    payer: &Pubkey,
+    project: &Pubkey,
+    admin: &Pubkey,
+    referral_account: &Pubkey,
+    partner: &Pubkey,
+    balance: &ReferralBalance,
+) -> Instruction {
+    let ata = |owner: &Pubkey| ata_address(owner, &balance.mint, &balance.token_program);
+    Instruction {
+        program_id: REFERRAL_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(*project, false),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new(ata(admin), false),
+            AccountMeta::new_readonly(*referral_account, false),
+            AccountMeta::new(balance.address, false),
+            AccountMeta::new_readonly(*partner, false),
+            AccountMeta::new(ata(partner), false),
+            AccountMeta::new_readonly(balance.mint, false),
+            AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(balance.token_program, false),
+        ],
+        data: ix_discriminator("claim"),
+    }
+}
+
+/// Amount swept from one referral token account
+#[derive(Debug, Clone)]
+pub struct ClaimedFee {
+    pub mint: Pubkey,
+    /// Raw amount claimed (partner and project shares combined)
+    pub amount: u64,
+    pub ui_amount: String,
+    pub signature: Signature,
+}
+
+/// Claim every non-empty referral token account of `referral_account`,
+/// batching [`CLAIMS_PER_TX`] claims per transaction
+pub async fn claim_referral_fees(
+    rpc: &RpcClient,
+    kp: &dyn TxSigner,
+    referral_account: &Pubkey,
+) -> Result<Vec<ClaimedFee>> {
+    // referral account: disc | partner | project ; project: disc | base | admin
+    let partner = read_pubkey_at(rpc, referral_account, 8).await?;
+    let project = read_pubkey_at(rpc, referral_account, 8 + 32).await?;
+    let admin   = read_pubkey_at(rpc, &project, 8 + 32).await?;
+
+    let balances = referral_balances(rpc, referral_account).await?;
+    let mut claimed = Vec::new();
+    for batch in balances.chunks(CLAIMS_PER_TX) {
+        let ixs: Vec<Instruction> = batch
+            .iter()
+            .map(|b| claim_ix(&kp.address(), &project, &admin, referral_account, &partner, b))
+            .collect();
+        let signature = send_instructions(rpc, kp, &ixs).await?;
+        claimed.extend(batch.iter().map(|b| ClaimedFee {
+            mint: b.mint,
+            amount: b.amount,
+            ui_amount: b.ui_amount.clone(),
+            signature,
+        }));
+    }
+    Ok(claimed)
+}
+
+pub async fn claim_referral_fees_flow(kp: &dyn TxSigner, referral_account: &Pubkey) -> Result<Vec<ClaimedFee>> {
+    let cfg = Config::load()?;
+    let rpc = rpc_client(&cfg);
+
+    let claimed = claim_referral_fees(&rpc, kp, referral_account).await?;
+    let text = if claimed.is_empty() {
+        format!("Nothing to claim for {referral_account}")
+    } else {
+        claimed
+            .iter()
+            .map(|c| {
+                let mint = crate::mint_label(&c.mint.to_string());
+                format!("claimed {} of {mint} ({})", c.ui_amount, tx_link(c.signature))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    let data: Vec<_> = claimed
+        .iter()
+        .map(|c| {
+            json!({
+                "mint": c.mint.to_string(),
+                "amount": c.amount,
+                "uiAmount": c.ui_amount,
+                "signature": c.signature.to_string(),
+            })
+        })
+        .collect();
+    emit("referral_claims", &data, text);
+    Ok(claimed)
+}
@@ -0,0 +1,90 @@
+// common/src/remote_signer.rs
+use crate::{setting, ConfigError, Error, Result, TxSigner};
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use solana_sdk::{pubkey::Pubkey, signature::Signature};
+use std::str::FromStr;
+use std::time::Duration;
+
+// ─────────────────── Remote signer ───────────────────
+//
+// Skeleton for custodial setups (AWS KMS, Turnkey, Fireblocks, an in-house
+// HSM service): the key never leaves the service, which is sent the
+// serialized message and answers with the signature. Put a small adapter
+// speaking the protocol below in front of your provider's API.
+
+/// Custody services may wait on a policy engine or a human approval
+const REMOTE_SIGN_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// `POST {url}/sign` body
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoteSignRequest {
+    pub pubkey: String,
+    /// Serialized transaction message, base64
+    pub message: String,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoteSignResponse {
+    /// base58
+    pub signature: String,
+}
+
+/// [`TxSigner`] that asks an HTTP signing service for each signature
+#[derive(Debug, Clone)]
+pub struct RemoteSigner {
+    http: Client,
+    url: String,
+    pubkey: Pubkey,
+    token: Option<String>,
+}
+
+impl RemoteSigner {
+    /// `url` is the service root; `token`, when given, is sent as a bearer token
+    pub fn new(url: impl Into<String>, pubkey: Pubkey, token: Option<String>) -> Self {
+        let http = Client::builder().timeout(REMOTE_SIGN_TIMEOUT).build().unwrap();
+        RemoteSigner { http, url: url.into().trim_end_matches('/').to_string(), pubkey, token }
+    }
+
+    /// `REMOTE_SIGNER_URL`, `REMOTE_SIGNER_PUBKEY` and optional `REMOTE_SIGNER_TOKEN`
+    pub fn from_env() -> Result<Self, ConfigError> {
+        let url = setting("REMOTE_SIGNER_URL", &None).ok_or(ConfigError::Missing("REMOTE_SIGNER_URL"))?;
+        let pubkey = setting("REMOTE_SIGNER_PUBKEY", &None).ok_or(ConfigError::Missing("REMOTE_SIGNER_PUBKEY"))?;
+        let pubkey = Pubkey::from_str(pubkey.trim())
+            .map_err(|e| ConfigError::Invalid { var: "REMOTE_SIGNER_PUBKEY", reason: e.to_string() })?;
+        Ok(Self::new(url, pubkey, setting("REMOTE_SIGNER_TOKEN", &None)))
+    }
+}
+
+#[async_trait]
+impl TxSigner for RemoteSigner {
+    fn address(&self) -> Pubkey {
+        self.pubkey
+    }
+
+    async fn sign(&self, message: &[u8]) -> Result<Signature> {
+        let body = RemoteSignRequest { pubkey: self.pubkey.to_string(), message: base64::encode(message) };
+        let mut req = self.http.post(format!("{}/sign", self.url)).json(&body);
+        if let Some(token) = &self.token {
+            req = req.bearer_auth(token);
+        }
+        let resp = req.send().await.map_err(|e| Error::Sign(format!("remote signer: {e}")))?;
+        let status = resp.status();
+        if !status.is_success() {
+            let text = resp.text().await.unwrap_or_default();
+            return Err(Error::Sign(format!("remote signer returned {status}: {}", text.trim())));
+        }
+        let resp: RemoteSignResponse =
+            resp.json().await.map_err(|e| Error::Deserialize(format!("remote signer response: {e}")))?;
+        let signature = Signature::from_str(&resp.signature)
+            .map_err(|e| Error::Deserialize(format!("remote signer signature: {e}")))?;
+        // a misconfigured service signing with another key would only fail at the RPC
+        if !signature.verify(self.pubkey.as_ref(), message) {
+            return Err(Error::Sign(format!("remote signer returned a signature that isn't from {}", self.pubkey)));
+        }
+        Ok(signature)
+    }
+}
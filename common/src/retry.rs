@@ -0,0 +1,97 @@
+// common/src/retry.rs
+use crate::metrics::record_retry;
+use crate::rate_limit::throttle;
+use crate::{file_config, request_api};
+use dotenv::dotenv;
+use reqwest::{RequestBuilder, Response, StatusCode};
+use std::env;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// ─────────────────── HTTP retries ───────────────────
+
+/// How Jupiter API calls are retried on 429/502/503/504, timeouts and
+/// connection failures
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Total tries including the first; 1 disables retries
+    pub max_attempts: u32,
+    /// Backoff ceiling for the first retry, doubled on each one after
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self { max_attempts: 4, base_delay: Duration::from_millis(500), max_delay: Duration::from_secs(10) }
+    }
+}
+
+impl RetryPolicy {
+    /// `JUPITER_RETRY_ATTEMPTS`, else `retry_attempts` in jupiter.toml, else 4 attempts
+    pub fn from_env() -> Self {
+        dotenv().ok();
+        let max_attempts = env::var("JUPITER_RETRY_ATTEMPTS")
+            .ok()
+            .and_then(|s| s.trim().parse().ok())
+            .or(file_config().retry_attempts)
+            .unwrap_or(RetryPolicy::default().max_attempts);
+        Self { max_attempts: max_attempts.max(1), ..Self::default() }
+    }
+
+    /// Jittered exponential backoff: between half and all of `base * 2^(attempt-1)`, capped
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let ceiling = self.base_delay.saturating_mul(1u32 << (attempt - 1).min(16)).min(self.max_delay);
+        ceiling.mul_f64(jitter())
+    }
+
+    fn should_retry(status: StatusCode) -> bool {
+        matches!(
+            status,
+            StatusCode::TOO_MANY_REQUESTS
+                | StatusCode::BAD_GATEWAY
+                | StatusCode::SERVICE_UNAVAILABLE
+                | StatusCode::GATEWAY_TIMEOUT
+        )
+    }
+
+    /// Send `req`, retrying per this policy. Requests whose body can't be
+    /// cloned (streams) are sent once.
+    pub async fn send(&self, req: RequestBuilder) -> reqwest::Result<Response> {
+        let mut attempt = 1;
+        loop {
+            throttle(&req).await;
+            let Some(retry) = req.try_clone().filter(|_| attempt < self.max_attempts) else {
+                return req.send().await;
+            };
+            let wait = match retry.send().await {
+                Ok(resp) if Self::should_retry(resp.status()) => {
+                    retry_after(&resp).unwrap_or_else(|| self.backoff(attempt)).min(self.max_delay)
+                }
+                Err(e) if e.is_timeout() || e.is_connect() => self.backoff(attempt),
+                done => return done,
+            };
+            record_retry(request_api(&req));
+            tokio::time::sleep(wait).await;
+            attempt += 1;
+        }
+    }
+}
+
+/// `Retry-After` in seconds, when the API sends one
+pub(crate) fn retry_after(resp: &Response) -> Option<Duration> {
+    resp.headers()
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .parse::<u64>()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+/// A number in [0.5, 1.0] from the clock's sub-second nanos; enough to
+/// de-synchronise concurrent retries without pulling in a RNG crate
+fn jitter() -> f64 {
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.subsec_nanos()).unwrap_or(0);
+    let mixed = nanos.wrapping_mul(2_654_435_761) as f64 / u32::MAX as f64;
+    0.5 + mixed / 2.0
+}
@@ -0,0 +1,177 @@
+// common/src/routes.rs
+use crate::{emit, get_quote, mint_label, progress, try_http_client, Config, QuoteResponse, Result, SwapParams};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashSet};
+use std::time::Duration;
+
+// ─────────────────── Route / DEX usage analytics ───────────────────
+
+/// What one quote (or executed swap) routed through
+#[derive(Serialize, Debug, Clone)]
+pub struct RouteSample {
+    pub at: DateTime<Utc>,
+    pub input_mint: String,
+    pub output_mint: String,
+    /// Sequential hops (distinct intermediate mints + 1)
+    pub hops: usize,
+    /// More than one venue on the same hop
+    pub split: bool,
+    /// DEX label → percent of the route's input
+    pub venues: Vec<(String, u8)>,
+    pub executed: bool,
+    /// `maxAccounts` the quote was requested with, if any
+    pub max_accounts: Option<u32>,
+}
+
+impl RouteSample {
+    pub fn from_quote(quote: &QuoteResponse, executed: bool, max_accounts: Option<u32>) -> Self {
+        let intermediates: HashSet<&str> = quote
+            .routePlan
+            .iter()
+            .map(|s| s.swap_info.output_mint.as_str())
+            .filter(|m| *m != quote.outputMint)
+            .collect();
+        let hops = intermediates.len() + 1;
+        RouteSample {
+            at: Utc::now(),
+            input_mint: quote.inputMint.clone(),
+            output_mint: quote.outputMint.clone(),
+            hops,
+            split: quote.routePlan.len() > hops,
+            venues: quote.routePlan.iter().map(|s| (s.swap_info.label.clone(), s.percent)).collect(),
+            executed,
+            max_accounts,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct VenueStats {
+    /// Routes that used the venue at least once
+    pub routes: usize,
+    pub executed: usize,
+    /// Sum of `percent` over every leg through the venue
+    pub percent_total: u64,
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct RouteReport {
+    pub samples: usize,
+    pub executed: usize,
+    pub split_routes: usize,
+    /// hop count → routes
+    pub hops: BTreeMap<usize, usize>,
+    pub venues: BTreeMap<String, VenueStats>,
+    /// maxAccounts → average hops; hops collapsing to 1 at low limits means the limit is binding
+    pub avg_hops_by_max_accounts: BTreeMap<u32, f64>,
+}
+
+impl RouteReport {
+    /// Venues sorted by how many routes used them, most first
+    pub fn top_venues(&self) -> Vec<(&str, &VenueStats)> {
+        let mut v: Vec<_> = self.venues.iter().map(|(k, s)| (k.as_str(), s)).collect();
+        v.sort_by(|a, b| b.1.routes.cmp(&a.1.routes));
+        v
+    }
+}
+
+/// Collects [`RouteSample`]s over time and aggregates them on demand
+#[derive(Debug, Default)]
+pub struct RouteAnalyzer {
+    samples: Vec<RouteSample>,
+}
+
+impl RouteAnalyzer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&mut self, quote: &QuoteResponse, executed: bool) {
+        self.samples.push(RouteSample::from_quote(quote, executed, None));
+    }
+
+    pub fn record_sample(&mut self, sample: RouteSample) {
+        self.samples.push(sample);
+    }
+
+    pub fn samples(&self) -> &[RouteSample] {
+        &self.samples
+    }
+
+    /// Aggregate every sample taken at or after `since` (all samples when `None`)
+    pub fn report(&self, since: Option<DateTime<Utc>>) -> RouteReport {
+        let mut report = RouteReport::default();
+        let mut hops_by_limit: BTreeMap<u32, (usize, usize)> = BTreeMap::new();
+
+        for s in self.samples.iter().filter(|s| since.map_or(true, |t| s.at >= t)) {
+            report.samples += 1;
+            report.executed += s.executed as usize;
+            report.split_routes += s.split as usize;
+            *report.hops.entry(s.hops).or_default() += 1;
+
+            let mut seen = HashSet::new();
+            for (label, percent) in &s.venues {
+                let stats = report.venues.entry(label.clone()).or_default();
+                stats.percent_total += *percent as u64;
+                if seen.insert(label) {
+                    stats.routes += 1;
+                    stats.executed += s.executed as usize;
+                }
+            }
+            if let Some(limit) = s.max_accounts {
+                let e = hops_by_limit.entry(limit).or_default();
+                e.0 += s.hops;
+                e.1 += 1;
+            }
+        }
+        report.avg_hops_by_max_accounts = hops_by_limit
+            .into_iter()
+            .map(|(limit, (hops, n))| (limit, hops as f64 / n as f64))
+            .collect();
+        report
+    }
+}
+
+impl std::fmt::Display for RouteReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{} routes ({} executed, {} split)", self.samples, self.executed, self.split_routes)?;
+        for (hops, n) in &self.hops {
+            writeln!(f, "  {hops} hop(s): {n}")?;
+        }
+        for (label, s) in self.top_venues() {
+            writeln!(f, "  {label:<24} {:>5} routes {:>5} executed", s.routes, s.executed)?;
+        }
+        for (limit, hops) in &self.avg_hops_by_max_accounts {
+            writeln!(f, "  maxAccounts {limit}: {hops:.2} avg hops")?;
+        }
+        Ok(())
+    }
+}
+
+/// Quote each pair every `interval` for `rounds` rounds and print the aggregated route usage
+pub async fn route_stats_flow(pairs: Vec<SwapParams>, rounds: u32, interval: Duration) -> Result<RouteReport> {
+    Config::load()?;
+    let http = try_http_client()?;
+    let mut analyzer = RouteAnalyzer::new();
+
+    let mut ticker = tokio::time::interval(interval);
+    for round in 1..=rounds {
+        ticker.tick().await;
+        for p in &pairs {
+            match get_quote(&http, p).await {
+                Ok(q) => analyzer.record(&q, false),
+                Err(e) => eprintln!(
+                    "quote {} → {} failed: {e}",
+                    mint_label(&p.input_mint),
+                    mint_label(&p.output_mint)
+                ),
+            }
+        }
+        progress(format!("round {round}/{rounds}: {} samples", analyzer.samples().len()));
+    }
+
+    let report = analyzer.report(None);
+    emit("route_report", &report, &report);
+    Ok(report)
+}
@@ -0,0 +1,270 @@
+// common/src/rpc.rs
+use crate::metrics::{record_confirmation, record_sent, SentVia};
+use crate::{dry_run, emit, file_config, setting, vcr_mode, Config, ConfigError, Error, Result, VcrMode, VcrSender};
+use futures::StreamExt;
+use serde_json::json;
+use solana_client::client_error::ClientError;
+use solana_client::nonblocking::pubsub_client::PubsubClient;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_client::SerializableTransaction;
+use solana_client::rpc_config::{RpcSendTransactionConfig, RpcSignatureSubscribeConfig, RpcSimulateTransactionConfig};
+use solana_client::rpc_response::RpcSignatureResult;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::hash::Hash;
+use solana_sdk::signature::Signature;
+use solana_sdk::transaction::{TransactionResult, VersionedTransaction};
+use solana_rpc_client::rpc_client::RpcClientConfig;
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+use tokio::sync::oneshot;
+
+// ─────────────────── RPC ───────────────────
+
+/// Client at the configured `COMMITMENT`, used for every call that doesn't pass its own.
+///
+/// RPC traffic goes through HTTPS_PROXY / HTTP_PROXY when set (NO_PROXY exempts hosts);
+/// `JUPITER_PROXY` only covers the Jupiter API
+pub fn rpc_client(cfg: &Config) -> RpcClient {
+    match vcr_mode() {
+        VcrMode::Off => RpcClient::new_with_commitment(cfg.rpc_url.clone(), cfg.commitment),
+        mode => RpcClient::new_sender(
+            VcrSender::new(cfg.rpc_url.clone(), mode),
+            RpcClientConfig::with_commitment(cfg.commitment),
+        ),
+    }
+}
+
+/// Genesis hash of mainnet-beta. A cluster is identified by this, not by its URL.
+pub const MAINNET_GENESIS_HASH: &str = "5eykt4UsFv8P8NJdTREpY1vzqKqZKvdpKuc147dw2N9d";
+
+/// Whether `rpc` serves mainnet, by its genesis hash; asked once per endpoint
+pub async fn rpc_is_mainnet(rpc: &RpcClient) -> Result<bool> {
+    static CLUSTERS: OnceLock<Mutex<HashMap<String, bool>>> = OnceLock::new();
+    let clusters = CLUSTERS.get_or_init(Default::default);
+    if let Some(&mainnet) = clusters.lock().unwrap().get(&rpc.url()) {
+        return Ok(mainnet);
+    }
+    let mainnet = rpc.get_genesis_hash().await?.to_string() == MAINNET_GENESIS_HASH;
+    clusters.lock().unwrap().insert(rpc.url(), mainnet);
+    Ok(mainnet)
+}
+
+/// Latest blockhash as seen at `commitment`
+pub async fn latest_blockhash(rpc: &RpcClient, commitment: CommitmentConfig) -> Result<Hash> {
+    Ok(rpc.get_latest_blockhash_with_commitment(commitment).await?.0)
+}
+
+/// Send `tx` with preflight at `commitment` and wait until it reaches `commitment`,
+/// e.g. build at processed but confirm at finalized. Waits per [`confirmation`];
+/// gives up once the transaction's blockhash expires.
+pub async fn send_and_confirm(
+    rpc: &RpcClient,
+    tx: &impl SerializableTransaction,
+    commitment: CommitmentConfig,
+) -> Result<Signature> {
+    if dry_run() {
+        return simulate_and_report(rpc, tx, "transaction").await;
+    }
+    // subscribe before sending so the notification can't be missed
+    let notified = match confirmation() {
+        Confirmation::WebSocket => subscribe_signature(ws_url(&rpc.url()), *tx.get_signature(), commitment)
+            .await
+            .map_err(|e| eprintln!("signatureSubscribe unavailable ({e}); confirming by polling"))
+            .ok(),
+        Confirmation::Polling => None,
+    };
+
+    let config = RpcSendTransactionConfig { preflight_commitment: Some(commitment.commitment), ..Default::default() };
+    let signature = rpc.send_transaction_with_config(tx, config).await?;
+    record_sent(SentVia::Rpc);
+    let sent_at = Instant::now();
+
+    if let Some(notified) = notified {
+        match tokio::time::timeout(WS_CONFIRM_TIMEOUT, notified).await {
+            Ok(Ok(Some(Ok(())))) => {
+                record_confirmation(sent_at.elapsed());
+                return Ok(signature);
+            }
+            Ok(Ok(Some(Err(e)))) => return Err(ClientError::from(e).into()),
+            _ => eprintln!("signatureSubscribe for {signature} dropped; confirming by polling"),
+        }
+    }
+    poll_confirmation(rpc, &signature, tx.get_recent_blockhash(), commitment).await?;
+    record_confirmation(sent_at.elapsed());
+    Ok(signature)
+}
+
+/// Poll getSignatureStatuses until `signature` reaches `commitment` or `blockhash` expires
+pub async fn poll_confirmation(
+    rpc: &RpcClient,
+    signature: &Signature,
+    blockhash: &Hash,
+    commitment: CommitmentConfig,
+) -> Result<()> {
+    loop {
+        match rpc.get_signature_status_with_commitment(signature, commitment).await? {
+            Some(Ok(())) => return Ok(()),
+            Some(Err(e)) => return Err(ClientError::from(e).into()),
+            None if !rpc.is_blockhash_valid(blockhash, CommitmentConfig::processed()).await? => {
+                return Err(Error::Rpc(Box::new(ClientError::from(std::io::Error::new(
+                    std::io::ErrorKind::TimedOut,
+                    format!("{signature} not {:?} before its blockhash expired", commitment.commitment),
+                )))));
+            }
+            None => tokio::time::sleep(Duration::from_millis(500)).await,
+        }
+    }
+}
+
+// ─────────────────── Dry run ───────────────────
+
+/// `status` of the execute responses returned in place of calling an execute endpoint
+pub const DRY_RUN_STATUS: &str = "DryRun";
+
+/// Simulate `tx` in place of sending it and print what would have gone out.
+/// Returns the signature it would have landed under; a failed simulation is an error,
+/// so a dry run in CI fails the same way the real run would.
+pub async fn simulate_and_report(
+    rpc: &RpcClient,
+    tx: &impl SerializableTransaction,
+    what: &str,
+) -> Result<Signature> {
+    // API-built transactions may still lack a co-signer (e.g. Jupiter paying gas on Ultra)
+    let config = RpcSimulateTransactionConfig {
+        sig_verify: false,
+        commitment: Some(rpc.commitment()),
+        ..Default::default()
+    };
+    let result = rpc.simulate_transaction_with_config(tx, config).await?.value;
+    let signature = *tx.get_signature();
+    let logs = result.logs.unwrap_or_default();
+    let blockhash = tx.get_recent_blockhash();
+    let mut text = format!("DRY_RUN: {what} not sent\n  signature  {signature}\n  blockhash  {blockhash}");
+    if let Some(units) = result.units_consumed {
+        text.push_str(&format!("\n  compute    {units} units"));
+    }
+    for line in &logs {
+        text.push_str(&format!("\n  | {line}"));
+    }
+    match &result.err {
+        Some(e) => text.push_str(&format!("\n  simulation FAILED: {e}")),
+        None => text.push_str("\n  simulation ok"),
+    }
+    let data = json!({
+        "what": what,
+        "signature": signature.to_string(),
+        "blockhash": blockhash.to_string(),
+        "unitsConsumed": result.units_consumed,
+        "logs": logs,
+        "error": result.err.as_ref().map(|e| e.to_string()),
+    });
+    emit("dry_run", &data, text);
+    match result.err {
+        Some(e) => Err(ClientError::from(e).into()),
+        None => Ok(signature),
+    }
+}
+
+/// Dry-run stand-in for handing `signed` (base64) to an execute endpoint
+#[cfg(any(feature = "ultra", feature = "trigger", feature = "recurring"))]
+pub(crate) async fn simulate_execute(endpoint: &str, signed: &str) -> Result<Signature> {
+    let tx: VersionedTransaction = bincode::deserialize(&base64::decode(signed)?)?;
+    let rpc = rpc_client(&Config::load()?);
+    simulate_and_report(&rpc, &tx, endpoint).await
+}
+
+// ─────────────────── WebSocket confirmation ───────────────────
+
+/// Longest wait for a signatureSubscribe notification before falling back to
+/// polling; about a blockhash lifetime
+const WS_CONFIRM_TIMEOUT: Duration = Duration::from_secs(90);
+
+/// How [`send_and_confirm`] waits for a signature
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Confirmation {
+    /// getSignatureStatuses every 500ms
+    #[default]
+    Polling,
+    /// signatureSubscribe on the RPC's PubSub endpoint, polling if the socket fails
+    WebSocket,
+}
+
+impl FromStr for Confirmation {
+    type Err = ConfigError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "polling" | "poll" | "http" => Ok(Confirmation::Polling),
+            "websocket" | "ws" => Ok(Confirmation::WebSocket),
+            other => Err(ConfigError::Invalid {
+                var: "CONFIRMATION",
+                reason: format!("{other:?} is not polling or websocket"),
+            }),
+        }
+    }
+}
+
+/// `CONFIRMATION`, else `confirmation` in jupiter.toml, else polling
+pub fn confirmation() -> Confirmation {
+    // PubSub traffic isn't recorded
+    if vcr_mode() != VcrMode::Off {
+        return Confirmation::Polling;
+    }
+    setting("CONFIRMATION", &file_config().confirmation)
+        .and_then(|s| s.parse().ok())
+        .unwrap_or_default()
+}
+
+/// PubSub endpoint: `WS_URL`, else `ws_url` in jupiter.toml, else `rpc_url` with ws:// / wss://
+pub fn ws_url(rpc_url: &str) -> String {
+    setting("WS_URL", &file_config().ws_url).unwrap_or_else(|| {
+        if let Some(rest) = rpc_url.strip_prefix("https://") {
+            format!("wss://{rest}")
+        } else if let Some(rest) = rpc_url.strip_prefix("http://") {
+            format!("ws://{rest}")
+        } else {
+            rpc_url.to_string()
+        }
+    })
+}
+
+/// Open a signatureSubscribe on a background task. Returns once the
+/// subscription is live; the receiver yields the transaction's outcome, or
+/// `None` if the socket closed first.
+async fn subscribe_signature(
+    ws_url: String,
+    signature: Signature,
+    commitment: CommitmentConfig,
+) -> std::result::Result<oneshot::Receiver<Option<TransactionResult<()>>>, String> {
+    let (ready_tx, ready_rx) = oneshot::channel();
+    let (done_tx, done_rx) = oneshot::channel();
+    tokio::spawn(async move {
+        let client = match PubsubClient::new(&ws_url).await {
+            Ok(client) => client,
+            Err(e) => {
+                let _ = ready_tx.send(Err(e.to_string()));
+                return;
+            }
+        };
+        let config = RpcSignatureSubscribeConfig { commitment: Some(commitment), enable_received_notification: Some(false) };
+        let (mut stream, unsubscribe) = match client.signature_subscribe(&signature, Some(config)).await {
+            Ok(sub) => sub,
+            Err(e) => {
+                let _ = ready_tx.send(Err(e.to_string()));
+                return;
+            }
+        };
+        let _ = ready_tx.send(Ok(()));
+        let outcome = match stream.next().await.map(|resp| resp.value) {
+            Some(RpcSignatureResult::ProcessedSignature(processed)) => Some(processed.err.map_or(Ok(()), Err)),
+            _ => None,
+        };
+        drop(stream);
+        unsubscribe().await;
+        let _ = done_tx.send(outcome);
+    });
+    ready_rx.await.map_err(|_| "subscription task ended".to_string())??;
+    Ok(done_rx)
+}
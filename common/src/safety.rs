@@ -0,0 +1,257 @@
+// common/src/safety.rs
+use crate::{api_url, check_mint, ApiResponseExt, JupiterApi, JupiterReqExt, MintPolicy, Result};
+#[cfg(feature = "tokens")]
+use crate::get_token;
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
+
+// ─────────────────── Pre-trade safety pipeline ───────────────────
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Info,
+    Warning,
+    Critical,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct SafetyFinding {
+    /// Name of the check that raised it
+    pub source: String,
+    pub severity: Severity,
+    pub message: String,
+}
+
+/// One source of pre-trade warnings about a mint
+#[async_trait]
+pub trait SafetyCheck: Send + Sync {
+    fn name(&self) -> &str;
+    async fn check(&self, mint: &Pubkey) -> Result<Vec<SafetyFinding>>;
+}
+
+fn finding(source: &str, severity: Severity, message: impl Into<String>) -> SafetyFinding {
+    SafetyFinding { source: source.to_string(), severity, message: message.into() }
+}
+
+/// Ultra Shield warnings (`/ultra/v1/shield?mints=`)
+pub struct ShieldCheck {
+    http: Client,
+}
+
+impl ShieldCheck {
+    pub fn new(http: Client) -> Self {
+        Self { http }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct ShieldWarning {
+    #[serde(rename = "type")]
+    kind: String,
+    message: String,
+    severity: Severity,
+}
+
+#[derive(Deserialize, Debug)]
+struct ShieldResponse {
+    warnings: HashMap<String, Vec<ShieldWarning>>,
+}
+
+#[async_trait]
+impl SafetyCheck for ShieldCheck {
+    fn name(&self) -> &str {
+        "shield"
+    }
+
+    async fn check(&self, mint: &Pubkey) -> Result<Vec<SafetyFinding>> {
+        let url = format!("{}/shield?mints={mint}", api_url(JupiterApi::Ultra));
+        let resp: ShieldResponse = self.http.get(&url).with_jupiter_key().send_with_retry().await?.api_json().await?;
+        Ok(resp
+            .warnings
+            .get(&mint.to_string())
+            .into_iter()
+            .flatten()
+            .map(|w| finding(self.name(), w.severity, format!("{}: {}", w.kind, w.message)))
+            .collect())
+    }
+}
+
+/// Token API listing: unknown or unverified mints and missing audit flags
+#[cfg(feature = "tokens")]
+pub struct TokenTagCheck {
+    http: Client,
+    /// Severity for tokens that are listed but not verified
+    pub unverified: Severity,
+}
+
+#[cfg(feature = "tokens")]
+impl TokenTagCheck {
+    pub fn new(http: Client) -> Self {
+        Self { http, unverified: Severity::Warning }
+    }
+}
+
+#[cfg(feature = "tokens")]
+#[async_trait]
+impl SafetyCheck for TokenTagCheck {
+    fn name(&self) -> &str {
+        "tokens"
+    }
+
+    async fn check(&self, mint: &Pubkey) -> Result<Vec<SafetyFinding>> {
+        let Some(token) = get_token(&self.http, &mint.to_string()).await? else {
+            return Ok(vec![finding(self.name(), Severity::Critical, "not found in the Token API")]);
+        };
+        let mut out = Vec::new();
+        if !token.is_verified() {
+            out.push(finding(self.name(), self.unverified, format!("{} is not verified", token.symbol)));
+        }
+        if let Some(top) = token.audit.as_ref().and_then(|a| a.top_holders_percentage).filter(|&p| p > 50.0) {
+            out.push(finding(self.name(), Severity::Warning, format!("top holders own {top:.1}% of supply")));
+        }
+        Ok(out)
+    }
+}
+
+/// On-chain mint account against a [`MintPolicy`]
+pub struct MintAccountCheck {
+    rpc: Arc<RpcClient>,
+    policy: MintPolicy,
+}
+
+impl MintAccountCheck {
+    pub fn new(rpc: Arc<RpcClient>, policy: MintPolicy) -> Self {
+        Self { rpc, policy }
+    }
+}
+
+#[async_trait]
+impl SafetyCheck for MintAccountCheck {
+    fn name(&self) -> &str {
+        "mint"
+    }
+
+    async fn check(&self, mint: &Pubkey) -> Result<Vec<SafetyFinding>> {
+        let info = check_mint(&self.rpc, mint).await?;
+        Ok(match self.policy.evaluate(&info) {
+            Ok(()) => vec![],
+            Err(rejected) => rejected
+                .reasons
+                .into_iter()
+                .map(|r| finding(self.name(), Severity::Critical, r))
+                .collect(),
+        })
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct SafetyVerdict {
+    pub mint: Pubkey,
+    /// True when any finding is at or above the pipeline's blocking severity
+    pub blocked: bool,
+    pub findings: Vec<SafetyFinding>,
+}
+
+impl SafetyVerdict {
+    pub fn reasons(&self) -> Vec<String> {
+        self.findings
+            .iter()
+            .map(|f| format!("[{}/{:?}] {}", f.source, f.severity, f.message))
+            .collect()
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("pre-trade safety check blocked {mint}: {}", reasons.join("; "))]
+pub struct SafetyBlocked {
+    pub mint: Pubkey,
+    pub reasons: Vec<String>,
+}
+
+/// Runs every check concurrently and folds the findings into one verdict.
+/// A check that errors is reported as a finding at `block_at` severity.
+pub struct SafetyPipeline {
+    checks: Vec<Box<dyn SafetyCheck>>,
+    block_at: Severity,
+}
+
+impl SafetyPipeline {
+    pub fn new() -> Self {
+        Self { checks: Vec::new(), block_at: Severity::Critical }
+    }
+
+    /// Shield + Token API + on-chain mint checks with the default [`MintPolicy`];
+    /// the Token API check needs the `tokens` feature
+    pub fn standard(http: Client, rpc: Arc<RpcClient>) -> Self {
+        let pipeline = Self::new().with(ShieldCheck::new(http.clone()));
+        #[cfg(feature = "tokens")]
+        let pipeline = pipeline.with(TokenTagCheck::new(http));
+        pipeline.with(MintAccountCheck::new(rpc, MintPolicy::default()))
+    }
+
+    pub fn with(mut self, check: impl SafetyCheck + 'static) -> Self {
+        self.checks.push(Box::new(check));
+        self
+    }
+
+    pub fn block_at(mut self, severity: Severity) -> Self {
+        self.block_at = severity;
+        self
+    }
+
+    pub async fn evaluate(&self, mint: &Pubkey) -> SafetyVerdict {
+        let results = futures::future::join_all(self.checks.iter().map(|c| c.check(mint))).await;
+
+        let mut findings = Vec::new();
+        for (check, result) in self.checks.iter().zip(results) {
+            match result {
+                Ok(f) => findings.extend(f),
+                Err(e) => findings.push(finding(check.name(), self.block_at, format!("check failed: {e}"))),
+            }
+        }
+        let blocked = findings.iter().any(|f| f.severity >= self.block_at);
+        SafetyVerdict { mint: *mint, blocked, findings }
+    }
+
+    /// `Err(SafetyBlocked)` when the verdict is blocking
+    pub async fn enforce(&self, mint: &Pubkey) -> Result<SafetyVerdict> {
+        let verdict = self.evaluate(mint).await;
+        if verdict.blocked {
+            return Err(SafetyBlocked { mint: *mint, reasons: verdict.reasons() }.into());
+        }
+        Ok(verdict)
+    }
+}
+
+impl Default for SafetyPipeline {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static PRE_TRADE_HOOK: OnceLock<SafetyPipeline> = OnceLock::new();
+
+/// Install a pipeline that every flow runs on its output mint before signing.
+/// Can only be installed once per process.
+pub fn install_pre_trade_hook(pipeline: SafetyPipeline) -> Result<()> {
+    PRE_TRADE_HOOK
+        .set(pipeline)
+        .map_err(|_| anyhow::anyhow!("a pre-trade hook is already installed").into())
+}
+
+/// Run the installed hook, if any, on `output_mint`
+pub(crate) async fn pre_trade_check(output_mint: &str) -> Result<()> {
+    if let Some(pipeline) = PRE_TRADE_HOOK.get() {
+        let verdict = pipeline.enforce(&output_mint.parse()?).await?;
+        for reason in verdict.reasons() {
+            eprintln!("pre-trade: {reason}");
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,264 @@
+// common/src/scheduler.rs
+use crate::{
+    correlation_id, default_slippage_bps, emit, emit_error, mint_label, progress, swap_with_params, tx_link,
+    with_correlation, Config, Result, SwapParams, TokenAmount, TxSigner, USDC_MINT, WSOL_MINT,
+};
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+
+// ─────────────────── Client-side DCA scheduler ───────────────────
+//
+// Fallback for pairs or sizes the Recurring API rejects: plain swaps on a
+// fixed schedule, run from this process. Progress is persisted so a restart
+// knows which runs were missed while it was down.
+
+/// A swap repeated every `every_secs`, with occurrences anchored at `start_at`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DcaJob {
+    pub name: String,
+    pub input_mint: String,
+    pub output_mint: String,
+    /// Input per run
+    pub amount: TokenAmount,
+    pub slippage_bps: u64,
+    pub every_secs: u64,
+    pub start_at: DateTime<Utc>,
+}
+
+impl DcaJob {
+    /// Index of the latest occurrence at or before `now`
+    pub fn occurrence_at(&self, now: DateTime<Utc>) -> Option<u64> {
+        let elapsed = (now - self.start_at).num_seconds();
+        (elapsed >= 0).then(|| elapsed as u64 / self.every_secs.max(1))
+    }
+
+    pub fn scheduled_at(&self, occurrence: u64) -> DateTime<Utc> {
+        self.start_at + chrono::Duration::seconds((occurrence * self.every_secs) as i64)
+    }
+
+    fn swap_params(&self) -> SwapParams {
+        SwapParams {
+            input_mint: self.input_mint.clone(),
+            output_mint: self.output_mint.clone(),
+            amount: self.amount,
+            slippage_bps: self.slippage_bps,
+            mint_policy: None,
+        }
+    }
+}
+
+/// What to do with occurrences that passed while the scheduler was not running
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MissedRuns {
+    /// Record them as skipped and only run the current one
+    Skip,
+    /// Execute up to `max` of the most recent missed runs, skip the rest
+    CatchUp { max: u32 },
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DcaRun {
+    pub occurrence: u64,
+    pub scheduled_at: DateTime<Utc>,
+    pub executed_at: Option<DateTime<Utc>>,
+    pub signature: Option<String>,
+    /// Raw (quoted) output
+    pub out_amount: Option<u64>,
+    pub error: Option<String>,
+    pub skipped: bool,
+    /// Our id for the run's API calls (see [`crate::correlation_id`])
+    #[serde(default)]
+    pub correlation_id: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+struct DcaState {
+    /// Highest occurrence already handled (run, failed or skipped)
+    last_occurrence: Option<u64>,
+    history: Vec<DcaRun>,
+}
+
+pub struct DcaScheduler {
+    job: DcaJob,
+    missed: MissedRuns,
+    state_path: PathBuf,
+    state: DcaState,
+    cancel: CancellationToken,
+}
+
+impl DcaScheduler {
+    /// Load (or start) the job's state from `state_path`
+    pub fn new(job: DcaJob, state_path: impl Into<PathBuf>) -> Result<Self> {
+        let state_path = state_path.into();
+        let state = if state_path.exists() {
+            serde_json::from_slice(&std::fs::read(&state_path)?)?
+        } else {
+            DcaState::default()
+        };
+        Ok(Self { job, missed: MissedRuns::Skip, state_path, state, cancel: CancellationToken::new() })
+    }
+
+    pub fn with_missed_runs(mut self, missed: MissedRuns) -> Self {
+        self.missed = missed;
+        self
+    }
+
+    /// Stop at the next safe point once `token` is cancelled: a swap already
+    /// being sent finishes and is recorded, later due runs are left for the next start
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancel = token;
+        self
+    }
+
+    pub fn history(&self) -> &[DcaRun] {
+        &self.state.history
+    }
+
+    fn save(&self) -> Result<()> {
+        std::fs::write(&self.state_path, serde_json::to_vec_pretty(&self.state)?)?;
+        Ok(())
+    }
+
+    /// Occurrences due at `now` that have not been handled yet, split into
+    /// (to skip, to execute) according to the missed-run policy. A fresh
+    /// state only picks up the current occurrence.
+    pub fn due(&self, now: DateTime<Utc>) -> (Vec<u64>, Vec<u64>) {
+        let Some(current) = self.job.occurrence_at(now) else { return (vec![], vec![]) };
+        let first = self.state.last_occurrence.map(|l| l + 1).unwrap_or(current);
+        if first > current {
+            return (vec![], vec![]);
+        }
+        let missed: Vec<u64> = (first..current).collect();
+        let keep = match self.missed {
+            MissedRuns::Skip => 0,
+            MissedRuns::CatchUp { max } => (max as usize).min(missed.len()),
+        };
+        let (skip, catch_up) = missed.split_at(missed.len() - keep);
+        let mut run = catch_up.to_vec();
+        run.push(current);
+        (skip.to_vec(), run)
+    }
+
+    /// Handle everything due now, signing with `kp`; each result is persisted before the next swap
+    pub async fn run_due(&mut self, kp: &dyn TxSigner) -> Result<Vec<DcaRun>> {
+        let (skip, run) = self.due(Utc::now());
+        let mut handled = Vec::new();
+
+        for occurrence in skip {
+            let r = DcaRun {
+                occurrence,
+                scheduled_at: self.job.scheduled_at(occurrence),
+                executed_at: None,
+                signature: None,
+                out_amount: None,
+                error: None,
+                skipped: true,
+                correlation_id: None,
+            };
+            emit("dca_run", &r, format!("[{}] skipping missed run #{occurrence} ({})", self.job.name, r.scheduled_at));
+            handled.push(self.record(r)?);
+        }
+
+        for occurrence in run {
+            // not recorded, so the next start picks it up as missed
+            if self.cancel.is_cancelled() {
+                break;
+            }
+            // each run is its own flow invocation as far as support is concerned
+            let r = with_correlation(async {
+                let mut r = DcaRun {
+                    occurrence,
+                    scheduled_at: self.job.scheduled_at(occurrence),
+                    executed_at: Some(Utc::now()),
+                    signature: None,
+                    out_amount: None,
+                    error: None,
+                    skipped: false,
+                    correlation_id: Some(correlation_id()),
+                };
+                match swap_with_params(kp, self.job.swap_params()).await {
+                    Ok(res) => {
+                        let link = tx_link(&res.signature);
+                        let out = format!("{} {}", res.out_amount, mint_label(&self.job.output_mint));
+                        let text = format!("[{}] run #{occurrence}: {link} ({out} out)", self.job.name);
+                        r.signature = Some(res.signature);
+                        r.out_amount = Some(res.out_amount.raw);
+                        emit("dca_run", &r, text);
+                    }
+                    Err(e) => {
+                        let message = format!("[{}] run #{occurrence} failed: {e}", self.job.name);
+                        eprintln!("{message}");
+                        emit_error(&message);
+                        r.error = Some(e.to_string());
+                    }
+                }
+                r
+            })
+            .await;
+            handled.push(self.record(r)?);
+        }
+        Ok(handled)
+    }
+
+    fn record(&mut self, run: DcaRun) -> Result<DcaRun> {
+        self.state.last_occurrence = Some(run.occurrence);
+        self.state.history.push(run.clone());
+        self.save()?;
+        Ok(run)
+    }
+
+    /// Run due swaps, then sleep until the next occurrence, until cancelled
+    pub async fn run(&mut self, kp: &dyn TxSigner) -> Result<()> {
+        while !self.cancel.is_cancelled() {
+            self.run_due(kp).await?;
+            let now = Utc::now();
+            let next = self.job.occurrence_at(now).map(|o| o + 1).unwrap_or(0);
+            let wait = (self.job.scheduled_at(next) - now).to_std().unwrap_or(Duration::ZERO);
+            tokio::select! {
+                _ = tokio::time::sleep(wait) => {}
+                _ = self.cancel.cancelled() => {}
+            }
+        }
+        self.save()?;
+        progress(format!("{}: stopped, state saved to {}", self.job.name, self.state_path.display()));
+        Ok(())
+    }
+}
+
+impl Default for DcaJob {
+    /// 1 USDC → SOL every hour on the hour (occurrences anchored at the Unix epoch)
+    fn default() -> Self {
+        Self {
+            name: "usdc-sol-hourly".into(),
+            input_mint: USDC_MINT.to_string(),
+            output_mint: WSOL_MINT.to_string(),
+            amount: TokenAmount::new(1_000_000, 6),
+            slippage_bps: default_slippage_bps(),
+            every_secs: 60 * 60,
+            start_at: Utc.timestamp_opt(0, 0).unwrap(),
+        }
+    }
+}
+
+pub async fn dca_scheduler_flow(
+    kp: &dyn TxSigner,
+    job: DcaJob,
+    state_path: PathBuf,
+    missed: MissedRuns,
+    cancel: CancellationToken,
+) -> Result<()> {
+    Config::load()?;
+    progress(format!(
+        "{}: {} {} → {} every {}, state in {}",
+        job.name,
+        job.amount,
+        mint_label(&job.input_mint),
+        mint_label(&job.output_mint),
+        humantime::format_duration(Duration::from_secs(job.every_secs)),
+        state_path.display(),
+    ));
+    DcaScheduler::new(job, state_path)?.with_missed_runs(missed).with_cancellation(cancel).run(kp).await
+}
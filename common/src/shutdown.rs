@@ -0,0 +1,42 @@
+// common/src/shutdown.rs
+use crate::progress;
+use tokio_util::sync::CancellationToken;
+
+// ─────────────────── Graceful shutdown ───────────────────
+//
+// Long-running components (the DCA scheduler, monitors, watchers, the live
+// quoter) take a CancellationToken and stop at the next safe point: a swap
+// that is already being sent is allowed to confirm and its result is written
+// to the state file before the loop returns. A second Ctrl-C exits at once.
+
+/// A token cancelled on the first Ctrl-C (or SIGTERM on Unix)
+pub fn shutdown_token() -> CancellationToken {
+    let token = CancellationToken::new();
+    let cancel = token.clone();
+    tokio::spawn(async move {
+        if wait_for_signal().await.is_err() {
+            return;
+        }
+        progress("shutting down after the current step (Ctrl-C again to exit now)");
+        cancel.cancel();
+        if wait_for_signal().await.is_ok() {
+            std::process::exit(130);
+        }
+    });
+    token
+}
+
+#[cfg(unix)]
+async fn wait_for_signal() -> std::io::Result<()> {
+    use tokio::signal::unix::{signal, SignalKind};
+    let mut term = signal(SignalKind::terminate())?;
+    tokio::select! {
+        res = tokio::signal::ctrl_c() => res,
+        _ = term.recv() => Ok(()),
+    }
+}
+
+#[cfg(not(unix))]
+async fn wait_for_signal() -> std::io::Result<()> {
+    tokio::signal::ctrl_c().await
+}
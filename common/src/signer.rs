@@ -0,0 +1,369 @@
+// common/src/signer.rs
+#[cfg(feature = "keystore")]
+use crate::keystore::keystore_keypair;
+use crate::{file_config, keystore_path, setting, Config, ConfigError, Error, RemoteSigner, Result};
+use async_trait::async_trait;
+use solana_sdk::{
+    derivation_path::DerivationPath,
+    pubkey::Pubkey,
+    signature::{Keypair, Signature, Signer},
+    signer::keypair::{
+        generate_seed_from_seed_phrase_and_passphrase, keypair_from_seed, keypair_from_seed_and_derivation_path,
+    },
+    transaction::VersionedTransaction,
+};
+use std::env;
+use std::path::{Path, PathBuf};
+use zeroize::Zeroizing;
+
+#[cfg(feature = "ledger")]
+use solana_sdk::signer::SignerError;
+
+/// Account 0 as derived by Phantom, Solflare and `solana-keygen recover 'prompt:?key=0/0'`
+pub const DEFAULT_DERIVATION_PATH: &str = "m/44'/501'/0'/0'";
+
+// ─────────────────── Signer ───────────────────
+
+/// Load Keypair from SECRET_KEY, else MNEMONIC, else the encrypted KEYSTORE_PATH,
+/// else the KEYPAIR_PATH file.
+/// SECRET_KEY and the file may be in any format [`parse_keypair`] accepts.
+pub fn try_keypair(cfg: &Config) -> Result<Keypair, ConfigError> {
+    let invalid = |var, reason: String| ConfigError::Invalid { var, reason };
+    match env::var("SECRET_KEY").map(Zeroizing::new) {
+        Ok(secret) if !secret.trim().is_empty() => parse_keypair(&secret).map_err(|e| invalid("SECRET_KEY", e)),
+        _ if mnemonic().is_some() => mnemonic_keypair(),
+        _ => match keystore_path() {
+            Some(path) => keystore_keypair(&path),
+            None if cfg.keypair_path.is_empty() => {
+                Err(ConfigError::Missing("SECRET_KEY, MNEMONIC, KEYSTORE_PATH or KEYPAIR_PATH"))
+            }
+            None => read_keypair(&cfg.keypair_path)
+                .map_err(|e| invalid("KEYPAIR_PATH", format!("{}: {e}", cfg.keypair_path))),
+        },
+    }
+}
+
+/// KEYSTORE_PATH without the `keystore` feature is an error rather than silently skipped
+#[cfg(not(feature = "keystore"))]
+fn keystore_keypair(_path: &std::path::Path) -> Result<Keypair, ConfigError> {
+    let reason = "this build has no `keystore` feature".to_string();
+    Err(ConfigError::Invalid { var: "KEYSTORE_PATH", reason })
+}
+
+/// Keypair from the formats wallets and tools export, detected by shape:
+/// - JSON byte array, as written by solana-keygen or copied from a `Uint8Array` (`[12,34,…]`)
+/// - base58, as exported by Phantom / Solflare
+/// - hex
+///
+/// Each may hold the full 64-byte keypair or just its 32-byte seed. The decoded
+/// bytes are wiped once the keypair is built.
+pub fn parse_keypair(text: &str) -> Result<Keypair, String> {
+    let text = text.trim();
+    let bytes: Zeroizing<Vec<u8>> = if text.starts_with('[') {
+        serde_json::from_str::<Vec<u8>>(text).map_err(|e| format!("not a JSON byte array: {e}"))?.into()
+    } else if matches!(text.len(), 64 | 128) && text.bytes().all(|b| b.is_ascii_hexdigit()) {
+        (0..text.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&text[i..i + 2], 16))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("not hex: {e}"))?
+            .into()
+    } else {
+        bs58::decode(text)
+            .into_vec()
+            .map_err(|e| format!("not a JSON byte array, hex or base58: {e}"))?
+            .into()
+    };
+    keypair_from_bytes(&bytes)
+}
+
+/// 64 bytes as a keypair, 32 as a seed
+fn keypair_from_bytes(bytes: &[u8]) -> Result<Keypair, String> {
+    match bytes.len() {
+        64 => Keypair::from_bytes(bytes).map_err(|e| e.to_string()),
+        32 => keypair_from_seed(bytes).map_err(|e| e.to_string()),
+        n => Err(format!("{n} bytes; expected a 64-byte keypair or a 32-byte seed")),
+    }
+}
+
+/// Read a keypair file: any text format of [`parse_keypair`], or the raw 64/32
+/// bytes of a binary key or seed file. A leading `~/` is the home directory.
+pub fn read_keypair(path: impl AsRef<Path>) -> Result<Keypair, String> {
+    let path = expand_home(path.as_ref());
+    let raw = Zeroizing::new(std::fs::read(&path).map_err(|e| e.to_string())?);
+    match std::str::from_utf8(&raw) {
+        Ok(text) if !text.trim().is_empty() => parse_keypair(text),
+        Ok(_) => Err("file is empty".into()),
+        Err(_) => keypair_from_bytes(&raw),
+    }
+}
+
+fn expand_home(path: &Path) -> PathBuf {
+    match (path.strip_prefix("~"), env::var_os("HOME")) {
+        (Ok(rest), Some(home)) => PathBuf::from(home).join(rest),
+        _ => path.to_path_buf(),
+    }
+}
+
+pub(crate) fn mnemonic() -> Option<String> {
+    setting("MNEMONIC", &file_config().mnemonic)
+}
+
+/// Keypair for `MNEMONIC` (+ optional `MNEMONIC_PASSPHRASE`) at `DERIVATION_PATH`,
+/// [`DEFAULT_DERIVATION_PATH`] unless set
+fn mnemonic_keypair() -> Result<Keypair, ConfigError> {
+    let phrase = Zeroizing::new(mnemonic().ok_or(ConfigError::Missing("MNEMONIC"))?);
+    let path = setting("DERIVATION_PATH", &file_config().derivation_path)
+        .unwrap_or_else(|| DEFAULT_DERIVATION_PATH.to_string());
+    let passphrase = Zeroizing::new(env::var("MNEMONIC_PASSPHRASE").unwrap_or_default());
+    keypair_from_mnemonic(&phrase, &passphrase, &path)
+}
+
+/// Derive the ed25519 keypair for a BIP39 `phrase` at a BIP44 `path` like `m/44'/501'/1'/0'`
+pub fn keypair_from_mnemonic(phrase: &str, passphrase: &str, path: &str) -> Result<Keypair, ConfigError> {
+    let words = phrase.split_whitespace().collect::<Vec<_>>();
+    if ![12, 15, 18, 21, 24].contains(&words.len()) {
+        return Err(ConfigError::Invalid {
+            var: "MNEMONIC",
+            reason: format!("{} words; expected 12, 15, 18, 21 or 24", words.len()),
+        });
+    }
+    let path = DerivationPath::from_absolute_path_str(path.trim())
+        .map_err(|e| ConfigError::Invalid { var: "DERIVATION_PATH", reason: e.to_string() })?;
+    let phrase = Zeroizing::new(words.join(" "));
+    let seed = Zeroizing::new(generate_seed_from_seed_phrase_and_passphrase(&phrase, passphrase));
+    keypair_from_seed_and_derivation_path(&seed, Some(path))
+        .map_err(|e| ConfigError::Invalid { var: "MNEMONIC", reason: e.to_string() })
+}
+
+// ─────────────────── TxSigner ───────────────────
+
+/// What the flows sign with. Async so a signature can come from a KMS or
+/// custody service (Turnkey, Fireblocks, …) as easily as from a local key;
+/// every in-process [`Signer`] (Keypair, Ledger) gets it for free.
+#[async_trait]
+pub trait TxSigner: Send + Sync {
+    /// The account this signer signs for
+    fn address(&self) -> Pubkey;
+    /// Sign a serialized transaction message
+    async fn sign(&self, message: &[u8]) -> Result<Signature>;
+    /// Separate account that pays network fees and rent (gas sponsorship);
+    /// `None` when [`TxSigner::address`] pays its own
+    fn fee_payer(&self) -> Option<&dyn TxSigner> {
+        None
+    }
+}
+
+#[async_trait]
+impl<T: Signer + Send + Sync> TxSigner for T {
+    fn address(&self) -> Pubkey {
+        self.pubkey()
+    }
+
+    async fn sign(&self, message: &[u8]) -> Result<Signature> {
+        Ok(self.try_sign_message(message)?)
+    }
+}
+
+// ─────────────────── Wallet (hot key, hardware or remote) ───────────────────
+
+/// The signer the flows use, picked with `SIGNER`: the configured keypair,
+/// a Ledger (needs the `ledger` feature) or a remote signing service,
+/// optionally with a separate fee payer from `PAYER_SECRET_KEY` / `PAYER_KEYPAIR_PATH`
+pub enum Wallet {
+    Keypair(Keypair),
+    #[cfg(feature = "ledger")]
+    Ledger(LedgerSigner),
+    Remote(RemoteSigner),
+    /// `user` owns the tokens and signs for them; `payer` covers fees and rent
+    Sponsored { user: Box<Wallet>, payer: Keypair },
+}
+
+#[async_trait]
+impl TxSigner for Wallet {
+    fn address(&self) -> Pubkey {
+        match self {
+            Wallet::Keypair(kp) => kp.pubkey(),
+            #[cfg(feature = "ledger")]
+            Wallet::Ledger(ledger) => ledger.pubkey(),
+            Wallet::Remote(remote) => remote.address(),
+            Wallet::Sponsored { user, .. } => user.address(),
+        }
+    }
+
+    async fn sign(&self, message: &[u8]) -> Result<Signature> {
+        match self {
+            Wallet::Keypair(kp) => TxSigner::sign(kp, message).await,
+            #[cfg(feature = "ledger")]
+            Wallet::Ledger(ledger) => TxSigner::sign(ledger, message).await,
+            Wallet::Remote(remote) => remote.sign(message).await,
+            Wallet::Sponsored { user, .. } => user.sign(message).await,
+        }
+    }
+
+    fn fee_payer(&self) -> Option<&dyn TxSigner> {
+        match self {
+            Wallet::Sponsored { payer, .. } => Some(payer),
+            _ => None,
+        }
+    }
+}
+
+/// Where signatures come from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SignerKind {
+    Keypair,
+    Ledger,
+    Remote,
+}
+
+/// `SIGNER` (keypair, ledger or remote), else `signer` in jupiter.toml, else keypair
+pub(crate) fn signer_kind() -> Result<SignerKind, ConfigError> {
+    match setting("SIGNER", &file_config().signer).map(|s| s.to_ascii_lowercase()).as_deref() {
+        None | Some("keypair") => Ok(SignerKind::Keypair),
+        Some("ledger") if cfg!(feature = "ledger") => Ok(SignerKind::Ledger),
+        Some("ledger") => Err(ConfigError::Invalid {
+            var: "SIGNER",
+            reason: "ledger signing needs common built with the `ledger` feature".into(),
+        }),
+        Some("remote") => Ok(SignerKind::Remote),
+        Some(other) => Err(ConfigError::Invalid {
+            var: "SIGNER",
+            reason: format!("{other:?} is not keypair, ledger or remote"),
+        }),
+    }
+}
+
+/// The signer `SIGNER` selects, sponsored by [`try_payer`] when one is configured; see [`Wallet`]
+pub fn try_signer(cfg: &Config) -> Result<Wallet, ConfigError> {
+    let wallet = match signer_kind()? {
+        SignerKind::Keypair => Wallet::Keypair(try_keypair(cfg)?),
+        #[cfg(feature = "ledger")]
+        SignerKind::Ledger => Wallet::Ledger(LedgerSigner::from_env()?),
+        #[cfg(not(feature = "ledger"))]
+        SignerKind::Ledger => unreachable!("signer_kind rejects SIGNER=ledger without the feature"),
+        SignerKind::Remote => Wallet::Remote(RemoteSigner::from_env()?),
+    };
+    match try_payer()? {
+        Some(payer) if payer.pubkey() != wallet.address() => Ok(Wallet::Sponsored { user: Box::new(wallet), payer }),
+        _ => Ok(wallet),
+    }
+}
+
+/// Fee payer from `PAYER_SECRET_KEY`, else the `PAYER_KEYPAIR_PATH` file
+/// (`payer_keypair_path` in jupiter.toml); `None` when neither is set
+pub fn try_payer() -> Result<Option<Keypair>, ConfigError> {
+    let invalid = |var, reason: String| ConfigError::Invalid { var, reason };
+    if let Some(secret) = setting("PAYER_SECRET_KEY", &None).map(Zeroizing::new) {
+        return parse_keypair(&secret).map(Some).map_err(|e| invalid("PAYER_SECRET_KEY", e));
+    }
+    match setting("PAYER_KEYPAIR_PATH", &file_config().payer_keypair_path) {
+        Some(path) => read_keypair(&path).map(Some).map_err(|e| invalid("PAYER_KEYPAIR_PATH", format!("{path}: {e}"))),
+        None => Ok(None),
+    }
+}
+
+/// Ledger signing through solana-remote-wallet. Holds only the device URI and
+/// derivation path, so it stays `Send`; the device is opened for each signature.
+#[cfg(feature = "ledger")]
+pub struct LedgerSigner {
+    uri: String,
+    derivation_path: DerivationPath,
+    pubkey: Pubkey,
+}
+
+#[cfg(feature = "ledger")]
+impl LedgerSigner {
+    /// `LEDGER_URI` (default `usb://ledger`) at `DERIVATION_PATH`
+    /// (default [`DEFAULT_DERIVATION_PATH`], as Phantom/Solflare use with a Ledger)
+    pub fn from_env() -> Result<Self, ConfigError> {
+        let uri = setting("LEDGER_URI", &None).unwrap_or_else(|| "usb://ledger".to_string());
+        let path = setting("DERIVATION_PATH", &file_config().derivation_path)
+            .unwrap_or_else(|| DEFAULT_DERIVATION_PATH.to_string());
+        let derivation_path = DerivationPath::from_absolute_path_str(path.trim())
+            .map_err(|e| ConfigError::Invalid { var: "DERIVATION_PATH", reason: e.to_string() })?;
+        Self::connect(&uri, derivation_path)
+    }
+
+    /// Reads the public key from the device, which must be unlocked with the Solana app open
+    pub fn connect(uri: &str, derivation_path: DerivationPath) -> Result<Self, ConfigError> {
+        let invalid = |e: SignerError| ConfigError::Invalid { var: "LEDGER_URI", reason: format!("{uri}: {e}") };
+        let mut ledger = LedgerSigner { uri: uri.to_string(), derivation_path, pubkey: Pubkey::default() };
+        ledger.pubkey = ledger.open().and_then(|device| device.try_pubkey()).map_err(invalid)?;
+        Ok(ledger)
+    }
+
+    fn open(&self) -> Result<solana_remote_wallet::remote_keypair::RemoteKeypair, SignerError> {
+        use solana_remote_wallet::{locator::Locator, remote_keypair::generate_remote_keypair};
+        let manager = solana_remote_wallet::remote_wallet::maybe_wallet_manager()
+            .map_err(|e| SignerError::Connection(e.to_string()))?
+            .ok_or_else(|| SignerError::Connection("no hardware wallet found".into()))?;
+        let locator = Locator::new_from_path(&self.uri).map_err(|e| SignerError::Connection(e.to_string()))?;
+        generate_remote_keypair(locator, self.derivation_path.clone(), &manager, false, "ledger")
+            .map_err(|e| SignerError::Connection(e.to_string()))
+    }
+}
+
+#[cfg(feature = "ledger")]
+impl Signer for LedgerSigner {
+    fn try_pubkey(&self) -> Result<Pubkey, SignerError> {
+        Ok(self.pubkey)
+    }
+
+    fn try_sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        eprintln!("Approve the transaction on your Ledger…");
+        self.open()?.try_sign_message(message)
+    }
+
+    fn is_interactive(&self) -> bool {
+        true
+    }
+}
+
+// ─────────────────── Transaction signing ───────────────────
+
+/// Add `kp`'s signature to `tx` in the slot the message header assigns it, and
+/// its fee payer's when the transaction names that payer as a signer.
+/// Signatures already present (a sponsor's, a partner co-signer's) are kept and
+/// slots of signers that haven't signed yet stay as default placeholders.
+/// The mainnet guard is the flow's to run, once, against the RPC it sends through.
+pub async fn sign_versioned_tx(tx: &mut VersionedTransaction, kp: &dyn TxSigner) -> Result<()> {
+    sign_slot(tx, kp).await?;
+    if let Some(payer) = kp.fee_payer() {
+        if !pending_signers(tx).contains(&payer.address()) {
+            // the API built the transaction with the user paying; nothing to add
+            return Ok(());
+        }
+        sign_slot(tx, payer).await?;
+    }
+    Ok(())
+}
+
+async fn sign_slot(tx: &mut VersionedTransaction, kp: &dyn TxSigner) -> Result<()> {
+    let required = tx.message.header().num_required_signatures as usize;
+    let address = kp.address();
+    let slot = tx.message.static_account_keys()[..required]
+        .iter()
+        .position(|key| *key == address)
+        .ok_or_else(|| Error::Sign(format!("{address} is not a required signer of this transaction")))?;
+    let signature = kp.sign(&tx.message.serialize()).await?;
+    tx.signatures.resize(required, Signature::default());
+    tx.signatures[slot] = signature;
+    Ok(())
+}
+
+/// The account paying fees for transactions `kp` signs
+pub(crate) fn fee_payer_address(kp: &dyn TxSigner) -> Pubkey {
+    kp.fee_payer().map_or_else(|| kp.address(), |payer| payer.address())
+}
+
+/// Required signers of `tx` whose signature slot is still empty
+pub fn pending_signers(tx: &VersionedTransaction) -> Vec<Pubkey> {
+    let required = tx.message.header().num_required_signatures as usize;
+    tx.message.static_account_keys()[..required]
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| tx.signatures.get(*i).copied().unwrap_or_default() == Signature::default())
+        .map(|(_, key)| *key)
+        .collect()
+}
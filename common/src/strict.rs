@@ -0,0 +1,140 @@
+// common/src/strict.rs
+use crate::{ConfigError, Error, Result};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::any::Any;
+use std::collections::HashSet;
+use std::str::FromStr;
+use std::sync::{Mutex, OnceLock};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::setting;
+
+// ─────────────────── Strict decoding ───────────────────
+//
+// Response types only model the fields the SDK uses, so a field Jupiter adds
+// (or renames) is silently dropped. With JUPITER_STRICT=warn every such field
+// is printed to stderr once per endpoint, with JUPITER_STRICT=deny the response
+// fails to decode instead. Fields a type keeps in a flattened `extra` map count
+// as unknown too, unless the type lists them in `KnownExtra::KNOWN_EXTRA`.
+// Some endpoints return fields the SDK ignores on purpose (e.g.
+// /swap's fee and simulation details), so `deny` is for maintainers checking a
+// release against the live API rather than for production.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StrictMode {
+    /// Ignore unknown fields, as serde does by default
+    #[default]
+    Off,
+    /// Decode as usual, but report each unknown field once on stderr
+    Warn,
+    /// Fail with [`Error::Deserialize`] naming the unknown fields
+    Deny,
+}
+
+impl FromStr for StrictMode {
+    type Err = ConfigError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "off" | "0" | "false" => Ok(StrictMode::Off),
+            "warn" => Ok(StrictMode::Warn),
+            "deny" => Ok(StrictMode::Deny),
+            other => Err(ConfigError::Invalid {
+                var: "JUPITER_STRICT",
+                reason: format!("{other:?} is not off, warn or deny"),
+            }),
+        }
+    }
+}
+
+/// `JUPITER_STRICT`, off when unset. An invalid value means off here; [`crate::Config::load`] reports it.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn strict_mode() -> StrictMode {
+    setting("JUPITER_STRICT", &None).and_then(|s| s.parse().ok()).unwrap_or_default()
+}
+
+/// A response type that keeps the fields it doesn't model in a flattened
+/// `extra` map, e.g. so a quote round-trips to /swap unchanged
+pub trait KnownExtra {
+    /// Fields Jupiter is known to send that the type keeps in `extra` on purpose
+    const KNOWN_EXTRA: &'static [&'static str];
+
+    fn extra(&self) -> &Value;
+
+    /// Keys of `extra` missing from [`KnownExtra::KNOWN_EXTRA`], and of any nested `extra`
+    /// as `routePlan.0.newField`
+    fn unknown_extra(&self) -> Vec<String> {
+        unknown_keys(self.extra(), Self::KNOWN_EXTRA)
+    }
+}
+
+/// Keys of the `extra` object not in `known`
+pub(crate) fn unknown_keys(extra: &Value, known: &[&str]) -> Vec<String> {
+    let keys = extra.as_object().into_iter().flat_map(|fields| fields.keys());
+    keys.filter(|k| !known.contains(&k.as_str())).cloned().collect()
+}
+
+/// [`KnownExtra::unknown_extra`] for the response types that keep an `extra` map, nothing for the rest
+fn unknown_extra<T: Any>(value: &T) -> Vec<String> {
+    let value = value as &dyn Any;
+    #[cfg(feature = "swap")]
+    if let Some(quote) = value.downcast_ref::<crate::QuoteResponse>() {
+        return quote.unknown_extra();
+    }
+    #[cfg(all(feature = "ultra", not(target_arch = "wasm32")))]
+    if let Some(resp) = value.downcast_ref::<crate::UltraExecuteResponse>() {
+        return resp.unknown_extra();
+    }
+    #[cfg(all(feature = "trigger", not(target_arch = "wasm32")))]
+    if let Some(resp) = value.downcast_ref::<crate::CreateTriggerResponse>() {
+        return resp.unknown_extra();
+    }
+    #[cfg(all(feature = "trigger", not(target_arch = "wasm32")))]
+    if let Some(resp) = value.downcast_ref::<crate::ExecuteTriggerResponse>() {
+        return resp.unknown_extra();
+    }
+    #[cfg(all(feature = "recurring", not(target_arch = "wasm32")))]
+    if let Some(resp) = value.downcast_ref::<crate::RecurringTxResponse>() {
+        return resp.unknown_extra();
+    }
+    Vec::new()
+}
+
+/// `bytes` as `T`, plus the path of every field `T` had no place for
+/// (`newField`, or `orders.0.newField` when nested), in body order, followed by
+/// the unknown keys of its flattened `extra` maps
+pub fn unknown_fields<T: DeserializeOwned + Any>(bytes: &[u8]) -> serde_json::Result<(T, Vec<String>)> {
+    let mut unknown = Vec::new();
+    let mut de = serde_json::Deserializer::from_slice(bytes);
+    let value = serde_ignored::deserialize(&mut de, |path| unknown.push(path.to_string()))?;
+    de.end()?;
+    unknown.extend(unknown_extra(&value));
+    Ok((value, unknown))
+}
+
+/// A response body from `path` as `T`, checked according to `mode`
+pub(crate) fn decode<T: DeserializeOwned + Any>(path: &str, bytes: &[u8], mode: StrictMode) -> Result<T> {
+    let invalid = |e: serde_json::Error| Error::Deserialize(format!("{path} response: {e}"));
+    if mode == StrictMode::Off {
+        return serde_json::from_slice(bytes).map_err(invalid);
+    }
+    let (value, unknown) = unknown_fields(bytes).map_err(invalid)?;
+    if unknown.is_empty() {
+        return Ok(value);
+    }
+    if mode == StrictMode::Deny {
+        return Err(Error::Deserialize(format!("{path} response has unknown fields: {}", unknown.join(", "))));
+    }
+    warn_once(path, &unknown);
+    Ok(value)
+}
+
+/// Polling loops hit the same endpoint over and over; one line per new field is enough
+fn warn_once(path: &str, unknown: &[String]) {
+    static SEEN: OnceLock<Mutex<HashSet<String>>> = OnceLock::new();
+    let mut seen = SEEN.get_or_init(Default::default).lock().unwrap();
+    let new: Vec<&str> = unknown.iter().filter(|f| seen.insert(format!("{path} {f}"))).map(String::as_str).collect();
+    if !new.is_empty() {
+        eprintln!("{path} response has fields the SDK doesn't model: {}", new.join(", "));
+    }
+}
@@ -0,0 +1,327 @@
+// common/src/swap.rs
+use crate::strict::unknown_keys;
+use crate::{KnownExtra, TokenAmount, USDC_MINT, WSOL_MINT};
+use serde::{Deserialize, Serialize};
+#[cfg(target_arch = "wasm32")]
+use crate::DEFAULT_SLIPPAGE_BPS;
+#[cfg(not(target_arch = "wasm32"))]
+use {
+    crate::metrics::record_quote,
+    crate::{
+        api_url, checked_integrator_fee, default_slippage_bps, emit, integrator_fee, mainnet_guard, mint_label,
+        rpc_client, safety, send_and_confirm, sign_versioned_tx, tx_link, warn_transfer_fee, ApiResponseExt, Config,
+        JupiterApi, JupiterClient, JupiterReqExt, MintPolicy, Result, TxSigner,
+    },
+    base64::decode,
+    bincode::deserialize,
+    reqwest::Client,
+    serde_json::json,
+    solana_client::nonblocking::rpc_client::RpcClient,
+    solana_sdk::{pubkey::Pubkey, transaction::VersionedTransaction},
+    std::str::FromStr,
+};
+
+// ─────────────────── Swap Flow (/quote -> /swap -> send) ───────────────────
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct QuoteResponse {
+    pub inputMint: String,
+    pub inAmount: String,
+    pub outputMint: String,
+    pub outAmount: String,
+    pub otherAmountThreshold: String,
+    pub swapMode: String,
+    pub slippageBps: u64,
+    #[serde(default)] pub platformFee: Option<serde_json::Value>,
+    pub priceImpactPct: String,
+    pub routePlan: Vec<RoutePlanStep>,
+    pub contextSlot: u64,
+    pub timeTaken: f64,
+    /// Fields not modelled here (swapUsdValue, mostReliableAmmsQuoteReport, ...), sent back to /swap as received
+    #[serde(flatten)]
+    pub extra: serde_json::Value,
+}
+
+/// One leg of a quote's route; `percent` of the previous hop's output goes through `swap_info`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RoutePlanStep {
+    #[serde(rename = "swapInfo")]
+    pub swap_info: SwapInfo,
+    pub percent: u8,
+    /// Fields not modelled here, kept so the quote round-trips to /swap unchanged
+    #[serde(flatten)]
+    pub extra: serde_json::Value,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SwapInfo {
+    pub amm_key: String,
+    /// DEX name, e.g. "Raydium CLMM"
+    pub label: String,
+    pub input_mint: String,
+    pub output_mint: String,
+    pub in_amount: String,
+    pub out_amount: String,
+    #[serde(flatten)]
+    pub extra: serde_json::Value,
+}
+
+impl QuoteResponse {
+    /// `priceImpactPct` in percent; the API sends a fraction ("0.0012" is 0.12%)
+    pub fn price_impact_pct(&self) -> f64 {
+        self.priceImpactPct.parse::<f64>().unwrap_or(0.0) * 100.0
+    }
+
+    /// Integrator fee as (raw amount, bps), charged in the output mint for
+    /// ExactIn and the input mint for ExactOut
+    pub fn platform_fee(&self) -> Option<(u64, u64)> {
+        let fee = self.platformFee.as_ref()?;
+        Some((fee.get("amount")?.as_str()?.parse().ok()?, fee.get("feeBps")?.as_u64()?))
+    }
+}
+
+impl KnownExtra for QuoteResponse {
+    const KNOWN_EXTRA: &'static [&'static str] = &[
+        "swapUsdValue",
+        "simplerRouteUsed",
+        "mostReliableAmmsQuoteReport",
+        "useIncurredSlippageForQuoting",
+        "otherRoutePlans",
+        "loadedLongtailToken",
+        "instructionVersion",
+    ];
+
+    fn extra(&self) -> &serde_json::Value {
+        &self.extra
+    }
+
+    fn unknown_extra(&self) -> Vec<String> {
+        let mut unknown = unknown_keys(&self.extra, Self::KNOWN_EXTRA);
+        for (i, step) in self.routePlan.iter().enumerate() {
+            unknown.extend(step.unknown_extra().into_iter().map(|k| format!("routePlan.{i}.{k}")));
+        }
+        unknown
+    }
+}
+
+impl KnownExtra for RoutePlanStep {
+    const KNOWN_EXTRA: &'static [&'static str] = &["bps"];
+
+    fn extra(&self) -> &serde_json::Value {
+        &self.extra
+    }
+
+    fn unknown_extra(&self) -> Vec<String> {
+        let mut unknown = unknown_keys(&self.extra, Self::KNOWN_EXTRA);
+        unknown.extend(self.swap_info.unknown_extra().into_iter().map(|k| format!("swapInfo.{k}")));
+        unknown
+    }
+}
+
+impl KnownExtra for SwapInfo {
+    const KNOWN_EXTRA: &'static [&'static str] = &["feeAmount", "feeMint"];
+
+    fn extra(&self) -> &serde_json::Value {
+        &self.extra
+    }
+}
+
+impl SwapInfo {
+    /// The venue's fee on this leg, in raw units of [`SwapInfo::fee_mint`]
+    pub fn fee_amount(&self) -> Option<u64> {
+        self.extra.get("feeAmount")?.as_str()?.parse().ok()
+    }
+
+    pub fn fee_mint(&self) -> Option<&str> {
+        self.extra.get("feeMint")?.as_str()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SwapResponse {
+    #[serde(rename = "swapTransaction")]
+    pub swap_transaction: String,
+    #[serde(rename = "lastValidBlockHeight")]
+    pub last_valid_block_height: u64,
+}
+
+/// Inputs for a single /quote -> /swap round
+#[derive(Debug, Clone)]
+pub struct SwapParams {
+    pub input_mint: String,
+    pub output_mint: String,
+    /// Amount of the input mint
+    pub amount: TokenAmount,
+    pub slippage_bps: u64,
+    /// Reject the swap if either mint fails this policy
+    #[cfg(not(target_arch = "wasm32"))]
+    pub mint_policy: Option<MintPolicy>,
+}
+
+impl Default for SwapParams {
+    /// 0.05 SOL → USDC at the configured default slippage
+    fn default() -> Self {
+        Self {
+            input_mint: WSOL_MINT.to_string(),
+            output_mint: USDC_MINT.to_string(),
+            amount: TokenAmount::new(50_000_000, 9),
+            #[cfg(not(target_arch = "wasm32"))]
+            slippage_bps: default_slippage_bps(),
+            #[cfg(target_arch = "wasm32")]
+            slippage_bps: DEFAULT_SLIPPAGE_BPS,
+            #[cfg(not(target_arch = "wasm32"))]
+            mint_policy: None,
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Serialize, Debug, Clone)]
+pub struct SwapResult {
+    pub signature: String,
+    /// Input amount from the quote
+    pub in_amount: TokenAmount,
+    /// Quoted output amount
+    pub out_amount: TokenAmount,
+    /// `out_amount` net of the output mint's transfer fee, if it charges one
+    pub received_amount: TokenAmount,
+}
+
+/// Quote `params` without an integrator fee
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn get_quote(http: &Client, params: &SwapParams) -> Result<QuoteResponse> {
+    fetch_quote(http, params, None).await
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn fetch_quote(http: &Client, params: &SwapParams, platform_fee_bps: Option<u64>) -> Result<QuoteResponse> {
+    let quote_url = format!("{}/quote?{}", api_url(JupiterApi::Swap), quote_query(params, platform_fee_bps));
+    let quote = http.get(&quote_url).with_jupiter_key().send_with_retry().await?.api_json().await?;
+    record_quote();
+    Ok(quote)
+}
+
+/// Query string for /quote, without the leading `?`
+pub(crate) fn quote_query(params: &SwapParams, platform_fee_bps: Option<u64>) -> String {
+    let fee_q = platform_fee_bps
+        .map(|bps| format!("&platformFeeBps={}", bps))
+        .unwrap_or_default();
+    format!(
+        "inputMint={}&outputMint={}&amount={}&slippageBps={}{}",
+        params.input_mint, params.output_mint, params.amount.raw, params.slippage_bps, fee_q
+    )
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn swap_flow(kp: &dyn TxSigner) -> Result<()> {
+    swap_with_params(kp, SwapParams::default()).await?;
+    Ok(())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn swap_with_params(kp: &dyn TxSigner, params: SwapParams) -> Result<SwapResult> {
+    let cfg = Config::load()?;
+    let rpc = rpc_client(&cfg);
+    let (quote, fee) = quote_for_swap(&cfg, &rpc, &params).await?;
+    execute_quote(kp, &cfg, &rpc, &quote, fee).await
+}
+
+/// The quote [`swap_with_params`] would execute: mint policy enforced and the
+/// integrator fee included when its account exists. Show it, then pass it to
+/// [`swap_with_quote`] to swap exactly what was shown.
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn swap_quote(params: &SwapParams) -> Result<QuoteResponse> {
+    let cfg = Config::load()?;
+    Ok(quote_for_swap(&cfg, &rpc_client(&cfg), params).await?.0)
+}
+
+/// Build, sign, send and confirm a quote from [`swap_quote`]
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn swap_with_quote(kp: &dyn TxSigner, quote: &QuoteResponse) -> Result<SwapResult> {
+    let cfg = Config::load()?;
+    // the fee account was checked when the quote was made with a fee
+    let fee = quote.platformFee.as_ref().and(integrator_fee());
+    execute_quote(kp, &cfg, &rpc_client(&cfg), quote, fee).await
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn quote_for_swap(
+    cfg: &Config,
+    rpc: &RpcClient,
+    params: &SwapParams,
+) -> Result<(QuoteResponse, Option<(String, u64)>)> {
+    if let Some(policy) = &params.mint_policy {
+        let mints = [Pubkey::from_str(&params.input_mint)?, Pubkey::from_str(&params.output_mint)?];
+        policy.enforce(rpc, &mints).await?;
+    }
+    let fee = checked_integrator_fee(rpc).await;
+    let quote = JupiterClient::new(cfg)?.swap().quote_with_fee(params, fee.as_ref().map(|(_, bps)| *bps)).await?;
+    Ok((quote, fee))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn execute_quote(
+    kp: &dyn TxSigner,
+    cfg: &Config,
+    rpc: &RpcClient,
+    quote: &QuoteResponse,
+    fee: Option<(String, u64)>,
+) -> Result<SwapResult> {
+    mainnet_guard(rpc).await?;
+    let jup = JupiterClient::new(cfg)?;
+    let user_pubkey = kp.address().to_string();
+
+    // 1. Amounts, net of any transfer fee on the output
+    let in_amount = TokenAmount::fetch(rpc, &quote.inputMint, quote.inAmount.parse()?).await?;
+    let received = warn_transfer_fee(rpc, &Pubkey::from_str(&quote.outputMint)?, quote.outAmount.parse()?).await?;
+
+    // 2. Build swap transaction
+    let mut extra = json!({});
+    if let Some((acc, _)) = fee {
+        extra["feeAccount"] = acc.into();
+    }
+    if let Some(priority) = cfg.priority_fee.to_swap_param() {
+        extra["prioritizationFeeLamports"] = priority;
+    }
+    if let Some(payer) = kp.fee_payer() {
+        extra["payer"] = payer.address().to_string().into();
+    }
+    let swap_resp = jup.swap().swap_transaction(quote, &user_pubkey, extra).await?;
+
+    // 3. Decode, sign, and send via RPC
+    safety::pre_trade_check(&quote.outputMint).await?;
+    let mut tx: VersionedTransaction = deserialize(&decode(&swap_resp.swap_transaction)?)?;
+    sign_versioned_tx(&mut tx, kp).await?;
+    let signature = send_and_confirm(rpc, &tx, rpc.commitment()).await?;
+    let result = SwapResult {
+        signature: signature.to_string(),
+        in_amount,
+        out_amount: received.gross,
+        received_amount: received.net,
+    };
+    emit(
+        "swap",
+        &json!({
+            "signature": result.signature,
+            "inputMint": quote.inputMint,
+            "outputMint": quote.outputMint,
+            "inAmount": quote.inAmount,
+            "outAmount": quote.outAmount,
+            "receivedAmount": received.net.raw.to_string(),
+            "otherAmountThreshold": quote.otherAmountThreshold,
+            "slippageBps": quote.slippageBps,
+            "priceImpactPct": quote.priceImpactPct,
+            "explorerUrl": tx_link(signature),
+        }),
+        format!(
+            "Swap confirmed: {in_amount} {} → {} {} ({})",
+            mint_label(&quote.inputMint),
+            received.net,
+            mint_label(&quote.outputMint),
+            tx_link(signature),
+        ),
+    );
+
+    Ok(result)
+}
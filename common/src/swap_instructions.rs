@@ -0,0 +1,234 @@
+// common/src/swap_instructions.rs
+use crate::{
+    checked_integrator_fee, emit, fee_payer_address, get_nonce, latest_blockhash, mainnet_guard, nonce_account,
+    rpc_client, safety, send_and_confirm, send_nonce_transaction, sign_versioned_tx, try_http_client, tx_link,
+    with_advance_nonce, ApiResponseExt, Config, Error, JupiterApi, JupiterReqExt, Result, TxSigner, WSOL_MINT,
+};
+use serde::Deserialize;
+use serde_json::json;
+use solana_program::address_lookup_table::state::AddressLookupTable;
+use solana_sdk::{
+    address_lookup_table_account::AddressLookupTableAccount,
+    instruction::{AccountMeta, Instruction},
+    message::{v0::Message, VersionedMessage},
+    pubkey::Pubkey,
+    transaction::VersionedTransaction,
+};
+use std::str::FromStr;
+
+// ────────── Swap Instructions Flow (/swap/v1/swap-instructions → build & send) ──────────
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(untagged)]
+enum Ci {
+    Json(InstructionJson),
+    B64(String), 
+}
+
+#[derive(Deserialize, Debug, Clone)]
+struct InstructionJson {
+    #[serde(rename = "programId")]
+    program_id: String,
+    accounts: Vec<AccountMetaJson>,
+    data: String, // base-64
+}
+
+#[derive(Deserialize, Debug, Clone)]
+struct AccountMetaJson {
+    pubkey: String,
+    isSigner: bool,
+    isWritable: bool,
+}
+
+impl InstructionJson {
+    fn to_instruction(&self) -> Result<Instruction, anyhow::Error> {
+        let program_id = Pubkey::from_str(&self.program_id)?;
+        let accounts = self
+            .accounts
+            .iter()
+            .map(|a| {
+                let pk = Pubkey::from_str(&a.pubkey)?;
+                Ok(if a.isWritable {
+                    AccountMeta::new(pk, a.isSigner)
+                } else {
+                    AccountMeta::new_readonly(pk, a.isSigner)
+                })
+            })
+            .collect::<Result<Vec<_>, anyhow::Error>>()?;
+
+        Ok(Instruction {
+            program_id,
+            data: base64::decode(&self.data)?,
+            accounts,
+        })
+    }
+}
+
+impl Ci {
+    fn to_instruction(&self) -> Result<Instruction, anyhow::Error> {
+        match self {
+            Ci::Json(j) => j.to_instruction(),
+            Ci::B64(_) => anyhow::bail!(
+                "legacy CompiledInstruction returned – \
+                 re-issue the API call with \"instructionFormat\":\"json\""
+            ),
+        }
+    }
+}
+
+// ───────────────────────── response struct ─────────────────────────
+/// Body of /swap/v1/swap-instructions, requested with `"instructionFormat": "json"`
+#[derive(Deserialize, Debug)]
+pub struct SwapInstructionResponse {
+    #[serde(default, rename = "tokenLedgerInstruction")]
+    token_ledger_instruction: Option<Ci>,
+
+    #[serde(default, rename = "computeBudgetInstructions")]
+    compute_budget_instructions: Option<Vec<Ci>>,
+
+    #[serde(default, rename = "setupInstructions")]
+    setup_instructions: Option<Vec<Ci>>,
+
+    #[serde(default, rename = "swapInstruction")]
+    swap_instruction: Option<Ci>,
+
+    
+    #[serde(default, rename = "cleanupInstruction")]
+    cleanup_instruction: Option<Ci>,
+
+    #[serde(default, rename = "addressLookupTableAddresses")]
+    pub address_lookup_table_addresses: Option<Vec<String>>,
+}
+
+impl SwapInstructionResponse {
+    /// Every instruction in execution order: token ledger, compute budget, setup, swap, cleanup
+    pub fn instructions(&self) -> Result<Vec<Instruction>, anyhow::Error> {
+        let mut ix: Vec<Instruction> = Vec::new();
+
+        if let Some(ci)  = &self.token_ledger_instruction  { ix.push(ci.to_instruction()?); }
+        if let Some(lst) = &self.compute_budget_instructions { for ci in lst { ix.push(ci.to_instruction()?); } }
+        if let Some(lst) = &self.setup_instructions        { for ci in lst { ix.push(ci.to_instruction()?); } }
+        if let Some(ci)  = &self.swap_instruction          { ix.push(ci.to_instruction()?); }
+        if let Some(ci)  = &self.cleanup_instruction       { ix.push(ci.to_instruction()?); }
+        Ok(ix)
+    }
+}
+
+// ───────────────────────────────── flow ────────────────────────────
+pub async fn swap_instruction_flow(kp: &dyn TxSigner) -> Result<()> {
+    let cfg  = Config::load()?;
+    let http = try_http_client()?;
+    let rpc  = rpc_client(&cfg);
+    mainnet_guard(&rpc).await?;
+
+    // ─────────── /quote ─────────────────────────────────────────────
+    let fee = checked_integrator_fee(&rpc).await;
+    let fee_q = fee.as_ref()
+        .map(|(_, bps)| format!("&platformFeeBps={}", bps))
+        .unwrap_or_default();
+
+    let quote_url = format!(
+        concat!(
+            "{}/quote",
+            "?inputMint={}&outputMint={}",
+            "&amount=1000000",
+            "&slippageBps={}{}"
+        ),
+        cfg.api_url(JupiterApi::Swap),
+        "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",     // input mint
+        "So11111111111111111111111111111111111111112",      // output mint
+        cfg.slippage_bps,
+        fee_q
+    );
+
+    let quote: serde_json::Value = http
+        .get(quote_url)
+        .with_jupiter_key()
+        .send_with_retry()
+        .await?
+        .api_json()
+        .await?;
+
+    // ─────────── /swap-instructions ─────────────────────────────────
+    let user_pubkey = kp.address().to_string();
+    let mut body = json!({
+        "quoteResponse": quote,
+        "userPublicKey": user_pubkey,
+        "payer": fee_payer_address(kp).to_string(), // the user, unless a sponsor covers fees
+        "instructionFormat": "json",
+    });
+    if let Some(priority) = cfg.priority_fee.to_swap_param() {
+        body["prioritizationFeeLamports"] = priority;
+    }
+    if let Some((acc, _)) = fee {
+        body["feeAccount"] = acc.into();
+    }
+
+    let resp: SwapInstructionResponse = http
+        .post(format!("{}/swap-instructions", cfg.api_url(JupiterApi::Swap)))
+        .with_jupiter_key()
+        .json(&body)
+        .send_with_retry()
+        .await?
+        .api_json()
+        .await?;
+
+
+    // decode every Instruction ----------------------------------------------
+    let ix = resp.instructions()?;
+
+    if ix.is_empty() {
+        return Err(Error::Api {
+            status: None,
+            message: "swap-instructions API returned no instructions – check amount/slippage".into(),
+        });
+    }
+
+    // fetch & build ALT accounts --------------------------------------------
+    let mut alts: Vec<AddressLookupTableAccount> = Vec::new();
+    if let Some(addrs) = resp.address_lookup_table_addresses {
+        for addr in addrs {
+            let key = Pubkey::from_str(&addr)?;
+            if let Ok(raw) = rpc.get_account(&key).await {
+                if let Ok(table) = AddressLookupTable::deserialize(&raw.data) {
+                    alts.push(AddressLookupTableAccount {
+                        key,
+                        addresses: table.addresses.to_vec(),
+                    });
+                }
+            }
+        }
+    }
+
+    // compile message & send -------------------------------------------------
+    safety::pre_trade_check(WSOL_MINT).await?;
+    let payer            = fee_payer_address(kp);  // Sponsor if configured, else the main account
+    // NONCE_ACCOUNT: build on the durable nonce (authority = the user) so signing can take its time
+    let nonce = match nonce_account()? {
+        Some(addr) => Some(get_nonce(&rpc, &addr).await?),
+        None => None,
+    };
+    let (ix, recent_blockhash) = match &nonce {
+        Some(n) => (with_advance_nonce(&ix, &n.address, &kp.address()), n.blockhash),
+        None => (ix, latest_blockhash(&rpc, rpc.commitment()).await?),
+    };
+    let msg              = Message::try_compile(&payer, &ix, &alts, recent_blockhash).map_err(anyhow::Error::from)?;
+    let versioned        = VersionedMessage::V0(msg);
+    let mut tx           = VersionedTransaction { signatures: vec![], message: versioned };
+    sign_versioned_tx(&mut tx, kp).await?;  // Sign as user, and as payer when sponsored
+
+    let sig = match &nonce {
+        Some(n) => send_nonce_transaction(&rpc, &tx, n, rpc.commitment()).await?,
+        None => send_and_confirm(&rpc, &tx, rpc.commitment()).await?,
+    };
+    emit(
+        "swap_instructions",
+        &json!({
+            "signature": sig.to_string(),
+            "explorerUrl": tx_link(sig),
+            "nonce": nonce.map(|n| n.address.to_string()),
+        }),
+        format!("swap-instructions tx confirmed: {}", tx_link(sig)),
+    );
+    Ok(())
+}
@@ -0,0 +1,404 @@
+// common/src/tokens.rs
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+#[cfg(not(target_arch = "wasm32"))]
+use {
+    crate::cache::CacheCounters,
+    crate::{api_url, ApiResponseExt, CacheStats, JupiterApi, JupiterReqExt},
+    anyhow::Result,
+    reqwest::Client,
+    solana_sdk::pubkey::Pubkey,
+    std::path::PathBuf,
+    std::str::FromStr,
+    std::sync::RwLock,
+    std::time::Duration,
+};
+
+// ─────────────────── Token API (/tokens/v2) ───────────────────
+
+/// Result of Jupiter's token audit
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TokenAudit {
+    pub mint_authority_disabled: Option<bool>,
+    pub freeze_authority_disabled: Option<bool>,
+    pub top_holders_percentage: Option<f64>,
+}
+
+/// Trading stats over one window (e.g. `stats24h`)
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TokenStats {
+    pub price_change: Option<f64>,
+    pub buy_volume: Option<f64>,
+    pub sell_volume: Option<f64>,
+    pub num_buys: Option<u64>,
+    pub num_sells: Option<u64>,
+}
+
+/// First liquidity pool created for a token
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FirstPool {
+    /// Pool address
+    pub id: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Token metadata as returned by the Token API
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Token {
+    /// Mint address
+    #[serde(rename = "id", alias = "address")]
+    pub mint: String,
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+    #[serde(default, rename = "icon", alias = "logoURI")]
+    pub logo_uri: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub is_verified: Option<bool>,
+    #[serde(default)]
+    pub token_program: Option<String>,
+    #[serde(default)]
+    pub usd_price: Option<f64>,
+    #[serde(default)]
+    pub audit: Option<TokenAudit>,
+    #[serde(default, rename = "stats24h")]
+    pub stats_24h: Option<TokenStats>,
+    #[serde(default)]
+    pub first_pool: Option<FirstPool>,
+}
+
+impl Token {
+    /// USD volume over the last 24h (buys + sells)
+    pub fn daily_volume(&self) -> Option<f64> {
+        let stats = self.stats_24h.as_ref()?;
+        match (stats.buy_volume, stats.sell_volume) {
+            (None, None) => None,
+            (buy, sell) => Some(buy.unwrap_or(0.0) + sell.unwrap_or(0.0)),
+        }
+    }
+
+    /// `Some(true)` when the mint can still be minted into
+    pub fn has_mint_authority(&self) -> Option<bool> {
+        self.audit.as_ref()?.mint_authority_disabled.map(|d| !d)
+    }
+
+    /// `Some(true)` when token accounts can still be frozen
+    pub fn has_freeze_authority(&self) -> Option<bool> {
+        self.audit.as_ref()?.freeze_authority_disabled.map(|d| !d)
+    }
+
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t == tag)
+    }
+
+    /// When the token's first pool was created
+    pub fn created_at(&self) -> Option<DateTime<Utc>> {
+        self.first_pool.as_ref().map(|p| p.created_at)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+/// Search tokens by symbol, name or mint
+pub async fn search_tokens(http: &Client, query: &str) -> Result<Vec<Token>> {
+    let req = http.get(format!("{}/search", api_url(JupiterApi::Tokens))).query(&[("query", query)]);
+    Ok(req.with_jupiter_key().send_with_retry().await?.api_json().await?)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+/// Metadata for `mint`, `None` if the Token API doesn't know it
+pub async fn get_token(http: &Client, mint: &str) -> Result<Option<Token>> {
+    Ok(search_tokens(http, mint).await?.into_iter().find(|t| t.mint == mint))
+}
+
+
+// ─────────────────── Tagged token lists (/tokens/v2/tag) ───────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenTag {
+    Verified,
+    /// Liquid staking tokens
+    Lst,
+}
+
+impl TokenTag {
+    pub(crate) fn as_api(&self) -> &'static str {
+        match self {
+            TokenTag::Verified => "verified",
+            TokenTag::Lst      => "lst",
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+/// Every token carrying `tag`
+pub async fn get_tagged_tokens(http: &Client, tag: TokenTag) -> Result<Vec<Token>> {
+    let req = http.get(format!("{}/tag", api_url(JupiterApi::Tokens))).query(&[("query", tag.as_api())]);
+    Ok(req.with_jupiter_key().send_with_retry().await?.api_json().await?)
+}
+
+/// In-memory lookup over a token list by mint and by (case-insensitive) symbol
+#[derive(Debug, Clone, Default)]
+pub struct TokenIndex {
+    by_mint: HashMap<String, Token>,
+    by_symbol: HashMap<String, Vec<String>>,
+}
+
+impl TokenIndex {
+    pub fn new(tokens: impl IntoIterator<Item = Token>) -> Self {
+        let mut index = Self::default();
+        index.extend(tokens);
+        index
+    }
+
+    /// Index of the tokens carrying `tag`
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn fetch(http: &Client, tag: TokenTag) -> Result<Self> {
+        Ok(Self::new(get_tagged_tokens(http, tag).await?))
+    }
+
+    pub fn extend(&mut self, tokens: impl IntoIterator<Item = Token>) {
+        for token in tokens {
+            let mints = self.by_symbol.entry(token.symbol.to_uppercase()).or_default();
+            if !mints.contains(&token.mint) {
+                mints.push(token.mint.clone());
+            }
+            self.by_mint.insert(token.mint.clone(), token);
+        }
+    }
+
+    pub fn get(&self, mint: &str) -> Option<&Token> {
+        self.by_mint.get(mint)
+    }
+
+    /// All indexed tokens using `symbol`; more than one means the symbol is ambiguous
+    pub fn by_symbol(&self, symbol: &str) -> Vec<&Token> {
+        self.by_symbol
+            .get(&symbol.to_uppercase())
+            .into_iter()
+            .flatten()
+            .filter_map(|mint| self.by_mint.get(mint))
+            .collect()
+    }
+
+    pub fn contains(&self, mint: &str) -> bool {
+        self.by_mint.contains_key(mint)
+    }
+
+    pub fn len(&self) -> usize {
+        self.by_mint.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.by_mint.is_empty()
+    }
+
+    pub fn tokens(&self) -> impl Iterator<Item = &Token> {
+        self.by_mint.values()
+    }
+}
+
+
+// ─────────────────── Recently created tokens (/tokens/v2/recent) ───────────────────
+
+#[cfg(not(target_arch = "wasm32"))]
+/// Pages through tokens ordered by first-pool creation, newest first
+pub struct RecentTokens {
+    http: Client,
+    limit: usize,
+    offset: usize,
+    done: bool,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl RecentTokens {
+    pub fn new(http: Client, page_size: usize) -> Self {
+        Self { http, limit: page_size.max(1), offset: 0, done: false }
+    }
+
+    /// The next page; empty once the feed is exhausted
+    pub async fn next_page(&mut self) -> Result<Vec<Token>> {
+        if self.done {
+            return Ok(Vec::new());
+        }
+        let url = format!("{}/recent?limit={}&offset={}", api_url(JupiterApi::Tokens), self.limit, self.offset);
+        let page: Vec<Token> = self.http.get(&url).with_jupiter_key().send_with_retry().await?.api_json().await?;
+        self.offset += page.len();
+        // a short page is the last one
+        self.done = page.len() < self.limit;
+        Ok(page)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+/// The newest `limit` tokens
+pub async fn get_recent_tokens(http: &Client, limit: usize) -> Result<Vec<Token>> {
+    RecentTokens::new(http.clone(), limit).next_page().await
+}
+
+
+// ─────────────────── Symbol → mint resolution ───────────────────
+
+impl Token {
+    pub fn is_verified(&self) -> bool {
+        self.is_verified == Some(true) || self.has_tag("verified")
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+/// Resolves "JUP"-style symbols to mints using the verified list first and
+/// token search as a fallback
+pub struct TokenResolver {
+    http: Client,
+    verified: TokenIndex,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl TokenResolver {
+    /// Loads the verified token list once
+    pub async fn new(http: Client) -> Result<Self> {
+        let verified = TokenIndex::fetch(&http, TokenTag::Verified).await?;
+        Ok(Self { http, verified })
+    }
+
+    /// Mint for `symbol_or_mint`. Base58 mints pass through unchanged; a symbol
+    /// shared by several tokens is an error unless exactly one is verified.
+    pub async fn resolve(&self, symbol_or_mint: &str) -> Result<String> {
+        let query = symbol_or_mint.trim();
+        if Pubkey::from_str(query).is_ok() {
+            return Ok(query.to_string());
+        }
+
+        if let [token] = self.verified.by_symbol(query).as_slice() {
+            return Ok(token.mint.clone());
+        }
+
+        let matches: Vec<Token> = search_tokens(&self.http, query)
+            .await?
+            .into_iter()
+            .filter(|t| t.symbol.eq_ignore_ascii_case(query))
+            .collect();
+        pick_unambiguous(query, matches)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn pick_unambiguous(symbol: &str, matches: Vec<Token>) -> Result<String> {
+    if matches.len() == 1 {
+        return Ok(matches[0].mint.clone());
+    }
+    if matches.is_empty() {
+        anyhow::bail!("no token with symbol {symbol:?}");
+    }
+    let verified: Vec<&Token> = matches.iter().filter(|t| t.is_verified()).collect();
+    if let [token] = verified.as_slice() {
+        return Ok(token.mint.clone());
+    }
+    let candidates: Vec<&str> = matches.iter().map(|t| t.mint.as_str()).collect();
+    anyhow::bail!(
+        "symbol {symbol:?} is ambiguous ({} verified of {} matches): {}",
+        verified.len(),
+        matches.len(),
+        candidates.join(", ")
+    )
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+/// One-off resolution without keeping the verified list around
+pub async fn resolve_mint(http: &Client, symbol_or_mint: &str) -> Result<String> {
+    TokenResolver::new(http.clone()).await?.resolve(symbol_or_mint).await
+}
+
+
+// ─────────────────── Token metadata cache ───────────────────
+
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct CachedToken {
+    token: Token,
+    fetched_at: DateTime<Utc>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+/// TTL cache in front of [`get_token`], optionally persisted to a JSON file so
+/// repeated runs don't re-fetch the same mints
+pub struct TokenCache {
+    http: Client,
+    ttl: Duration,
+    entries: RwLock<HashMap<String, CachedToken>>,
+    path: Option<PathBuf>,
+    counters: CacheCounters,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl TokenCache {
+    pub fn new(http: Client, ttl: Duration) -> Self {
+        Self { http, ttl, entries: RwLock::new(HashMap::new()), path: None, counters: CacheCounters::default() }
+    }
+
+    /// Load entries from `path` (if it exists) and write back after every fetch
+    pub fn with_persistence(mut self, path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        if path.exists() {
+            let loaded: HashMap<String, CachedToken> = serde_json::from_slice(&std::fs::read(&path)?)?;
+            *self.entries.get_mut().unwrap() = loaded;
+        }
+        self.path = Some(path);
+        Ok(self)
+    }
+
+    fn fresh(&self, mint: &str) -> Option<Token> {
+        let entries = self.entries.read().unwrap();
+        let cached = entries.get(mint)?;
+        let age = (Utc::now() - cached.fetched_at).to_std().unwrap_or_default();
+        (age < self.ttl).then(|| cached.token.clone())
+    }
+
+    /// Cached metadata for `mint`, fetching it when missing or expired
+    pub async fn get(&self, mint: &str) -> Result<Option<Token>> {
+        let fresh = self.fresh(mint);
+        self.counters.record(fresh.is_some());
+        if let Some(token) = fresh {
+            return Ok(Some(token));
+        }
+        let Some(token) = get_token(&self.http, mint).await? else {
+            return Ok(None);
+        };
+        self.entries
+            .write()
+            .unwrap()
+            .insert(mint.to_string(), CachedToken { token: token.clone(), fetched_at: Utc::now() });
+        self.save()?;
+        Ok(Some(token))
+    }
+
+    pub async fn decimals(&self, mint: &str) -> Result<u8> {
+        self.get(mint)
+            .await?
+            .map(|t| t.decimals)
+            .ok_or_else(|| anyhow::anyhow!("unknown mint {mint}"))
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        self.counters.stats()
+    }
+
+    pub fn invalidate(&self, mint: &str) {
+        self.entries.write().unwrap().remove(mint);
+    }
+
+    /// Write the cache to its file; a no-op without persistence
+    pub fn save(&self) -> Result<()> {
+        if let Some(path) = &self.path {
+            let json = serde_json::to_vec_pretty(&*self.entries.read().unwrap())?;
+            std::fs::write(path, json)?;
+        }
+        Ok(())
+    }
+}
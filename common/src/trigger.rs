@@ -0,0 +1,244 @@
+// common/src/trigger.rs
+use crate::{
+    emit, fee_payer_address, integrator_fee, mainnet_guard, progress, rpc_client, safety, sign_versioned_tx, tx_link,
+    Config, Error, JupiterClient, KnownExtra, Result, TokenAmount, TxSigner, USDC_MINT, WSOL_MINT,
+};
+use base64::{decode, encode};
+use bincode::{deserialize, serialize};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use solana_sdk::{transaction::VersionedTransaction};
+
+// ─────────────────────────────── Trigger Flow (/trigger/v1/createOrder -> /trigger/v1/execute) ──────────────────────────────
+
+
+#[derive(Deserialize, Debug)]
+pub struct CreateTriggerResponse {
+    /// Base64-encoded unsigned transaction
+    #[serde(default, rename = "transaction", alias = "tx", alias = "transactions")]
+    pub transaction: Option<String>,
+
+    /// Request ID for matching with execute
+    #[serde(default, rename = "requestId", alias = "request_id")]
+    pub request_id: Option<String>,
+
+    /// Order public key string
+    #[serde(default, rename = "order")]
+    pub order: Option<String>,
+
+    /// Any additional fields (e.g. code, error)
+    #[serde(flatten)]
+    pub extra: serde_json::Value,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ExecuteTriggerResponse {
+    pub status: String,
+    pub signature: String,
+    #[serde(flatten)] pub extra: serde_json::Value,
+}
+
+impl KnownExtra for CreateTriggerResponse {
+    const KNOWN_EXTRA: &'static [&'static str] = &["code", "error"];
+
+    fn extra(&self) -> &serde_json::Value {
+        &self.extra
+    }
+}
+
+impl KnownExtra for ExecuteTriggerResponse {
+    const KNOWN_EXTRA: &'static [&'static str] = &["code", "error"];
+
+    fn extra(&self) -> &serde_json::Value {
+        &self.extra
+    }
+}
+
+/// A limit order: sell `making_amount` of the input mint once it buys `taking_amount` of the output
+#[derive(Debug, Clone)]
+pub struct TriggerParams {
+    pub input_mint: String,
+    pub output_mint: String,
+    /// Amount of the input mint to sell
+    pub making_amount: TokenAmount,
+    /// Amount of the output mint to receive for it
+    pub taking_amount: TokenAmount,
+    /// Cancel automatically if still open at this time; `None` keeps it open until filled or cancelled
+    pub expired_at: Option<DateTime<Utc>>,
+}
+
+impl Default for TriggerParams {
+    /// 0.03 SOL for 5 USDC
+    fn default() -> Self {
+        Self {
+            input_mint: WSOL_MINT.to_string(),
+            output_mint: USDC_MINT.to_string(),
+            making_amount: TokenAmount::new(30_000_000, 9),
+            taking_amount: TokenAmount::new(5_000_000, 6),
+            expired_at: None,
+        }
+    }
+}
+
+pub async fn trigger_flow(kp: &dyn TxSigner) -> Result<()> {
+    trigger_with_params(kp, TriggerParams::default()).await?;
+    Ok(())
+}
+
+pub async fn trigger_with_params(kp: &dyn TxSigner, params: TriggerParams) -> Result<ExecuteTriggerResponse> {
+    let cfg  = Config::load()?;
+    let jup  = JupiterClient::new(&cfg)?;
+    let rpc  = rpc_client(&cfg);
+    mainnet_guard(&rpc).await?;
+    let user = kp.address().to_string();
+
+    // 1. Create order ---------------------------------------------------------
+    let mut create_body = json!({
+        "inputMint":  params.input_mint,
+        "outputMint": params.output_mint,
+        "maker":      user,
+        "payer":      fee_payer_address(kp).to_string(),
+        "params": {
+            "makingAmount": params.making_amount.raw.to_string(),
+            "takingAmount": params.taking_amount.raw.to_string()
+        }
+    });
+    if let Some((_, bps)) = integrator_fee() {
+        create_body["params"]["feeBps"] = bps.into();
+    }
+    if let Some(at) = params.expired_at {
+        create_body["params"]["expiredAt"] = at.timestamp().to_string().into();
+    }
+
+    let create_resp = jup.trigger().create_order(&create_body).await?;
+
+    // 2. Decode, sign, execute -------------------------------------------------
+    if create_resp.transaction.as_deref().unwrap_or("").is_empty() {
+        return Err(Error::Api {
+            status: None,
+            message: format!("createOrder returned no transaction: {}", create_resp.extra),
+        });
+    }
+    safety::pre_trade_check(&params.output_mint).await?;
+    let tx_b64 = create_resp.transaction.as_ref().unwrap();
+    let mut tx: VersionedTransaction = deserialize(&decode(tx_b64)?)?;
+    sign_versioned_tx(&mut tx, kp).await?;
+    let signed = encode(&serialize(&tx)?);
+
+    let request_id = create_resp.request_id.as_deref().unwrap_or_default();
+    let exec_resp = jup.trigger().execute(&signed, request_id).await?;
+
+    emit("trigger", &exec_resp, format!("Trigger execute: {exec_resp:#?}\n{}", tx_link(&exec_resp.signature)));
+    Ok(exec_resp)
+}
+
+// ─────────────────── Open orders & cancel (/trigger/v1/getTriggerOrders, /cancelOrder(s)) ───────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriggerOrderStatus {
+    /// Open, possibly partly filled
+    Active,
+    /// Filled, cancelled or expired
+    History,
+}
+
+impl TriggerOrderStatus {
+    pub(crate) fn as_api(&self) -> &'static str {
+        match self {
+            TriggerOrderStatus::Active  => "active",
+            TriggerOrderStatus::History => "history",
+        }
+    }
+}
+
+/// Limit order as returned by getTriggerOrders. Amounts are UI (decimal)
+/// strings, the `raw_*` fields carry base units.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TriggerOrder {
+    pub user_pubkey: String,
+    pub order_key: String,
+    pub input_mint: String,
+    pub output_mint: String,
+    pub making_amount: String,
+    pub taking_amount: String,
+    pub remaining_making_amount: String,
+    pub remaining_taking_amount: String,
+    pub raw_making_amount: String,
+    pub raw_taking_amount: String,
+    pub raw_remaining_making_amount: String,
+    pub raw_remaining_taking_amount: String,
+    /// Unix seconds, as a string; `None` when the order never expires
+    pub expired_at: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+    /// "Open", "Completed", "Cancelled", …
+    pub status: String,
+    pub open_tx: String,
+    pub close_tx: String,
+}
+
+impl TriggerOrder {
+    /// Output received per input sold, in UI units
+    pub fn limit_price(&self) -> Option<f64> {
+        let making: f64 = self.making_amount.parse().ok()?;
+        let taking: f64 = self.taking_amount.parse().ok()?;
+        (making > 0.0).then(|| taking / making)
+    }
+
+    pub fn expires_at(&self) -> Option<DateTime<Utc>> {
+        DateTime::from_timestamp(self.expired_at.as_deref()?.parse().ok()?, 0)
+    }
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub(crate) struct TriggerOrdersResponse {
+    pub orders: Vec<TriggerOrder>,
+    pub total_pages: u32,
+    pub page: u32,
+}
+
+/// Unsigned transaction(s) from cancelOrder (one) or cancelOrders (one per batch of orders)
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelTriggerResponse {
+    #[serde(default)]
+    pub transaction: Option<String>,
+    #[serde(default)]
+    pub transactions: Vec<String>,
+    pub request_id: String,
+}
+
+impl CancelTriggerResponse {
+    /// Every transaction to sign, whichever endpoint answered
+    pub fn into_transactions(self) -> Vec<String> {
+        self.transaction.into_iter().chain(self.transactions).collect()
+    }
+}
+
+/// Cancel `orders` of the signer, or every open one when `orders` is empty,
+/// returning one execute response per cancel transaction
+pub async fn cancel_trigger_orders_flow(kp: &dyn TxSigner, orders: &[String]) -> Result<Vec<ExecuteTriggerResponse>> {
+    let cfg = Config::load()?;
+    mainnet_guard(&rpc_client(&cfg)).await?;
+    let trigger = JupiterClient::new(&cfg)?.trigger();
+    let maker = kp.address().to_string();
+
+    let resp = match orders {
+        [order] => trigger.cancel_order(&maker, order).await?,
+        _ => trigger.cancel_orders(&maker, orders).await?,
+    };
+    let request_id = resp.request_id.clone();
+    let mut executed = Vec::new();
+    for tx_b64 in resp.into_transactions() {
+        let mut tx: VersionedTransaction = deserialize(&decode(&tx_b64)?)?;
+        sign_versioned_tx(&mut tx, kp).await?;
+        let exec_resp = trigger.execute(&encode(&serialize(&tx)?), &request_id).await?;
+        progress(format!("Trigger cancel: {} ({})", exec_resp.status, tx_link(&exec_resp.signature)));
+        executed.push(exec_resp);
+    }
+    emit("trigger_cancel", &executed, format!("Cancelled with {} transaction(s)", executed.len()));
+    Ok(executed)
+}
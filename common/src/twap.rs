@@ -0,0 +1,219 @@
+// common/src/twap.rs
+use crate::{
+    default_slippage_bps, emit, get_quote, load_decimals, mint_label, progress, rpc_client, swap_with_params,
+    try_http_client, ui_amount, Config, Error, Result, SwapParams, TokenAmount, TxSigner, USDC_MINT, WSOL_MINT,
+};
+use serde::Serialize;
+use std::time::Duration;
+
+// ─────────────────── TWAP executor ───────────────────
+
+/// Shortest gap between slices; each one is a quote plus a confirmed swap
+pub const MIN_TWAP_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Split `total_amount` into `slices` equal swaps spread evenly over `window`
+#[derive(Debug, Clone)]
+pub struct TwapParams {
+    pub input_mint: String,
+    pub output_mint: String,
+    /// Total of the input mint
+    pub total_amount: TokenAmount,
+    pub slices: u32,
+    pub window: Duration,
+    /// Slippage tolerance of each slice
+    pub slippage_bps: u64,
+    /// Skip a slice when its quote is this much worse than the arrival quote
+    pub max_deviation_bps: f64,
+}
+
+impl Default for TwapParams {
+    /// 0.2 SOL → USDC in 4 slices over an hour
+    fn default() -> Self {
+        Self {
+            input_mint: WSOL_MINT.to_string(),
+            output_mint: USDC_MINT.to_string(),
+            total_amount: TokenAmount::new(200_000_000, 9),
+            slices: 4,
+            window: Duration::from_secs(60 * 60),
+            slippage_bps: default_slippage_bps(),
+            max_deviation_bps: 100.0,
+        }
+    }
+}
+
+impl TwapParams {
+    pub fn interval(&self) -> Duration {
+        self.window / self.slices.max(1)
+    }
+
+    /// Size of slice `i`; the last slice takes the rounding remainder
+    pub fn slice_amount(&self, i: u32) -> TokenAmount {
+        let total = self.total_amount.raw;
+        let base = total / self.slices as u64;
+        let raw = if i + 1 == self.slices { total - base * (self.slices as u64 - 1) } else { base };
+        TokenAmount { raw, ..self.total_amount }
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if self.slices == 0 {
+            return Err(Error::Invalid("slices must be at least 1".into()));
+        }
+        if self.total_amount.raw < self.slices as u64 {
+            let total = self.total_amount.raw;
+            return Err(Error::Invalid(format!("total_amount {total} is smaller than the number of slices")));
+        }
+        if self.window.is_zero() {
+            return Err(Error::Invalid("window must be longer than zero".into()));
+        }
+        if self.interval() < MIN_TWAP_INTERVAL {
+            return Err(Error::Invalid(format!(
+                "{} slices over {} are less than {} apart; use fewer slices or a longer window",
+                self.slices,
+                humantime::format_duration(self.window),
+                humantime::format_duration(MIN_TWAP_INTERVAL),
+            )));
+        }
+        Ok(())
+    }
+
+    fn slice_params(&self, amount: TokenAmount) -> SwapParams {
+        SwapParams {
+            input_mint: self.input_mint.clone(),
+            output_mint: self.output_mint.clone(),
+            amount,
+            slippage_bps: self.slippage_bps,
+            mint_policy: None,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct TwapSlice {
+    pub index: u32,
+    /// Raw input of this slice
+    pub in_amount: u64,
+    /// Raw output (0 when skipped)
+    pub out_amount: u64,
+    pub signature: Option<String>,
+    /// Deviation of this slice's quote from the arrival quote, in bps (positive = worse);
+    /// `None` when the slice couldn't be quoted
+    pub deviation_bps: Option<f64>,
+    pub skipped: Option<String>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct TwapReport {
+    /// Raw output per raw input quoted before the first slice
+    pub benchmark_rate: f64,
+    /// Raw output per raw input over all executed slices
+    pub achieved_rate: Option<f64>,
+    /// Shortfall vs the benchmark in bps (positive = worse than arrival)
+    pub shortfall_bps: Option<f64>,
+    pub slices: Vec<TwapSlice>,
+}
+
+impl TwapReport {
+    pub fn executed_in(&self) -> u64 {
+        self.slices.iter().filter(|s| s.signature.is_some()).map(|s| s.in_amount).sum()
+    }
+
+    pub fn executed_out(&self) -> u64 {
+        self.slices.iter().map(|s| s.out_amount).sum()
+    }
+}
+
+fn rate(out_amount: &str, in_amount: &str) -> Result<f64> {
+    Ok(out_amount.parse::<f64>()? / in_amount.parse::<f64>()?)
+}
+
+fn deviation_bps(benchmark: f64, rate: f64) -> f64 {
+    (benchmark - rate) / benchmark * 10_000.0
+}
+
+/// Execute `params` slice by slice. The arrival quote (one slice, before
+/// anything trades) is the benchmark; slices quoting worse than
+/// `max_deviation_bps` from it are skipped rather than chased.
+pub async fn execute_twap(kp: &dyn TxSigner, params: &TwapParams) -> Result<TwapReport> {
+    params.validate()?;
+    let http = try_http_client()?;
+
+    let arrival = get_quote(&http, &params.slice_params(params.slice_amount(0))).await?;
+    let benchmark_rate = rate(&arrival.outAmount, &arrival.inAmount)?;
+
+    let mut ticker = tokio::time::interval(params.interval());
+    let mut slices = Vec::with_capacity(params.slices as usize);
+    for index in 0..params.slices {
+        ticker.tick().await;
+        let swap = params.slice_params(params.slice_amount(index));
+
+        let mut slice = TwapSlice {
+            index,
+            in_amount: swap.amount.raw,
+            out_amount: 0,
+            signature: None,
+            deviation_bps: None,
+            skipped: None,
+        };
+
+        // one slice failing to quote doesn't end the schedule, same as one failing to swap
+        match get_quote(&http, &swap).await.and_then(|quote| rate(&quote.outAmount, &quote.inAmount)) {
+            Err(e) => slice.skipped = Some(format!("quote failed: {e}")),
+            Ok(quoted) => {
+                let deviation = deviation_bps(benchmark_rate, quoted);
+                slice.deviation_bps = Some(deviation);
+                if deviation > params.max_deviation_bps {
+                    slice.skipped = Some(format!("quote {deviation:.1} bps worse than arrival"));
+                } else {
+                    match swap_with_params(kp, swap).await {
+                        Ok(res) => {
+                            slice.out_amount = res.out_amount.raw;
+                            slice.signature = Some(res.signature);
+                        }
+                        Err(e) => slice.skipped = Some(format!("swap failed: {e}")),
+                    }
+                }
+            }
+        }
+        progress(format!(
+            "slice {}/{}: {} in, {} out, {}{}",
+            index + 1,
+            params.slices,
+            slice.in_amount,
+            slice.out_amount,
+            slice.deviation_bps.map_or_else(|| "- bps".to_string(), |d| format!("{d:+.1} bps")),
+            slice.skipped.as_deref().map(|r| format!(" – skipped: {r}")).unwrap_or_default(),
+        ));
+        slices.push(slice);
+    }
+
+    let mut report = TwapReport { benchmark_rate, achieved_rate: None, shortfall_bps: None, slices };
+    let executed_in = report.executed_in();
+    if executed_in > 0 {
+        let achieved = report.executed_out() as f64 / executed_in as f64;
+        report.achieved_rate = Some(achieved);
+        report.shortfall_bps = Some(deviation_bps(benchmark_rate, achieved));
+    }
+    Ok(report)
+}
+
+pub async fn twap_flow(kp: &dyn TxSigner, params: TwapParams) -> Result<TwapReport> {
+    let cfg = Config::load()?;
+    load_decimals(&rpc_client(&cfg), &[&params.input_mint, &params.output_mint]).await.ok();
+    progress(format!(
+        "TWAP {} → {} in {} slices every {:?}",
+        ui_amount(&params.input_mint, params.total_amount.raw),
+        mint_label(&params.output_mint),
+        params.slices,
+        params.interval(),
+    ));
+    let report = execute_twap(kp, &params).await?;
+    let text = format!(
+        "executed {} of {} in, {} out; shortfall vs arrival: {}",
+        ui_amount(&params.input_mint, report.executed_in()),
+        ui_amount(&params.input_mint, params.total_amount.raw),
+        ui_amount(&params.output_mint, report.executed_out()),
+        report.shortfall_bps.map(|b| format!("{b:+.1} bps")).unwrap_or_else(|| "-".into()),
+    );
+    emit("twap", &report, text);
+    Ok(report)
+}
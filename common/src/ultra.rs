@@ -0,0 +1,115 @@
+// common/src/ultra.rs
+use crate::{
+    emit, integrator_fee, mainnet_guard, referral, rpc_client, safety, sign_versioned_tx, tx_link, Config,
+    JupiterClient, KnownExtra, Result, TokenAmount, TxSigner, USDC_MINT, WSOL_MINT,
+};
+use base64::decode;
+use bincode::deserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::{pubkey::Pubkey, transaction::VersionedTransaction};
+use std::str::FromStr;
+
+// ───────────────────────────────── Ultra Flow (/ultra/v1/order -> /ultra/v1/execute) ─────────────────────────────────
+
+#[derive(Deserialize, Debug)]
+pub struct UltraOrderResponse {
+    pub requestId: String,
+    pub transaction: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UltraExecuteResponse {
+    #[serde(default)] pub status: Option<String>,
+    #[serde(default)] pub signature: Option<String>,
+    /// slot as string from API
+    #[serde(default)] pub slot: Option<String>,
+    #[serde(flatten)] pub extra: serde_json::Value,
+}
+
+impl KnownExtra for UltraExecuteResponse {
+    const KNOWN_EXTRA: &'static [&'static str] = &[
+        "code",
+        "error",
+        "inputAmountResult",
+        "outputAmountResult",
+        "totalInputAmount",
+        "totalOutputAmount",
+        "swapEvents",
+    ];
+
+    fn extra(&self) -> &serde_json::Value {
+        &self.extra
+    }
+}
+
+/// Inputs for a single /order -> /execute round
+#[derive(Debug, Clone)]
+pub struct UltraParams {
+    pub input_mint: String,
+    pub output_mint: String,
+    /// Amount of the input mint
+    pub amount: TokenAmount,
+}
+
+impl Default for UltraParams {
+    /// 0.01 SOL → USDC
+    fn default() -> Self {
+        Self {
+            input_mint: WSOL_MINT.to_string(),
+            output_mint: USDC_MINT.to_string(),
+            amount: TokenAmount::new(10_000_000, 9),
+        }
+    }
+}
+
+pub async fn ultra_flow(kp: &dyn TxSigner) -> Result<()> {
+    ultra_with_params(kp, UltraParams::default()).await?;
+    Ok(())
+}
+
+pub async fn ultra_with_params(kp: &dyn TxSigner, params: UltraParams) -> Result<UltraExecuteResponse> {
+    let cfg = Config::load()?;
+    let jup  = JupiterClient::new(&cfg)?;
+    let rpc  = rpc_client(&cfg);
+    mainnet_guard(&rpc).await?;
+    let taker = kp.address().to_string();
+
+    let input_mint  = Pubkey::from_str(&params.input_mint)?;
+    let output_mint = Pubkey::from_str(&params.output_mint)?;
+
+    // Ultra collects the fee into the referral token account of either mint,
+    // so only pass the referral params when one of them exists
+    let mut fee_part = String::new();
+    if let Some((acc, bps)) = integrator_fee() {
+        let ready = match Pubkey::from_str(&acc) {
+            Ok(referral) => {
+                referral::referral_token_account_exists(&rpc, &referral, &input_mint).await
+                    || referral::referral_token_account_exists(&rpc, &referral, &output_mint).await
+            }
+            Err(_) => false,
+        };
+        if ready {
+            fee_part = format!("&referralAccount={}&referralFee={}", acc, bps.max(50));
+        } else {
+            eprintln!("FEE_ACCOUNT {acc} has no referral token account for either mint; continuing without a fee");
+        }
+    }
+
+    let order = jup
+        .ultra()
+        .order(&input_mint.to_string(), &output_mint.to_string(), params.amount.raw, &taker, &fee_part)
+        .await?;
+
+    safety::pre_trade_check(&output_mint.to_string()).await?;
+    let mut tx: VersionedTransaction = deserialize(&decode(&order.transaction)?)?;
+    sign_versioned_tx(&mut tx, kp).await?;
+
+    let signed_bytes = bincode::serialize(&tx)?;   // Vec<u8>
+    let signed       = base64::encode(&signed_bytes);
+
+    let exec_resp = jup.ultra().execute(&signed, &order.requestId).await?;
+
+    let link = exec_resp.signature.as_deref().map(|s| format!("\n{}", tx_link(s))).unwrap_or_default();
+    emit("ultra", &exec_resp, format!("Ultra execute: {exec_resp:#?}{link}"));
+    Ok(exec_resp)
+}
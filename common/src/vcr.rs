@@ -0,0 +1,258 @@
+// common/src/vcr.rs
+use crate::{setting, ConfigError};
+use async_trait::async_trait;
+use reqwest::{RequestBuilder, Response};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use solana_client::client_error::{ClientErrorKind, Result as ClientResult};
+use solana_client::rpc_request::RpcRequest;
+use solana_rpc_client::http_sender::HttpSender;
+use solana_rpc_client::rpc_sender::{RpcSender, RpcTransportStats};
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+// ─────────────────── Record / replay ───────────────────
+//
+// With JUPITER_VCR=record every Jupiter API call and every JSON-RPC call is
+// written to a fixture file under JUPITER_VCR_DIR; with JUPITER_VCR=replay the
+// same calls are answered from those files and nothing goes over the network,
+// so a flow recorded once can be re-run deterministically without an API key,
+// funds or connectivity. Signing is deterministic too: replay with the keypair
+// the recording was made with and even sendTransaction matches its fixture.
+//
+// Fixtures are keyed by method, path and query (not host, so lite-api and
+// api.jup.ag recordings are interchangeable) and request body; RPC fixtures by
+// method and params. Recording the same call twice keeps the latest answer.
+// Confirmation always polls in either mode, since PubSub isn't recorded.
+
+/// Where fixtures go when `JUPITER_VCR_DIR` isn't set
+pub const DEFAULT_VCR_DIR: &str = "fixtures/vcr";
+
+/// Response headers not worth keeping; the body is stored decoded
+const SKIPPED_HEADERS: [&str; 5] = ["content-length", "content-encoding", "transfer-encoding", "date", "set-cookie"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VcrMode {
+    #[default]
+    Off,
+    /// Call through and save every interaction
+    Record,
+    /// Answer from saved interactions only
+    Replay,
+}
+
+impl FromStr for VcrMode {
+    type Err = ConfigError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "off" | "0" | "false" => Ok(VcrMode::Off),
+            "record" => Ok(VcrMode::Record),
+            "replay" => Ok(VcrMode::Replay),
+            other => {
+                Err(ConfigError::Invalid { var: "JUPITER_VCR", reason: format!("{other:?} is not off, record or replay") })
+            }
+        }
+    }
+}
+
+/// `JUPITER_VCR`, off when unset. An invalid value means off here; [`crate::Config::load`] reports it.
+pub fn vcr_mode() -> VcrMode {
+    setting("JUPITER_VCR", &None).and_then(|s| s.parse().ok()).unwrap_or_default()
+}
+
+/// `JUPITER_VCR_DIR`, else [`DEFAULT_VCR_DIR`]
+pub fn vcr_dir() -> PathBuf {
+    setting("JUPITER_VCR_DIR", &None).unwrap_or_else(|| DEFAULT_VCR_DIR.to_string()).into()
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct HttpInteraction {
+    request: HttpRequest,
+    response: HttpResponse,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct HttpRequest {
+    method: String,
+    /// Path and query, without the host
+    url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body: Option<Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct HttpResponse {
+    status: u16,
+    #[serde(default)]
+    headers: BTreeMap<String, String>,
+    /// Parsed when it is JSON so fixtures stay readable, a string otherwise
+    body: Value,
+}
+
+/// Send `req` through `send`, or answer it from a fixture, per [`vcr_mode`]
+pub(crate) async fn record_or_replay<F, Fut>(req: RequestBuilder, send: F) -> reqwest::Result<Response>
+where
+    F: FnOnce(RequestBuilder) -> Fut,
+    Fut: Future<Output = reqwest::Result<Response>>,
+{
+    let mode = vcr_mode();
+    // streaming bodies can't be keyed
+    let built = match mode {
+        VcrMode::Off => None,
+        _ => req.try_clone().and_then(|r| r.build().ok()),
+    };
+    let Some(built) = built else { return send(req).await };
+
+    let url = built.url();
+    let request = HttpRequest {
+        method: built.method().to_string(),
+        url: url.query().map(|q| format!("{}?{q}", url.path())).unwrap_or_else(|| url.path().to_string()),
+        body: built.body().and_then(|b| b.as_bytes()).map(body_value),
+    };
+    let file = vcr_dir().join(http_file_name(&request));
+
+    if mode == VcrMode::Replay {
+        return Ok(match read::<HttpInteraction>(&file) {
+            Some(recorded) => to_response(&recorded.response),
+            None => to_response(&HttpResponse {
+                status: 501,
+                headers: BTreeMap::new(),
+                body: json!({
+                    "error": format!(
+                        "no recorded {} {} in {}; record it with JUPITER_VCR=record",
+                        request.method,
+                        request.url,
+                        file.display()
+                    )
+                }),
+            }),
+        });
+    }
+
+    let resp = send(req).await?;
+    let status = resp.status().as_u16();
+    let headers = resp
+        .headers()
+        .iter()
+        .filter(|(name, _)| !SKIPPED_HEADERS.contains(&name.as_str()))
+        .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+        .collect();
+    let response = HttpResponse { status, headers, body: body_value(&resp.bytes().await?) };
+    write(&file, &HttpInteraction { request, response: response.clone() });
+    Ok(to_response(&response))
+}
+
+/// [`RpcSender`] that records or replays JSON-RPC calls; [`crate::rpc_client`]
+/// uses it whenever `JUPITER_VCR` is set
+pub struct VcrSender {
+    inner: HttpSender,
+    mode: VcrMode,
+    dir: PathBuf,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct RpcInteraction {
+    method: String,
+    params: Value,
+    result: Value,
+}
+
+impl VcrSender {
+    pub fn new(url: impl ToString, mode: VcrMode) -> Self {
+        Self { inner: HttpSender::new(url), mode, dir: vcr_dir() }
+    }
+
+    /// Keep fixtures in `dir` instead of [`vcr_dir`]
+    pub fn with_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.dir = dir.into();
+        self
+    }
+}
+
+#[async_trait]
+impl RpcSender for VcrSender {
+    async fn send(&self, request: RpcRequest, params: Value) -> ClientResult<Value> {
+        let method = request.to_string();
+        let key = fnv1a(&[method.as_bytes(), params.to_string().as_bytes()]);
+        let file = self.dir.join(format!("rpc_{method}_{key:016x}.json"));
+
+        if self.mode == VcrMode::Replay {
+            return read::<RpcInteraction>(&file).map(|recorded| recorded.result).ok_or_else(|| {
+                let message = format!("no recorded {method} in {}; record it with JUPITER_VCR=record", file.display());
+                ClientErrorKind::Custom(message).into()
+            });
+        }
+        let result = self.inner.send(request, params.clone()).await?;
+        if self.mode == VcrMode::Record {
+            write(&file, &RpcInteraction { method, params, result: result.clone() });
+        }
+        Ok(result)
+    }
+
+    fn get_transport_stats(&self) -> RpcTransportStats {
+        self.inner.get_transport_stats()
+    }
+
+    fn url(&self) -> String {
+        self.inner.url()
+    }
+}
+
+fn body_value(bytes: &[u8]) -> Value {
+    if bytes.is_empty() {
+        return Value::Null;
+    }
+    serde_json::from_slice(bytes).unwrap_or_else(|_| Value::String(String::from_utf8_lossy(bytes).into_owned()))
+}
+
+fn to_response(recorded: &HttpResponse) -> Response {
+    let body = match &recorded.body {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        json => json.to_string(),
+    };
+    let mut builder = http::Response::builder().status(recorded.status);
+    for (name, value) in &recorded.headers {
+        builder = builder.header(name.as_str(), value.as_str());
+    }
+    Response::from(builder.body(body.clone()).unwrap_or_else(|_| http::Response::new(body)))
+}
+
+/// e.g. `get_swap_v1_quote_1f2e3d4c5b6a7980.json`
+fn http_file_name(request: &HttpRequest) -> String {
+    let path = request.url.split('?').next().unwrap_or_default();
+    let slug: String = path
+        .trim_matches('/')
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '_' })
+        .take(80)
+        .collect();
+    let body = request.body.as_ref().map(Value::to_string).unwrap_or_default();
+    let key = fnv1a(&[request.method.as_bytes(), request.url.as_bytes(), body.as_bytes()]);
+    format!("{}_{slug}_{key:016x}.json", request.method.to_ascii_lowercase())
+}
+
+/// FNV-1a over `parts`; unlike `DefaultHasher` it is stable across Rust releases, so fixture names are too
+fn fnv1a(parts: &[&[u8]]) -> u64 {
+    parts.iter().flat_map(|p| p.iter().chain(&[0xff])).fold(0xcbf2_9ce4_8422_2325, |hash, &b| {
+        (hash ^ b as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+fn read<T: DeserializeOwned>(file: &Path) -> Option<T> {
+    serde_json::from_slice(&std::fs::read(file).ok()?).ok()
+}
+
+fn write<T: Serialize>(file: &Path, interaction: &T) {
+    let written = file
+        .parent()
+        .map_or(Ok(()), std::fs::create_dir_all)
+        .and_then(|_| std::fs::write(file, serde_json::to_vec_pretty(interaction).unwrap_or_default()));
+    if let Err(e) = written {
+        eprintln!("vcr: could not save {}: {e}", file.display());
+    }
+}
@@ -0,0 +1,138 @@
+// common/src/web.rs
+//
+// The quote, price and token endpoints without the native stack: no RPC,
+// signing, env or jupiter.toml, only reqwest (its fetch backend on wasm32) and
+// the same request/response types the native flows decode. For a frontend:
+//
+//     cargo build -p jupiter-sdk --target wasm32-unknown-unknown \
+//         --no-default-features --features wasm,swap,price,tokens
+
+use crate::strict::decode;
+use crate::{JupiterApi, JupiterApiError, Result, StrictMode, JUPITER_API_URL, JUPITER_PRO_API_URL};
+use reqwest::{Client, RequestBuilder, Response};
+use serde::de::DeserializeOwned;
+#[cfg(feature = "price")]
+use {
+    crate::price::MAX_PRICE_IDS,
+    crate::Price,
+    std::collections::{HashMap, HashSet},
+};
+#[cfg(feature = "swap")]
+use crate::{swap::quote_query, QuoteResponse, SwapParams};
+#[cfg(feature = "tokens")]
+use crate::{Token, TokenTag};
+
+// ─────────────────── Web client ───────────────────
+
+/// Typed access to the Jupiter HTTP APIs that need no wallet or RPC
+#[derive(Clone)]
+pub struct WebClient {
+    http: Client,
+    base_url: String,
+    api_key: Option<String>,
+    strict: StrictMode,
+}
+
+impl WebClient {
+    /// Client for [`JUPITER_PRO_API_URL`] when `api_key` is set, [`JUPITER_API_URL`] otherwise
+    pub fn new(api_key: Option<String>) -> Self {
+        let base_url = if api_key.is_some() { JUPITER_PRO_API_URL } else { JUPITER_API_URL };
+        Self { http: Client::new(), base_url: base_url.to_string(), api_key, strict: StrictMode::Off }
+    }
+
+    pub fn with_http(mut self, http: Client) -> Self {
+        self.http = http;
+        self
+    }
+
+    /// Host to call instead, e.g. a backend that proxies Jupiter and keeps the key server-side
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Check responses for fields the SDK doesn't model; there is no `JUPITER_STRICT` to read here
+    pub fn with_strict(mut self, strict: StrictMode) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// `{base_url}/{path}/{endpoint}`; an endpoint that is only a query string attaches to the path
+    fn get(&self, api: JupiterApi, endpoint: &str) -> RequestBuilder {
+        let sep = if endpoint.starts_with('?') { "" } else { "/" };
+        let req = self.http.get(format!("{}/{}{}{}", self.base_url, api.path(), sep, endpoint));
+        match &self.api_key {
+            Some(key) => req.header("X-API-KEY", key),
+            None => req,
+        }
+    }
+
+    #[cfg(any(feature = "swap", feature = "price"))]
+    async fn get_json<T: DeserializeOwned + 'static>(&self, api: JupiterApi, endpoint: &str) -> Result<T> {
+        api_json(self.get(api, endpoint).send().await?, self.strict).await
+    }
+
+    /// Same query as [`crate::get_quote`]; the response can be handed to a
+    /// native /swap unchanged
+    #[cfg(feature = "swap")]
+    pub async fn quote(&self, params: &SwapParams) -> Result<QuoteResponse> {
+        self.get_json(JupiterApi::Swap, &format!("quote?{}", quote_query(params, None))).await
+    }
+
+    /// Prices keyed by mint, batched like [`crate::get_prices`]; unpriced mints are absent
+    #[cfg(feature = "price")]
+    pub async fn prices<S: AsRef<str>>(&self, mints: &[S]) -> Result<HashMap<String, Price>> {
+        let mut seen = HashSet::new();
+        let unique: Vec<&str> = mints.iter().map(|m| m.as_ref()).filter(|m| seen.insert(*m)).collect();
+
+        let mut prices = HashMap::with_capacity(unique.len());
+        for batch in unique.chunks(MAX_PRICE_IDS) {
+            // unpriced mints come back as `null`
+            let found: HashMap<String, Option<Price>> =
+                self.get_json(JupiterApi::Price, &format!("?ids={}", batch.join(","))).await?;
+            prices.extend(found.into_iter().filter_map(|(mint, p)| Some((mint, p?))));
+        }
+        Ok(prices)
+    }
+
+    #[cfg(feature = "price")]
+    pub async fn price(&self, mint: &str) -> Result<Option<Price>> {
+        Ok(self.prices(&[mint]).await?.remove(mint))
+    }
+
+    #[cfg(feature = "tokens")]
+    pub async fn search_tokens(&self, query: &str) -> Result<Vec<Token>> {
+        let req = self.get(JupiterApi::Tokens, "search").query(&[("query", query)]);
+        api_json(req.send().await?, self.strict).await
+    }
+
+    #[cfg(feature = "tokens")]
+    pub async fn token(&self, mint: &str) -> Result<Option<Token>> {
+        Ok(self.search_tokens(mint).await?.into_iter().find(|t| t.mint == mint))
+    }
+
+    #[cfg(feature = "tokens")]
+    pub async fn tagged_tokens(&self, tag: TokenTag) -> Result<Vec<Token>> {
+        let req = self.get(JupiterApi::Tokens, "tag").query(&[("query", tag.as_api())]);
+        api_json(req.send().await?, self.strict).await
+    }
+}
+
+/// The body as `T` on 2xx, the decoded error body otherwise. The native
+/// `ApiResponseExt` also records metrics and `Retry-After`, neither of which
+/// a browser exposes usefully.
+async fn api_json<T: DeserializeOwned + 'static>(resp: Response, strict: StrictMode) -> Result<T> {
+    let status = resp.status();
+    let path = resp.url().path().to_string();
+    if !status.is_success() {
+        let request_id = resp.headers().get("x-request-id").and_then(|v| v.to_str().ok()).map(str::to_string);
+        let text = resp.text().await.unwrap_or_default();
+        return Err(JupiterApiError { request_id, ..JupiterApiError::from_body(status, None, &text) }.into());
+    }
+    let bytes = resp.bytes().await?;
+    decode(&path, &bytes, strict)
+}
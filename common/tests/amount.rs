@@ -0,0 +1,83 @@
+// common/tests/amount.rs
+//
+// UI amount parsing and formatting: the conversions every whole-token
+// argument goes through on its way to a raw amount, and back for display.
+
+use jupiter_sdk::{format_ui_amount, parse_ui_amount, Error, Lamports, TokenAmount};
+
+fn rejected(ui: &str, decimals: u8) -> String {
+    match parse_ui_amount(ui, decimals) {
+        Err(Error::Invalid(msg)) => msg,
+        other => panic!("{ui:?} with {decimals} decimals: expected Error::Invalid, got {other:?}"),
+    }
+}
+
+#[test]
+fn parses_whole_and_fractional_amounts() {
+    assert_eq!(parse_ui_amount("1.5", 6).unwrap(), 1_500_000);
+    assert_eq!(parse_ui_amount("1", 9).unwrap(), 1_000_000_000);
+    assert_eq!(parse_ui_amount("0.000001", 6).unwrap(), 1);
+    assert_eq!(parse_ui_amount(".5", 2).unwrap(), 50);
+    assert_eq!(parse_ui_amount("2.", 2).unwrap(), 200);
+    assert_eq!(parse_ui_amount(" 12.50 ", 6).unwrap(), 12_500_000);
+    assert_eq!(parse_ui_amount("007", 0).unwrap(), 7);
+    assert_eq!(parse_ui_amount("0", 6).unwrap(), 0);
+    assert_eq!(parse_ui_amount("0.000", 6).unwrap(), 0);
+}
+
+#[test]
+fn parses_up_to_u64_max() {
+    assert_eq!(parse_ui_amount("18446744073709551615", 0).unwrap(), u64::MAX);
+    assert_eq!(parse_ui_amount("18446744073.709551615", 9).unwrap(), u64::MAX);
+}
+
+#[test]
+fn rejects_malformed_amounts_as_invalid_input() {
+    assert!(rejected("", 6).contains("empty"));
+    assert!(rejected(".", 6).contains("empty"));
+    assert!(rejected("-1", 6).contains("digits"));
+    assert!(rejected("1e6", 6).contains("digits"));
+    assert!(rejected("1,5", 6).contains("digits"));
+    assert!(rejected("1.2.3", 6).contains("digits"));
+    assert!(rejected("abc", 6).contains("digits"));
+}
+
+#[test]
+fn rejects_more_decimals_than_the_mint_has() {
+    assert!(rejected("1.0000001", 6).contains("more than 6 decimal places"));
+    assert!(rejected("1.5", 0).contains("more than 0 decimal places"));
+}
+
+#[test]
+fn rejects_amounts_above_u64_max() {
+    assert!(rejected("18446744073709551616", 0).contains("too large"));
+    assert!(rejected("18446744074", 9).contains("too large"));
+}
+
+#[test]
+fn formats_without_trailing_zeros() {
+    assert_eq!(format_ui_amount(1_500_000, 6), "1.5");
+    assert_eq!(format_ui_amount(1_000_000, 6), "1");
+    assert_eq!(format_ui_amount(1, 6), "0.000001");
+    assert_eq!(format_ui_amount(0, 6), "0");
+    assert_eq!(format_ui_amount(42, 0), "42");
+    assert_eq!(format_ui_amount(u64::MAX, 9), "18446744073.709551615");
+}
+
+#[test]
+fn format_and_parse_round_trip() {
+    for (raw, decimals) in [(0, 6), (1, 9), (1_500_000, 6), (123_456_789, 8), (10, 1), (u64::MAX, 18)] {
+        assert_eq!(parse_ui_amount(&format_ui_amount(raw, decimals), decimals).unwrap(), raw, "{raw} @ {decimals}");
+    }
+}
+
+#[test]
+fn token_amount_and_lamports_go_through_the_same_parser() {
+    let amount = TokenAmount::from_ui("0.25", 6).unwrap();
+    assert_eq!(amount, TokenAmount::new(250_000, 6));
+    assert_eq!(amount.to_string(), "0.25");
+    assert!(matches!(TokenAmount::from_ui("0.25 USDC", 6), Err(Error::Invalid(_))));
+
+    assert_eq!(Lamports::from_sol("0.000005").unwrap(), Lamports(5_000));
+    assert!(matches!(Lamports::from_sol("0.0000000001"), Err(Error::Invalid(_))));
+}
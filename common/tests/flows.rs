@@ -0,0 +1,502 @@
+// common/tests/flows.rs
+//
+// Every flow end-to-end against wiremock: success paths up to the simulated
+// send, Jupiter error bodies, and responses that don't have the expected shape.
+
+mod support;
+
+use jupiter_sdk::{
+    get_quote, recurring_flow, swap_instruction_flow, swap_with_params, trigger_flow, try_http_client, ultra_flow,
+    Config, Error, JupiterClient, PriceRecurringParams, RecurringError, RecurringOrderParams, RecurringOrderStatus,
+    SwapParams, TokenAmount, DRY_RUN_STATUS,
+};
+use serde_json::json;
+use solana_sdk::signature::{Signature, Signer};
+use support::{quote, unsigned_tx, Harness, MEMO_PROGRAM, USDC_MINT, WSOL_MINT};
+use wiremock::matchers::{body_partial_json, method, path, query_param};
+use wiremock::{Mock, ResponseTemplate};
+
+fn client() -> JupiterClient {
+    JupiterClient::new(&Config::load().unwrap()).unwrap()
+}
+
+fn sol_to_usdc() -> SwapParams {
+    SwapParams { slippage_bps: 50, ..SwapParams::default() }
+}
+
+async fn mount_quote(h: &Harness, out_amount: u64) {
+    Mock::given(method("GET"))
+        .and(path("/swap/v1/quote"))
+        .and(query_param("inputMint", WSOL_MINT))
+        .and(query_param("outputMint", USDC_MINT))
+        .and(query_param("amount", "50000000"))
+        .and(query_param("slippageBps", "50"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(quote(WSOL_MINT, USDC_MINT, 50_000_000, out_amount)))
+        .mount(&h.api)
+        .await;
+}
+
+// ─────────────────── /swap/v1 ───────────────────
+
+#[tokio::test]
+async fn quote_is_requested_with_pair_amount_and_slippage() {
+    let h = Harness::start().await;
+    mount_quote(&h, 7_512_345).await;
+
+    let quote = get_quote(&try_http_client().unwrap(), &sol_to_usdc()).await.unwrap();
+    assert_eq!(quote.outAmount, "7512345");
+    assert_eq!(quote.routePlan[0].swap_info.label, "Whirlpool");
+}
+
+#[tokio::test]
+async fn quote_error_body_is_decoded() {
+    let h = Harness::start().await;
+    Mock::given(path("/swap/v1/quote"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "error": "Could not find any route",
+            "errorCode": "COULD_NOT_FIND_ANY_ROUTE"
+        })))
+        .mount(&h.api)
+        .await;
+
+    match get_quote(&try_http_client().unwrap(), &sol_to_usdc()).await.unwrap_err() {
+        Error::Status(e) => {
+            assert_eq!(e.status.as_u16(), 400);
+            assert_eq!(e.code.as_deref(), Some("COULD_NOT_FIND_ANY_ROUTE"));
+            assert_eq!(e.message, "Could not find any route");
+        }
+        other => panic!("expected a status error, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn quote_rate_limit_keeps_retry_after() {
+    let h = Harness::start().await;
+    Mock::given(path("/swap/v1/quote"))
+        .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "2").set_body_string("Too Many Requests"))
+        .mount(&h.api)
+        .await;
+
+    match get_quote(&try_http_client().unwrap(), &sol_to_usdc()).await.unwrap_err() {
+        Error::Status(e) => {
+            assert!(e.is_rate_limited());
+            assert_eq!(e.retry_after, Some(std::time::Duration::from_secs(2)));
+            assert_eq!(e.message, "Too Many Requests");
+        }
+        other => panic!("expected a status error, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn malformed_quote_is_a_deserialize_error() {
+    let h = Harness::start().await;
+    Mock::given(path("/swap/v1/quote"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "inputMint": WSOL_MINT, "outAmount": 5 })))
+        .mount(&h.api)
+        .await;
+
+    let err = get_quote(&try_http_client().unwrap(), &sol_to_usdc()).await.unwrap_err();
+    assert!(matches!(&err, Error::Deserialize(m) if m.contains("/swap/v1/quote")), "{err:?}");
+}
+
+#[tokio::test]
+async fn swap_flow_signs_and_simulates_the_swap_transaction() {
+    let h = Harness::start().await;
+    h.rpc_defaults(6).await;
+    mount_quote(&h, 7_512_345).await;
+    Mock::given(method("POST"))
+        .and(path("/swap/v1/swap"))
+        .and(body_partial_json(json!({
+            "userPublicKey": h.user(),
+            "quoteResponse": { "outAmount": "7512345" }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "swapTransaction": unsigned_tx(&h.wallet.pubkey()),
+            "lastValidBlockHeight": 280000150
+        })))
+        .expect(1)
+        .mount(&h.api)
+        .await;
+
+    let result = swap_with_params(&h.wallet, sol_to_usdc()).await.unwrap();
+    assert_eq!(result.in_amount, TokenAmount::new(50_000_000, 9));
+    assert_eq!(result.out_amount, TokenAmount::new(7_512_345, 6));
+    assert_eq!(result.received_amount, result.out_amount);
+    assert_ne!(result.signature, Signature::default().to_string());
+    assert_eq!(h.rpc_calls("simulateTransaction").await, 1);
+    assert_eq!(h.rpc_calls("sendTransaction").await, 0);
+}
+
+#[tokio::test]
+async fn swap_error_stops_before_signing() {
+    let h = Harness::start().await;
+    h.rpc_defaults(6).await;
+    mount_quote(&h, 7_512_345).await;
+    Mock::given(path("/swap/v1/swap"))
+        .respond_with(ResponseTemplate::new(422).set_body_json(json!({ "error": "quoteResponse is stale" })))
+        .mount(&h.api)
+        .await;
+
+    let err = swap_with_params(&h.wallet, sol_to_usdc()).await.unwrap_err();
+    assert_eq!(err.status().map(|s| s.as_u16()), Some(422));
+    assert_eq!(h.rpc_calls("simulateTransaction").await, 0);
+}
+
+#[tokio::test]
+async fn undecodable_swap_transaction_is_rejected() {
+    let h = Harness::start().await;
+    h.rpc_defaults(6).await;
+    mount_quote(&h, 7_512_345).await;
+    Mock::given(path("/swap/v1/swap"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "swapTransaction": "not a transaction!",
+            "lastValidBlockHeight": 280000150
+        })))
+        .mount(&h.api)
+        .await;
+
+    let err = swap_with_params(&h.wallet, sol_to_usdc()).await.unwrap_err();
+    assert!(matches!(err, Error::Deserialize(_)), "{err:?}");
+}
+
+// ─────────────────── /swap/v1/swap-instructions ───────────────────
+
+async fn mount_swap_instructions(h: &Harness, body: serde_json::Value) {
+    Mock::given(path("/swap/v1/quote"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(quote(USDC_MINT, WSOL_MINT, 1_000_000, 6_650_000)))
+        .mount(&h.api)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/swap/v1/swap-instructions"))
+        .and(body_partial_json(json!({ "userPublicKey": h.user(), "instructionFormat": "json" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .mount(&h.api)
+        .await;
+}
+
+#[tokio::test]
+async fn swap_instruction_flow_compiles_and_simulates() {
+    let h = Harness::start().await;
+    h.rpc_defaults(6).await;
+    mount_swap_instructions(
+        &h,
+        json!({
+            "computeBudgetInstructions": [{
+                "programId": "ComputeBudget111111111111111111111111111111",
+                "accounts": [],
+                "data": base64::encode([2, 0x40, 0x0d, 0x03, 0x00])
+            }],
+            "setupInstructions": [],
+            "swapInstruction": {
+                "programId": MEMO_PROGRAM,
+                "accounts": [{ "pubkey": h.user(), "isSigner": true, "isWritable": true }],
+                "data": base64::encode("swap")
+            },
+            "addressLookupTableAddresses": []
+        }),
+    )
+    .await;
+
+    swap_instruction_flow(&h.wallet).await.unwrap();
+    assert_eq!(h.rpc_calls("getLatestBlockhash").await, 1);
+    assert_eq!(h.rpc_calls("simulateTransaction").await, 1);
+}
+
+#[tokio::test]
+async fn swap_instructions_without_instructions_is_an_api_error() {
+    let h = Harness::start().await;
+    h.rpc_defaults(6).await;
+    mount_swap_instructions(&h, json!({ "addressLookupTableAddresses": [] })).await;
+
+    let err = swap_instruction_flow(&h.wallet).await.unwrap_err();
+    assert!(matches!(&err, Error::Api { message, .. } if message.contains("no instructions")), "{err:?}");
+}
+
+#[tokio::test]
+async fn legacy_instruction_format_is_rejected() {
+    let h = Harness::start().await;
+    h.rpc_defaults(6).await;
+    mount_swap_instructions(&h, json!({ "swapInstruction": "AQID" })).await;
+
+    let err = swap_instruction_flow(&h.wallet).await.unwrap_err();
+    assert!(err.to_string().contains("instructionFormat"), "{err}");
+    assert_eq!(h.rpc_calls("simulateTransaction").await, 0);
+}
+
+// ─────────────────── /ultra/v1 ───────────────────
+
+#[tokio::test]
+async fn ultra_flow_simulates_instead_of_executing() {
+    let h = Harness::start().await;
+    h.rpc_defaults(6).await;
+    Mock::given(method("GET"))
+        .and(path("/ultra/v1/order"))
+        .and(query_param("taker", h.user()))
+        .and(query_param("amount", "10000000"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "requestId": "ultra-req-1",
+            "transaction": unsigned_tx(&h.wallet.pubkey())
+        })))
+        .expect(1)
+        .mount(&h.api)
+        .await;
+    Mock::given(path("/ultra/v1/execute")).respond_with(ResponseTemplate::new(200)).expect(0).mount(&h.api).await;
+
+    ultra_flow(&h.wallet).await.unwrap();
+    assert_eq!(h.rpc_calls("simulateTransaction").await, 1);
+}
+
+#[tokio::test]
+async fn ultra_execute_posts_the_signed_transaction() {
+    let h = Harness::start().await;
+    std::env::set_var("DRY_RUN", "");
+    let signature = Signature::new_unique().to_string();
+    Mock::given(method("POST"))
+        .and(path("/ultra/v1/execute"))
+        .and(body_partial_json(json!({ "signedTransaction": "c2lnbmVk", "requestId": "ultra-req-1" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "status": "Success",
+            "signature": signature,
+            "slot": "301234570",
+            "inputAmountResult": "10000000"
+        })))
+        .expect(1)
+        .mount(&h.api)
+        .await;
+
+    let resp = client().ultra().execute("c2lnbmVk", "ultra-req-1").await.unwrap();
+    assert_eq!(resp.status.as_deref(), Some("Success"));
+    assert_eq!(resp.signature.as_deref(), Some(signature.as_str()));
+    assert_eq!(resp.extra["inputAmountResult"], "10000000");
+}
+
+#[tokio::test]
+async fn ultra_order_error_is_surfaced() {
+    let h = Harness::start().await;
+    Mock::given(path("/ultra/v1/order"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({ "error": "Insufficient funds", "code": 1 })))
+        .mount(&h.api)
+        .await;
+
+    match ultra_flow(&h.wallet).await.unwrap_err() {
+        Error::Status(e) => {
+            assert_eq!(e.code.as_deref(), Some("1"));
+            assert_eq!(e.message, "Insufficient funds");
+        }
+        other => panic!("expected a status error, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn ultra_order_without_transaction_is_a_deserialize_error() {
+    let h = Harness::start().await;
+    Mock::given(path("/ultra/v1/order"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "requestId": "ultra-req-1" })))
+        .mount(&h.api)
+        .await;
+
+    let err = ultra_flow(&h.wallet).await.unwrap_err();
+    assert!(matches!(err, Error::Deserialize(_)), "{err:?}");
+}
+
+// ─────────────────── /trigger/v1 ───────────────────
+
+#[tokio::test]
+async fn trigger_flow_creates_signs_and_simulates() {
+    let h = Harness::start().await;
+    h.rpc_defaults(6).await;
+    Mock::given(method("POST"))
+        .and(path("/trigger/v1/createOrder"))
+        .and(body_partial_json(json!({
+            "maker": h.user(),
+            "payer": h.user(),
+            "params": { "makingAmount": "30000000", "takingAmount": "5000000" }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "requestId": "trigger-req-1",
+            "transaction": unsigned_tx(&h.wallet.pubkey()),
+            "order": "6uJ4LoD7BWnT6Z3iDqaPZZmWz6VH4jLH5LGc29L5bA4D"
+        })))
+        .expect(1)
+        .mount(&h.api)
+        .await;
+    Mock::given(path("/trigger/v1/execute")).respond_with(ResponseTemplate::new(200)).expect(0).mount(&h.api).await;
+
+    trigger_flow(&h.wallet).await.unwrap();
+    assert_eq!(h.rpc_calls("simulateTransaction").await, 1);
+}
+
+#[tokio::test]
+async fn trigger_create_without_transaction_is_an_api_error() {
+    let h = Harness::start().await;
+    Mock::given(path("/trigger/v1/createOrder"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({ "code": 400, "error": "makingAmount too small" })),
+        )
+        .mount(&h.api)
+        .await;
+
+    let err = trigger_flow(&h.wallet).await.unwrap_err();
+    assert!(matches!(&err, Error::Api { message, .. } if message.contains("makingAmount too small")), "{err:?}");
+}
+
+#[tokio::test]
+async fn trigger_execute_error_body_is_decoded() {
+    let h = Harness::start().await;
+    std::env::set_var("DRY_RUN", "");
+    Mock::given(path("/trigger/v1/execute"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "error": "Transaction simulation failed",
+            "code": "SIMULATION_FAILED"
+        })))
+        .mount(&h.api)
+        .await;
+
+    match client().trigger().execute("c2lnbmVk", "trigger-req-1").await.unwrap_err() {
+        Error::Status(e) => {
+            assert_eq!(e.code.as_deref(), Some("SIMULATION_FAILED"));
+            assert_eq!(e.message, "Transaction simulation failed");
+        }
+        other => panic!("expected a status error, got {other:?}"),
+    }
+}
+
+// ─────────────────── /recurring/v1 ───────────────────
+
+fn create_recurring_mock() -> Mock {
+    Mock::given(method("POST")).and(path("/recurring/v1/createOrder"))
+}
+
+#[tokio::test]
+async fn recurring_flow_previews_creates_and_simulates() {
+    let h = Harness::start().await;
+    h.rpc_defaults(6).await;
+    Mock::given(path("/price/v3"))
+        .and(query_param("ids", WSOL_MINT))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            WSOL_MINT: { "usdPrice": 150.0, "decimals": 9, "blockId": 301234567, "priceChange24h": 1.2 }
+        })))
+        .mount(&h.api)
+        .await;
+    create_recurring_mock()
+        .and(body_partial_json(json!({
+            "user": h.user(),
+            "inputMint": WSOL_MINT,
+            "outputMint": USDC_MINT,
+            "params": { "time": { "inAmount": 50000000, "numberOfOrders": 2, "interval": 86400 } }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "requestId": "recurring-req-1",
+            "transaction": unsigned_tx(&h.wallet.pubkey())
+        })))
+        .expect(1)
+        .mount(&h.api)
+        .await;
+    Mock::given(path("/recurring/v1/execute")).respond_with(ResponseTemplate::new(200)).expect(0).mount(&h.api).await;
+
+    recurring_flow(&h.wallet, RecurringOrderParams::default()).await.unwrap();
+    assert_eq!(h.rpc_calls("simulateTransaction").await, 1);
+}
+
+#[tokio::test]
+async fn recurring_sign_and_execute_reports_dry_run() {
+    let h = Harness::start().await;
+    h.rpc_defaults(6).await;
+    create_recurring_mock()
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "requestId": "recurring-req-1",
+            "transaction": unsigned_tx(&h.wallet.pubkey())
+        })))
+        .mount(&h.api)
+        .await;
+
+    let recurring = client().recurring();
+    let tx = recurring.create_order(&h.user(), None, &RecurringOrderParams::default()).await.unwrap();
+    assert_eq!(tx.request_id, "recurring-req-1");
+    let resp = recurring.sign_and_execute(&h.wallet, &tx).await.unwrap();
+    assert_eq!(resp.status, DRY_RUN_STATUS);
+}
+
+#[tokio::test]
+async fn recurring_price_order_sends_price_strategy() {
+    let h = Harness::start().await;
+    create_recurring_mock()
+        .and(body_partial_json(json!({
+            "inputMint": USDC_MINT,
+            "outputMint": WSOL_MINT,
+            "params": { "price": { "depositAmount": 100000000, "incrementUsdcValue": 10000000, "interval": 86400 } }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "requestId": "recurring-req-2",
+            "transaction": unsigned_tx(&h.wallet.pubkey())
+        })))
+        .expect(1)
+        .mount(&h.api)
+        .await;
+
+    let params = PriceRecurringParams::default();
+    let tx = client().recurring().create_price_order(&h.user(), None, &params).await.unwrap();
+    assert_eq!(tx.request_id, "recurring-req-2");
+}
+
+#[tokio::test]
+async fn recurring_minimum_is_classified() {
+    let h = Harness::start().await;
+    create_recurring_mock()
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "code": 400,
+            "error": "Order amount must be at least 100 USD"
+        })))
+        .mount(&h.api)
+        .await;
+
+    let err = client().recurring().create_order(&h.user(), None, &RecurringOrderParams::default()).await.unwrap_err();
+    assert!(matches!(err, Error::Recurring(RecurringError::AmountTooSmall(_))), "{err:?}");
+}
+
+#[tokio::test]
+async fn recurring_server_error_keeps_its_status() {
+    let h = Harness::start().await;
+    create_recurring_mock()
+        .respond_with(ResponseTemplate::new(503).set_body_json(json!({ "message": "upstream unavailable" })))
+        .mount(&h.api)
+        .await;
+
+    let err = client().recurring().create_order(&h.user(), None, &RecurringOrderParams::default()).await.unwrap_err();
+    assert!(matches!(&err, Error::Status(e) if e.status == 503), "{err:?}");
+}
+
+#[tokio::test]
+async fn recurring_response_without_transaction_or_error_is_rejected() {
+    let h = Harness::start().await;
+    create_recurring_mock()
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "requestId": "recurring-req-1" })))
+        .mount(&h.api)
+        .await;
+
+    let err = client().recurring().create_order(&h.user(), None, &RecurringOrderParams::default()).await.unwrap_err();
+    assert!(matches!(err, Error::Recurring(RecurringError::MissingTransaction)), "{err:?}");
+}
+
+#[tokio::test]
+async fn recurring_orders_are_collected_across_pages() {
+    let h = Harness::start().await;
+    for (page, key) in [("1", "OrderA"), ("2", "OrderB")] {
+        Mock::given(path("/recurring/v1/getRecurringOrders"))
+            .and(query_param("user", h.user()))
+            .and(query_param("orderStatus", "active"))
+            .and(query_param("page", page))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "all": [{ "orderKey": key, "userPubkey": h.user(), "inputMint": USDC_MINT, "outputMint": WSOL_MINT }],
+                "totalPages": 2,
+                "page": page.parse::<u32>().unwrap()
+            })))
+            .expect(1)
+            .mount(&h.api)
+            .await;
+    }
+
+    let orders = client().recurring().get_orders(&h.user(), RecurringOrderStatus::Active).await.unwrap();
+    let keys: Vec<&str> = orders.iter().map(|o| o.order_key.as_str()).collect();
+    assert_eq!(keys, ["OrderA", "OrderB"]);
+}
@@ -0,0 +1,227 @@
+// common/tests/responses.rs
+//
+// Every response type against a saved body from the API it models, so a
+// change in Jupiter's JSON shows up here rather than in someone's swap.
+// Fixtures live in tests/fixtures (error bodies in tests/fixtures/errors);
+// to refresh one, save the endpoint's current response over it with the
+// wallet, signatures and transaction swapped for the test values.
+
+use jupiter_sdk::{
+    unknown_fields, CreateTriggerResponse, ExecuteRecurringResponse, ExecuteTriggerResponse, JupiterApiError,
+    QuoteResponse, RecurringError, RecurringOrder, RecurringTxResponse, RecurringType, SwapInstructionResponse,
+    SwapResponse, UltraExecuteResponse, UltraOrderResponse,
+};
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use solana_sdk::message::VersionedMessage;
+use solana_sdk::transaction::VersionedTransaction;
+
+const WALLET: &str = "EvJyF2AW5kq4M8ZryHqLdDTpPXc1dwxB7tK8yPP5cqgW";
+
+fn fixture(name: &str) -> String {
+    let path = format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"));
+    std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("{path}: {e}"))
+}
+
+fn decode<T: DeserializeOwned>(name: &str) -> T {
+    serde_json::from_str(&fixture(name))
+        .unwrap_or_else(|e| panic!("{name} no longer decodes as {}: {e}", std::any::type_name::<T>()))
+}
+
+/// Decode, encode and decode again; whatever the type keeps must survive the trip
+fn round_trip<T: Serialize + DeserializeOwned>(name: &str) -> T {
+    let decoded: T = decode(name);
+    let value = serde_json::to_value(&decoded).unwrap();
+    let again: T = serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&again).unwrap(), value, "{name} changed on a round trip");
+    decoded
+}
+
+/// [`round_trip`] for types that keep unknown fields: nothing in the body may be dropped
+fn lossless<T: Serialize + DeserializeOwned>(name: &str) -> T {
+    let decoded: T = round_trip(name);
+    let body: Value = serde_json::from_str(&fixture(name)).unwrap();
+    assert_eq!(serde_json::to_value(&decoded).unwrap(), body, "{name} lost fields when re-encoded");
+    decoded
+}
+
+fn transaction(b64: &str) -> VersionedTransaction {
+    bincode::deserialize(&base64::decode(b64).unwrap()).unwrap()
+}
+
+// ─────────────────── /swap/v1 ───────────────────
+
+#[test]
+fn quote_goes_back_to_swap_unchanged() {
+    let quote: QuoteResponse = lossless("quote.json");
+    assert_eq!(quote.outAmount, "15274512");
+    assert_eq!(quote.slippageBps, 50);
+    assert_eq!(quote.routePlan.len(), 3);
+    assert_eq!(quote.routePlan[1].swap_info.label, "Whirlpool");
+    assert_eq!(quote.routePlan[1].extra["bps"], 10000);
+    assert_eq!(quote.extra["swapUsdValue"], "15.2761340251962513");
+}
+
+#[test]
+fn swap_response_carries_a_v0_transaction() {
+    let swap: SwapResponse = round_trip("swap.json");
+    assert_eq!(swap.last_valid_block_height, 350497183);
+    let tx = transaction(&swap.swap_transaction);
+    assert!(matches!(tx.message, VersionedMessage::V0(_)));
+    assert_eq!(tx.signatures.len(), 1);
+    assert_eq!(tx.message.static_account_keys()[0].to_string(), WALLET);
+}
+
+#[test]
+fn strict_mode_sees_what_swap_ignores() {
+    let (_, unknown) = unknown_fields::<SwapResponse>(fixture("swap.json").as_bytes()).unwrap();
+    assert_eq!(
+        unknown,
+        [
+            "prioritizationFeeLamports",
+            "computeUnitLimit",
+            "prioritizationType",
+            "dynamicSlippageReport",
+            "simulationError",
+            "addressesByLookupTableAddress",
+        ]
+    );
+    // everything the quote keeps in `extra` today is known
+    let (_, unknown) = unknown_fields::<QuoteResponse>(fixture("quote.json").as_bytes()).unwrap();
+    assert!(unknown.is_empty(), "{unknown:?}");
+}
+
+#[test]
+fn strict_mode_reports_new_fields_kept_in_extra() {
+    let mut quote: Value = serde_json::from_str(&fixture("quote.json")).unwrap();
+    quote["newQuoteField"] = 1.into();
+    quote["routePlan"][1]["swapInfo"]["newVenueField"] = true.into();
+    let (quote, unknown) = unknown_fields::<QuoteResponse>(&serde_json::to_vec(&quote).unwrap()).unwrap();
+    assert_eq!(unknown, ["newQuoteField", "routePlan.1.swapInfo.newVenueField"]);
+    // still kept, so the quote goes back to /swap with the new fields
+    assert_eq!(quote.extra["newQuoteField"], 1);
+}
+
+#[test]
+fn swap_instructions_decode_in_execution_order() {
+    let resp: SwapInstructionResponse = decode("swap_instructions.json");
+    let ix = resp.instructions().unwrap();
+    let programs: Vec<String> = ix.iter().map(|i| i.program_id.to_string()).collect();
+    assert_eq!(
+        programs,
+        [
+            "ComputeBudget111111111111111111111111111111",
+            "ComputeBudget111111111111111111111111111111",
+            "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
+            "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4",
+            "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+        ]
+    );
+    let signer = &ix[3].accounts[1];
+    assert_eq!((signer.pubkey.to_string().as_str(), signer.is_signer, signer.is_writable), (WALLET, true, false));
+    assert_eq!(resp.address_lookup_table_addresses.map(|a| a.len()), Some(2));
+}
+
+// ─────────────────── /ultra/v1 ───────────────────
+
+#[test]
+fn ultra_order_has_request_id_and_transaction() {
+    let order: UltraOrderResponse = decode("ultra_order.json");
+    assert_eq!(order.requestId, "019a0c4e-3b7f-7a1d-9e3c-5f2b8d41c6a7");
+    assert_eq!(transaction(&order.transaction).message.static_account_keys()[0].to_string(), WALLET);
+}
+
+#[test]
+fn ultra_execute_keeps_every_field() {
+    let ok: UltraExecuteResponse = lossless("ultra_execute.json");
+    assert_eq!(ok.status.as_deref(), Some("Success"));
+    assert_eq!(ok.slot.as_deref(), Some("372419301"));
+    assert_eq!(ok.extra["outputAmountResult"], "1527544");
+
+    let failed: UltraExecuteResponse = lossless("ultra_execute_failed.json");
+    assert_eq!(failed.status.as_deref(), Some("Failed"));
+    assert_eq!(failed.extra["code"], -1005);
+    assert_eq!(failed.extra["error"], "custom program error: 0x1771");
+}
+
+// ─────────────────── /trigger/v1 ───────────────────
+
+#[test]
+fn trigger_create_order_has_order_and_transaction() {
+    let created: CreateTriggerResponse = decode("trigger_create_order.json");
+    assert_eq!(created.order.as_deref(), Some("3GqW5Ye9NRTBXbKbm9ttc6ZPLBpwcmQZUApyfwX2j6Pk"));
+    assert_eq!(created.request_id.as_deref(), Some("0b4e3c1d-0f6b-4a0e-bf8c-6c1f5b2f9e71"));
+    transaction(created.transaction.as_deref().unwrap());
+}
+
+#[test]
+fn trigger_execute_keeps_every_field() {
+    let executed: ExecuteTriggerResponse = lossless("trigger_execute.json");
+    assert_eq!(executed.status, "Success");
+}
+
+// ─────────────────── /recurring/v1 ───────────────────
+
+#[test]
+fn recurring_create_order_is_a_transaction() {
+    let tx = decode::<RecurringTxResponse>("recurring_create_order.json").into_tx().unwrap();
+    assert_eq!(tx.request_id, "1d6c7b2a-9e4f-4b8a-8c3d-2f5e6a7b8c9d");
+    assert_eq!(tx.order, None);
+    transaction(&tx.transaction);
+}
+
+#[test]
+fn recurring_minimum_is_amount_too_small() {
+    let err = decode::<RecurringTxResponse>("errors/recurring_amount_too_small.json").into_tx().unwrap_err();
+    assert!(matches!(&err, RecurringError::AmountTooSmall(m) if m.contains("at least 100 USDC")), "{err:?}");
+}
+
+#[test]
+fn recurring_execute_keeps_every_field() {
+    let executed: ExecuteRecurringResponse = lossless("recurring_execute.json");
+    assert_eq!(executed.status, "Success");
+    assert_eq!(executed.order.as_deref(), Some("7sTjr7W3wTLcH4aXTRFB2w1cX4xPgYdeBN4jQ6B8rXkB"));
+    assert_eq!(executed.error, None);
+}
+
+#[test]
+fn recurring_orders_decode_time_and_price_orders() {
+    let page: Value = serde_json::from_str(&fixture("recurring_get_orders.json")).unwrap();
+    let time: Vec<RecurringOrder> = serde_json::from_value(page["time"].clone()).unwrap();
+    let price: Vec<RecurringOrder> = serde_json::from_value(page["price"].clone()).unwrap();
+
+    assert_eq!(time[0].recurring_type(), RecurringType::Time);
+    assert_eq!(time[0].in_amount_per_cycle.as_deref(), Some("50"));
+    assert_eq!(time[0].remaining_in(), 50.0);
+    assert_eq!(time[0].trades[0].raw_output_amount, "327865919");
+    assert_eq!(price[0].recurring_type(), RecurringType::Price);
+    assert_eq!(price[0].remaining_in(), 200.0);
+
+    let again: RecurringOrder = serde_json::from_value(serde_json::to_value(&time[0]).unwrap()).unwrap();
+    assert_eq!(serde_json::to_value(again).unwrap(), serde_json::to_value(&time[0]).unwrap());
+}
+
+// ─────────────────── Error bodies ───────────────────
+
+#[test]
+fn error_bodies_give_code_and_message() {
+    let cases = [
+        ("quote_no_route.json", 400, Some("COULD_NOT_FIND_ANY_ROUTE"), "Could not find any route"),
+        ("ultra_insufficient_funds.json", 400, Some("1"), "Insufficient funds"),
+        ("trigger_invalid_amount.json", 400, Some("400"), "invalid request body"),
+        ("price_bad_request.json", 400, None, "ids: must contain at most 50 mints"),
+        ("rate_limited.txt", 429, None, "Too Many Requests"),
+    ];
+    for (name, status, code, message) in cases {
+        let status = StatusCode::from_u16(status).unwrap();
+        let err = JupiterApiError::from_body(status, None, &fixture(&format!("errors/{name}")));
+        assert_eq!((err.code.as_deref(), err.message.as_str()), (code, message), "{name}");
+    }
+
+    let err = JupiterApiError::from_body(StatusCode::BAD_REQUEST, None, &fixture("errors/swap_stale_quote.json"));
+    assert_eq!(err.code.as_deref(), Some("INVALID_REQUEST"));
+    assert!(err.message.contains("missing field `outAmount`"), "{}", err.message);
+    assert_eq!(err.body["errorCode"], "INVALID_REQUEST");
+}
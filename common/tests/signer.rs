@@ -0,0 +1,108 @@
+// common/tests/signer.rs
+//
+// Signature placement on transactions the API builds: each signer lands in
+// the slot the message header gives it, signatures already present stay, and
+// a sponsored wallet's payer signs only when the transaction names it.
+
+use jupiter_sdk::{pending_signers, sign_versioned_tx, Error, Wallet};
+use solana_sdk::hash::Hash;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::message::{v0, VersionedMessage};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signature, Signer};
+use solana_sdk::transaction::VersionedTransaction;
+
+/// Unsigned transaction paid by `payer` that `signers` must also sign, with
+/// no signature slots filled in, as the API returns it
+fn unsigned(payer: &Pubkey, signers: &[Pubkey]) -> VersionedTransaction {
+    let accounts = signers.iter().map(|key| AccountMeta::new(*key, true)).collect();
+    let ix = Instruction { program_id: Pubkey::new_unique(), accounts, data: vec![1] };
+    let message = v0::Message::try_compile(payer, &[ix], &[], Hash::new_unique()).unwrap();
+    let signatures = vec![Signature::default(); message.header.num_required_signatures as usize];
+    VersionedTransaction { signatures, message: VersionedMessage::V0(message) }
+}
+
+fn signed_by(tx: &VersionedTransaction, slot: usize, key: &Keypair) -> bool {
+    tx.message.static_account_keys()[slot] == key.pubkey()
+        && tx.signatures[slot].verify(key.pubkey().as_ref(), &tx.message.serialize())
+}
+
+#[tokio::test]
+async fn signs_in_the_slot_the_header_assigns() {
+    let (partner, user) = (Keypair::new(), Keypair::new());
+    let mut tx = unsigned(&partner.pubkey(), &[user.pubkey()]);
+    assert_eq!(tx.message.static_account_keys()[1], user.pubkey());
+
+    sign_versioned_tx(&mut tx, &user).await.unwrap();
+
+    assert!(signed_by(&tx, 1, &user));
+    assert_eq!(tx.signatures[0], Signature::default(), "the partner's slot stays a placeholder");
+    assert_eq!(pending_signers(&tx), vec![partner.pubkey()]);
+}
+
+#[tokio::test]
+async fn fills_in_missing_signature_slots() {
+    let (partner, user) = (Keypair::new(), Keypair::new());
+    let mut tx = unsigned(&partner.pubkey(), &[user.pubkey()]);
+    tx.signatures.clear();
+    assert_eq!(pending_signers(&tx), vec![partner.pubkey(), user.pubkey()]);
+
+    sign_versioned_tx(&mut tx, &user).await.unwrap();
+
+    assert_eq!(tx.signatures.len(), 2);
+    assert!(signed_by(&tx, 1, &user));
+    assert_eq!(pending_signers(&tx), vec![partner.pubkey()]);
+}
+
+#[tokio::test]
+async fn keeps_a_co_signers_signature() {
+    let (partner, user) = (Keypair::new(), Keypair::new());
+    let mut tx = unsigned(&partner.pubkey(), &[user.pubkey()]);
+    sign_versioned_tx(&mut tx, &partner).await.unwrap();
+    let partner_signature = tx.signatures[0];
+
+    sign_versioned_tx(&mut tx, &user).await.unwrap();
+
+    assert_eq!(tx.signatures[0], partner_signature);
+    assert!(signed_by(&tx, 0, &partner));
+    assert!(signed_by(&tx, 1, &user));
+    assert!(pending_signers(&tx).is_empty());
+    assert!(tx.verify_with_results().into_iter().all(|ok| ok));
+}
+
+#[tokio::test]
+async fn sponsored_wallet_signs_as_user_and_payer() {
+    let (payer, user) = (Keypair::new(), Keypair::new());
+    let mut tx = unsigned(&payer.pubkey(), &[user.pubkey()]);
+    let wallet =
+        Wallet::Sponsored { user: Box::new(Wallet::Keypair(user.insecure_clone())), payer: payer.insecure_clone() };
+
+    sign_versioned_tx(&mut tx, &wallet).await.unwrap();
+
+    assert!(signed_by(&tx, 0, &payer));
+    assert!(signed_by(&tx, 1, &user));
+    assert!(pending_signers(&tx).is_empty());
+}
+
+#[tokio::test]
+async fn sponsored_wallet_leaves_out_a_payer_the_transaction_does_not_name() {
+    let (payer, user) = (Keypair::new(), Keypair::new());
+    let mut tx = unsigned(&user.pubkey(), &[]);
+    let wallet = Wallet::Sponsored { user: Box::new(Wallet::Keypair(user.insecure_clone())), payer };
+
+    sign_versioned_tx(&mut tx, &wallet).await.unwrap();
+
+    assert_eq!(tx.signatures.len(), 1);
+    assert!(signed_by(&tx, 0, &user));
+}
+
+#[tokio::test]
+async fn refuses_a_transaction_the_signer_is_not_required_on() {
+    let (partner, stranger) = (Keypair::new(), Keypair::new());
+    let mut tx = unsigned(&partner.pubkey(), &[]);
+
+    let err = sign_versioned_tx(&mut tx, &stranger).await.unwrap_err();
+
+    assert!(matches!(&err, Error::Sign(msg) if msg.contains(&stranger.pubkey().to_string())), "{err:?}");
+    assert_eq!(tx.signatures, vec![Signature::default()]);
+}
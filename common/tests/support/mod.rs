@@ -0,0 +1,206 @@
+// common/tests/support/mod.rs
+//
+// Shared harness for the integration tests: one wiremock server standing in
+// for the Jupiter API, one answering JSON-RPC, and the env pointing the flows
+// at both. DRY_RUN is on, so every flow stops at the RPC-send boundary with a
+// simulateTransaction instead of a send.
+#![allow(dead_code)]
+
+pub mod validator;
+
+use base64::encode;
+use serde_json::{json, Value};
+use solana_sdk::hash::Hash;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::message::{v0, VersionedMessage};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signature, Signer};
+use solana_sdk::transaction::VersionedTransaction;
+use std::str::FromStr;
+use std::sync::OnceLock;
+use tokio::sync::{Mutex, MutexGuard};
+use wiremock::matchers::{body_partial_json, method};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+pub const WSOL_MINT: &str = "So11111111111111111111111111111111111111112";
+pub const USDC_MINT: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+pub const TOKEN_PROGRAM: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+pub const MEMO_PROGRAM: &str = "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr";
+
+/// Settings that would send a test somewhere real or change its path
+const CLEARED_VARS: [&str; 19] = [
+    "API_KEY",
+    "FEE_ACCOUNT",
+    "FEE_BPS",
+    "NETWORK",
+    "I_UNDERSTAND_MAINNET",
+    "NONCE_ACCOUNT",
+    "SIGNER",
+    "SECRET_KEY",
+    "MNEMONIC",
+    "KEYPAIR_PATH",
+    "KEYSTORE_PATH",
+    "PAYER_SECRET_KEY",
+    "PAYER_KEYPAIR_PATH",
+    "QUOTE_CACHE_TTL",
+    "JUPITER_VCR",
+    "JUPITER_PROXY",
+    "OUTPUT",
+    "JUPITER_STRICT",
+    "WS_URL",
+];
+pub struct Harness {
+    pub api: MockServer,
+    pub rpc: MockServer,
+    pub wallet: Keypair,
+    // flows read their settings from the process env, so tests take turns
+    _env: MutexGuard<'static, ()>,
+}
+
+impl Harness {
+    pub async fn start() -> Self {
+        static ENV: OnceLock<Mutex<()>> = OnceLock::new();
+        let guard = ENV.get_or_init(Mutex::default).lock().await;
+        let (api, rpc) = (MockServer::start().await, MockServer::start().await);
+
+        // empty rather than removed: the library's dotenv() calls would restore a removed
+        // variable from a developer's .env, while setting() treats empty as unset
+        for var in CLEARED_VARS {
+            std::env::set_var(var, "");
+        }
+        std::env::set_var("JUPITER_BASE_URL", api.uri());
+        std::env::set_var("RPC_URL", rpc.uri());
+        std::env::set_var("DRY_RUN", "1");
+        std::env::set_var("CONFIRMATION", "polling");
+        // error bodies should surface at once, not after backoff
+        std::env::set_var("JUPITER_RETRY_ATTEMPTS", "1");
+        for api in jupiter_sdk::JupiterApi::ALL {
+            std::env::set_var(api.env_var(), "");
+            std::env::set_var(format!("JUPITER_{}_RPS", api.name().to_ascii_uppercase()), "0");
+        }
+
+        Self { api, rpc, wallet: Keypair::new(), _env: guard }
+    }
+
+    pub fn user(&self) -> String {
+        self.wallet.pubkey().to_string()
+    }
+
+    /// Answer every JSON-RPC `rpc_method` call with `result`
+    pub async fn rpc(&self, rpc_method: &str, result: Value) {
+        let body = json!({ "jsonrpc": "2.0", "id": 1, "result": result });
+        Mock::given(method("POST"))
+            .and(body_partial_json(json!({ "method": rpc_method })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .mount(&self.rpc)
+            .await;
+    }
+
+    /// How many JSON-RPC `rpc_method` calls the flow made
+    pub async fn rpc_calls(&self, rpc_method: &str) -> usize {
+        let requests = self.rpc.received_requests().await.unwrap_or_default();
+        requests
+            .iter()
+            .filter_map(|r| serde_json::from_slice::<Value>(&r.body).ok())
+            .filter(|body| body["method"] == rpc_method)
+            .count()
+    }
+
+    /// getVersion, getAccountInfo for a plain SPL mint, getLatestBlockhash and a successful simulation
+    pub async fn rpc_defaults(&self, decimals: u8) {
+        // the client asks for the node version before picking encodings
+        self.rpc("getVersion", json!({ "solana-core": "1.18.26", "feature-set": 3469865029u32 })).await;
+        self.rpc("getAccountInfo", mint_account(decimals)).await;
+        self.rpc("getLatestBlockhash", latest_blockhash()).await;
+        self.rpc("simulateTransaction", simulation(None)).await;
+    }
+}
+
+/// Base64 v0 transaction with `payer` as its only (unsigned) signer, like the
+/// ones /swap, /ultra/v1/order and createOrder return
+pub fn unsigned_tx(payer: &Pubkey) -> String {
+    let ix = Instruction::new_with_bytes(
+        Pubkey::from_str(MEMO_PROGRAM).unwrap(),
+        b"jupiter test",
+        vec![AccountMeta::new(*payer, true)],
+    );
+    unsigned_tx_of(payer, &[ix], Hash::new_unique())
+}
+
+/// Base64 v0 transaction of `ixs` on `blockhash`, every signature left blank
+pub fn unsigned_tx_of(payer: &Pubkey, ixs: &[Instruction], blockhash: Hash) -> String {
+    let message = v0::Message::try_compile(payer, ixs, &[], blockhash).unwrap();
+    let signatures = vec![Signature::default(); message.header.num_required_signatures as usize];
+    let tx = VersionedTransaction { signatures, message: VersionedMessage::V0(message) };
+    encode(bincode::serialize(&tx).unwrap())
+}
+
+/// A /swap/v1/quote body for `amount` in and `out_amount` out
+pub fn quote(input_mint: &str, output_mint: &str, amount: u64, out_amount: u64) -> Value {
+    json!({
+        "inputMint": input_mint,
+        "inAmount": amount.to_string(),
+        "outputMint": output_mint,
+        "outAmount": out_amount.to_string(),
+        "otherAmountThreshold": (out_amount - out_amount / 200).to_string(),
+        "swapMode": "ExactIn",
+        "slippageBps": 50,
+        "platformFee": null,
+        "priceImpactPct": "0.0001",
+        "routePlan": [{
+            "swapInfo": {
+                "ammKey": "HcoJqG325TTifs6jyWvRJ9ET4pDu12Xrt2EQKZGFmuKX",
+                "label": "Whirlpool",
+                "inputMint": input_mint,
+                "outputMint": output_mint,
+                "inAmount": amount.to_string(),
+                "outAmount": out_amount.to_string(),
+                "feeAmount": "0",
+                "feeMint": input_mint
+            },
+            "percent": 100
+        }],
+        "contextSlot": 301234567,
+        "timeTaken": 0.012
+    })
+}
+
+/// getAccountInfo result for an initialized SPL Token mint with no authorities
+pub fn mint_account(decimals: u8) -> Value {
+    let mut data = vec![0u8; 82];
+    data[36..44].copy_from_slice(&1_000_000_000_000u64.to_le_bytes());
+    data[44] = decimals;
+    data[45] = 1;
+    json!({
+        "context": { "slot": 301234567 },
+        "value": {
+            "data": [encode(&data), "base64"],
+            "executable": false,
+            "lamports": 1_461_600,
+            "owner": TOKEN_PROGRAM,
+            "rentEpoch": 0,
+            "space": 82
+        }
+    })
+}
+
+pub fn latest_blockhash() -> Value {
+    json!({
+        "context": { "slot": 301234567 },
+        "value": { "blockhash": Hash::new_unique().to_string(), "lastValidBlockHeight": 280000150 }
+    })
+}
+
+/// simulateTransaction result, failed with `err` when given
+pub fn simulation(err: Option<Value>) -> Value {
+    json!({
+        "context": { "slot": 301234567 },
+        "value": {
+            "err": err,
+            "logs": ["Program MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr invoke [1]"],
+            "accounts": null,
+            "unitsConsumed": 1200,
+            "returnData": null
+        }
+    })
+}
@@ -0,0 +1,136 @@
+// common/tests/support/validator.rs
+//
+// A throwaway solana-test-validator for the end-to-end tests: fresh ledger in
+// the temp dir, its own ports, killed and cleaned up on drop. Set
+// SOLANA_TEST_VALIDATOR when the binary isn't on PATH.
+
+use super::TOKEN_PROGRAM;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::system_instruction;
+use solana_sdk::transaction::Transaction;
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::process::{Child, Command, Stdio};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+const STARTUP_TIMEOUT: Duration = Duration::from_secs(60);
+/// Ports after the RPC one: websocket, faucet, gossip, then the dynamic range
+const PORTS_USED: u16 = 40;
+const MINT_SIZE: usize = 82;
+
+pub struct TestValidator {
+    pub url: String,
+    pub ws_url: String,
+    child: Child,
+    ledger: PathBuf,
+}
+
+impl TestValidator {
+    /// Start a validator and wait until it answers getHealth
+    pub async fn start() -> Self {
+        let bin = std::env::var("SOLANA_TEST_VALIDATOR").unwrap_or_else(|_| "solana-test-validator".into());
+        let port = free_port();
+        let ledger = std::env::temp_dir().join(format!("jupiter-test-ledger-{port}"));
+        let child = Command::new(&bin)
+            .arg("--ledger")
+            .arg(&ledger)
+            .args(["--reset", "--quiet", "--bind-address", "127.0.0.1"])
+            .args(["--rpc-port", &port.to_string()])
+            .args(["--faucet-port", &(port + 2).to_string()])
+            .args(["--gossip-port", &(port + 3).to_string()])
+            .args(["--dynamic-port-range", &format!("{}-{}", port + 4, port + PORTS_USED)])
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .unwrap_or_else(|e| panic!("can't run {bin} ({e}); install the Solana CLI or set SOLANA_TEST_VALIDATOR"));
+        let mut validator = Self {
+            url: format!("http://127.0.0.1:{port}"),
+            ws_url: format!("ws://127.0.0.1:{}", port + 1),
+            child,
+            ledger,
+        };
+
+        let rpc = validator.rpc();
+        let deadline = Instant::now() + STARTUP_TIMEOUT;
+        while rpc.get_health().await.is_err() {
+            if let Ok(Some(status)) = validator.child.try_wait() {
+                panic!("{bin} exited ({status}) before it was healthy");
+            }
+            assert!(Instant::now() < deadline, "{bin} not healthy after {STARTUP_TIMEOUT:?}");
+            tokio::time::sleep(Duration::from_millis(500)).await;
+        }
+        validator
+    }
+
+    pub fn rpc(&self) -> RpcClient {
+        RpcClient::new_with_commitment(self.url.clone(), CommitmentConfig::confirmed())
+    }
+
+    /// Airdrop `sol` to `to` and wait for it to confirm
+    pub async fn airdrop(&self, to: &Pubkey, sol: u64) {
+        let rpc = self.rpc();
+        let signature = rpc.request_airdrop(to, sol * LAMPORTS_PER_SOL).await.unwrap();
+        rpc.poll_for_signature(&signature).await.unwrap();
+    }
+
+    /// A fresh keypair holding `sol`
+    pub async fn funded(&self, sol: u64) -> Keypair {
+        let kp = Keypair::new();
+        self.airdrop(&kp.pubkey(), sol).await;
+        kp
+    }
+
+    pub async fn balance(&self, address: &Pubkey) -> u64 {
+        self.rpc().get_balance(address).await.unwrap()
+    }
+
+    /// New SPL Token mint with `payer` as mint authority and no freeze authority
+    pub async fn create_mint(&self, payer: &Keypair, decimals: u8) -> Pubkey {
+        let rpc = self.rpc();
+        let mint = Keypair::new();
+        let token_program = Pubkey::from_str(TOKEN_PROGRAM).unwrap();
+        let rent = rpc.get_minimum_balance_for_rent_exemption(MINT_SIZE).await.unwrap();
+        // InitializeMint2: tag, decimals, mint authority, COption::None freeze authority
+        let mut data = vec![20, decimals];
+        data.extend_from_slice(payer.pubkey().as_ref());
+        data.push(0);
+        let ixs = [
+            system_instruction::create_account(
+                &payer.pubkey(),
+                &mint.pubkey(),
+                rent,
+                MINT_SIZE as u64,
+                &token_program,
+            ),
+            Instruction::new_with_bytes(token_program, &data, vec![AccountMeta::new(mint.pubkey(), false)]),
+        ];
+        let blockhash = rpc.get_latest_blockhash().await.unwrap();
+        let tx = Transaction::new_signed_with_payer(&ixs, Some(&payer.pubkey()), &[payer, &mint], blockhash);
+        rpc.send_and_confirm_transaction(&tx).await.unwrap();
+        mint.pubkey()
+    }
+}
+
+impl Drop for TestValidator {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+        let _ = std::fs::remove_dir_all(&self.ledger);
+    }
+}
+
+/// A free port with room for the ones after it
+fn free_port() -> u16 {
+    loop {
+        let port = TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+        if port < u16::MAX - PORTS_USED {
+            return port;
+        }
+    }
+}
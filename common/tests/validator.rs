@@ -0,0 +1,169 @@
+// common/tests/validator.rs
+//
+// The send path against a real ledger: a local solana-test-validator, funded
+// throwaway keypairs, and wiremock standing in for the Jupiter API. The mocks
+// hand back plain SOL transfers built on the validator's blockhash, so a
+// transfer that lands means the flow signed, sent and confirmed it properly.
+// Needs the Solana CLI, hence #[ignore]:
+//
+//     cargo test -p jupiter-sdk --test validator -- --ignored
+
+mod support;
+
+use jupiter_sdk::{
+    create_nonce_account, get_nonce, swap_instruction_flow, swap_with_params, SwapParams, TokenAmount, Wallet,
+};
+use serde_json::{json, Value};
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::system_instruction;
+use support::validator::TestValidator;
+use support::{quote, unsigned_tx_of, Harness, USDC_MINT, WSOL_MINT};
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, ResponseTemplate};
+
+/// Enough to leave the recipient rent-exempt
+const TRANSFER: u64 = 10_000_000;
+
+/// Harness pointed at `validator` with DRY_RUN off, so flows really send
+async fn live(validator: &TestValidator) -> Harness {
+    let h = Harness::start().await;
+    std::env::set_var("RPC_URL", &validator.url);
+    // a local ledger: its genesis hash isn't mainnet's, so the mainnet guard lets it through
+    std::env::set_var("NETWORK", "devnet");
+    std::env::set_var("DRY_RUN", "");
+    h
+}
+
+/// `instructionFormat: "json"` shape of `ix`, as /swap-instructions returns it
+fn instruction_json(ix: &Instruction) -> Value {
+    json!({
+        "programId": ix.program_id.to_string(),
+        "accounts": ix.accounts.iter().map(|a| json!({
+            "pubkey": a.pubkey.to_string(),
+            "isSigner": a.is_signer,
+            "isWritable": a.is_writable
+        })).collect::<Vec<_>>(),
+        "data": base64::encode(&ix.data)
+    })
+}
+
+/// /swap-instructions whose swap is a transfer of [`TRANSFER`] from `user` to `recipient`
+async fn mount_transfer_instructions(h: &Harness, user: &Pubkey, recipient: &Pubkey) {
+    Mock::given(path("/swap/v1/quote"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(quote(USDC_MINT, WSOL_MINT, 1_000_000, 6_650_000)))
+        .mount(&h.api)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/swap/v1/swap-instructions"))
+        .and(body_partial_json(json!({ "userPublicKey": user.to_string() })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "computeBudgetInstructions": [],
+            "setupInstructions": [],
+            "swapInstruction": instruction_json(&system_instruction::transfer(user, recipient, TRANSFER)),
+            "addressLookupTableAddresses": []
+        })))
+        .mount(&h.api)
+        .await;
+}
+
+#[tokio::test]
+#[ignore = "needs solana-test-validator"]
+async fn swap_transaction_lands() {
+    let validator = TestValidator::start().await;
+    let h = live(&validator).await;
+    validator.airdrop(&h.wallet.pubkey(), 2).await;
+    let (input, output) = (validator.create_mint(&h.wallet, 6).await, validator.create_mint(&h.wallet, 9).await);
+    let recipient = Pubkey::new_unique();
+
+    Mock::given(path("/swap/v1/quote"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(quote(
+            &input.to_string(),
+            &output.to_string(),
+            1_000_000,
+            2_000_000,
+        )))
+        .mount(&h.api)
+        .await;
+    let blockhash = validator.rpc().get_latest_blockhash().await.unwrap();
+    let transfer = system_instruction::transfer(&h.wallet.pubkey(), &recipient, TRANSFER);
+    Mock::given(method("POST"))
+        .and(path("/swap/v1/swap"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "swapTransaction": unsigned_tx_of(&h.wallet.pubkey(), &[transfer], blockhash),
+            "lastValidBlockHeight": u64::MAX
+        })))
+        .mount(&h.api)
+        .await;
+
+    let params = SwapParams {
+        input_mint: input.to_string(),
+        output_mint: output.to_string(),
+        amount: TokenAmount::new(1_000_000, 6),
+        slippage_bps: 50,
+        mint_policy: None,
+    };
+    let result = swap_with_params(&h.wallet, params).await.unwrap();
+    let status = validator.rpc().get_signature_status(&result.signature.parse().unwrap()).await.unwrap();
+    assert_eq!(status, Some(Ok(())));
+    assert_eq!(validator.balance(&recipient).await, TRANSFER);
+}
+
+#[tokio::test]
+#[ignore = "needs solana-test-validator"]
+async fn sponsored_swap_instructions_land_with_the_payer_covering_fees() {
+    let validator = TestValidator::start().await;
+    let h = live(&validator).await;
+    validator.airdrop(&h.wallet.pubkey(), 1).await;
+    let payer = validator.funded(1).await;
+    let (user, sponsor) = (h.wallet.pubkey(), payer.pubkey());
+    let recipient = Pubkey::new_unique();
+    mount_transfer_instructions(&h, &user, &recipient).await;
+
+    let (user_before, sponsor_before) = (validator.balance(&user).await, validator.balance(&sponsor).await);
+    let wallet = Wallet::Sponsored { user: Box::new(Wallet::Keypair(h.wallet.insecure_clone())), payer };
+    swap_instruction_flow(&wallet).await.unwrap();
+
+    assert_eq!(validator.balance(&recipient).await, TRANSFER);
+    assert_eq!(validator.balance(&user).await, user_before - TRANSFER);
+    assert!(validator.balance(&sponsor).await < sponsor_before, "the sponsor paid no fee");
+}
+
+#[tokio::test]
+#[ignore = "needs solana-test-validator"]
+async fn swap_instructions_land_on_a_durable_nonce() {
+    let validator = TestValidator::start().await;
+    let h = live(&validator).await;
+    validator.airdrop(&h.wallet.pubkey(), 2).await;
+    let rpc = validator.rpc();
+    let nonce = Keypair::new();
+    create_nonce_account(&rpc, &h.wallet, &nonce, &h.wallet.pubkey()).await.unwrap();
+    let before = get_nonce(&rpc, &nonce.pubkey()).await.unwrap();
+    // a nonce can't advance within the blockhash it was created under
+    while rpc.get_latest_blockhash().await.unwrap() == before.blockhash {
+        tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+    }
+    std::env::set_var("NONCE_ACCOUNT", nonce.pubkey().to_string());
+    let recipient = Pubkey::new_unique();
+    mount_transfer_instructions(&h, &h.wallet.pubkey(), &recipient).await;
+
+    swap_instruction_flow(&h.wallet).await.unwrap();
+    assert_eq!(validator.balance(&recipient).await, TRANSFER);
+    assert_ne!(get_nonce(&rpc, &nonce.pubkey()).await.unwrap().blockhash, before.blockhash);
+}
+
+#[tokio::test]
+#[ignore = "needs solana-test-validator"]
+async fn websocket_confirmation_sees_the_swap_land() {
+    let validator = TestValidator::start().await;
+    let h = live(&validator).await;
+    std::env::set_var("CONFIRMATION", "websocket");
+    std::env::set_var("WS_URL", &validator.ws_url);
+    validator.airdrop(&h.wallet.pubkey(), 1).await;
+    let recipient = Pubkey::new_unique();
+    mount_transfer_instructions(&h, &h.wallet.pubkey(), &recipient).await;
+
+    swap_instruction_flow(&h.wallet).await.unwrap();
+    assert_eq!(validator.balance(&recipient).await, TRANSFER);
+}
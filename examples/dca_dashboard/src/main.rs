@@ -0,0 +1,77 @@
+// examples/dca_dashboard/src/main.rs
+//
+// Lists every time- and price-based recurring order of the wallet with its
+// fills, remaining deposit and PnL at current Price API prices.
+
+use jupiter_sdk::{
+    get_prices, mint_label, try_http_client, try_keypair, Config, RecurringClient, RecurringOrder, RecurringOrderStatus,
+    RecurringType,
+};
+use anyhow::Result;
+use solana_sdk::signature::Signer;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // load .env (RPC_URL, KEYPAIR_PATH or SECRET_KEY)
+    let cfg  = Config::load()?;
+    let user = try_keypair(&cfg)?.pubkey().to_string();
+    let http = try_http_client()?;
+
+    let recurring = RecurringClient::new(http.clone());
+    let mut orders = recurring.get_orders(&user, RecurringOrderStatus::Active).await?;
+    orders.extend(recurring.get_orders(&user, RecurringOrderStatus::History).await?);
+    if orders.is_empty() {
+        println!("No recurring orders for {user}");
+        return Ok(());
+    }
+
+    // one batched price lookup for every mint involved
+    let mints: Vec<&str> = orders
+        .iter()
+        .flat_map(|o| [o.input_mint.as_str(), o.output_mint.as_str()])
+        .collect();
+    let prices = get_prices(&http, &mints).await?;
+    let usd = |mint: &str| prices.get(mint).map(|p| p.usd_price);
+
+    println!(
+        "{:<12} {:<14} {:<5} {:>7} {:>14} {:>14} {:>14} {:>12}",
+        "ORDER", "PAIR", "TYPE", "CYCLES", "REMAINING", "RECEIVED", "AVG PRICE", "PnL (USD)"
+    );
+    for order in &orders {
+        println!("{}", row(order, usd(&order.input_mint), usd(&order.output_mint)));
+    }
+    Ok(())
+}
+
+fn row(order: &RecurringOrder, in_usd: Option<f64>, out_usd: Option<f64>) -> String {
+    let num = |s: &str| s.parse::<f64>().unwrap_or(0.0);
+    let kind = match order.recurring_type() {
+        RecurringType::Time  => "time",
+        RecurringType::Price => "price",
+    };
+    let cycles = match order.total_cycles() {
+        Some(total) => format!("{}/{}", order.cycles_done(), total),
+        None => order.cycles_done().to_string(),
+    };
+    let avg = order
+        .average_price()
+        .map(|p| format!("{p:.4}"))
+        .unwrap_or_else(|| "-".to_string());
+    // value of what was bought minus what it cost, both at today's prices
+    let pnl = match (in_usd, out_usd) {
+        (Some(i), Some(o)) => format!("{:+.2}", num(&order.out_received) * o - num(&order.in_used) * i),
+        _ => "-".to_string(),
+    };
+
+    format!(
+        "{:<12} {:<14} {:<5} {:>7} {:>14} {:>14} {:>14} {:>12}",
+        &order.order_key[..order.order_key.len().min(10)],
+        format!("{}→{}", mint_label(&order.input_mint), mint_label(&order.output_mint)),
+        kind,
+        cycles,
+        format!("{:.4}", order.remaining_in().max(0.0)),
+        format!("{:.4}", num(&order.out_received)),
+        avg,
+        pnl,
+    )
+}
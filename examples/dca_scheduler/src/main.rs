@@ -0,0 +1,27 @@
+use jupiter_sdk::{
+    dca_scheduler_flow, parse_interval, shutdown_token, start_metrics, try_signer, Config, DcaJob, MissedRuns,
+};
+use anyhow::Result;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // load .env (RPC_URL, KEYPAIR_PATH)
+    let cfg = Config::load()?;
+    let wallet = try_signer(&cfg)?;
+    // serve /metrics on METRICS_ADDR when built with `--features jupiter-sdk/metrics`
+    start_metrics();
+
+    // usage: cargo run -p dca_scheduler -- [INTERVAL e.g. "1h"] [--catch-up N]
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let mut job = DcaJob::default();
+    if let Some(interval) = args.first().filter(|a| !a.starts_with("--")) {
+        job.every_secs = parse_interval(interval)?;
+    }
+    let missed = match args.iter().position(|a| a == "--catch-up") {
+        Some(i) => MissedRuns::CatchUp { max: args.get(i + 1).map(|n| n.parse()).transpose()?.unwrap_or(1) },
+        None => MissedRuns::Skip,
+    };
+
+    // swaps 1 USDC → SOL per run; Ctrl-C finishes the swap in flight, saves state and exits; restart to resume
+    dca_scheduler_flow(&wallet, job, "dca_state.json".into(), missed, shutdown_token()).await
+}
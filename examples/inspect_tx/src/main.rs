@@ -0,0 +1,20 @@
+use jupiter_sdk::{inspect_transaction_flow, Config, EmitErrorExt};
+use anyhow::Result;
+use std::io::Read;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // load .env (RPC_URL) – lookup tables are fetched over RPC
+    Config::load()?;
+    // usage: cargo run -p inspect_tx -- <BASE64_TX>, or pipe the transaction on stdin
+    let b64 = match std::env::args().nth(1) {
+        Some(arg) if arg != "-" => arg,
+        _ => {
+            let mut buf = String::new();
+            std::io::stdin().read_to_string(&mut buf)?;
+            buf
+        }
+    };
+    inspect_transaction_flow(&b64).await.or_emit()?;
+    Ok(())
+}
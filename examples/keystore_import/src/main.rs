@@ -0,0 +1,15 @@
+use jupiter_sdk::keystore_import_flow;
+use anyhow::Result;
+use std::path::PathBuf;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // usage: cargo run -p keystore_import -- [OUT] [KEY_FILE]
+    //   OUT defaults to keystore.json; without KEY_FILE the secret key is prompted for
+    let mut args = std::env::args().skip(1);
+    let out = args.next().map(PathBuf::from).unwrap_or_else(|| PathBuf::from("keystore.json"));
+    let source = args.next().map(PathBuf::from);
+
+    keystore_import_flow(source.as_deref(), &out)?;
+    Ok(())
+}
@@ -0,0 +1,21 @@
+use jupiter_sdk::{
+    lend_deposit_flow, mint_decimals, rpc_client, try_signer, Config, EmitErrorExt, LendDepositParams, TokenAmount,
+    USDC_MINT,
+};
+use anyhow::Result;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // load .env (RPC_URL, KEYPAIR_PATH)
+    let cfg = Config::load()?;
+    let wallet = try_signer(&cfg)?;
+
+    // usage: cargo run -p lend_deposit -- [ASSET_MINT] [AMOUNT]  (defaults: 1 USDC)
+    let mut args = std::env::args().skip(1);
+    let asset = args.next().unwrap_or_else(|| USDC_MINT.to_string());
+    let ui = args.next().unwrap_or_else(|| "1".to_string());
+    let amount = TokenAmount::from_ui(&ui, mint_decimals(&rpc_client(&cfg), &asset).await?)?;
+
+    lend_deposit_flow(&wallet, LendDepositParams { asset, amount }).await.or_emit()?;
+    Ok(())
+}
@@ -0,0 +1,12 @@
+use jupiter_sdk::{lend_positions_flow, try_signer, Config, EmitErrorExt, TxSigner};
+use anyhow::Result;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // load .env (RPC_URL, KEYPAIR_PATH)
+    let cfg = Config::load()?;
+    let owner = try_signer(&cfg)?.address();
+    // print every Lend position with deposits, current value and yield
+    lend_positions_flow(&owner).await.or_emit()?;
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use jupiter_sdk::{
+    get_lend_token, lend_withdraw_flow, progress, try_http_client, try_signer, Config, EmitErrorExt, LendWithdrawAmount,
+    LendWithdrawParams, TokenAmount,
+};
+use anyhow::Result;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // load .env (RPC_URL, KEYPAIR_PATH)
+    let cfg = Config::load()?;
+    let wallet = try_signer(&cfg)?;
+
+    // usage: cargo run -p lend_withdraw -- <ASSET_MINT> <AMOUNT> [shares]
+    //   withdraws AMOUNT whole asset tokens, or redeems AMOUNT whole shares with `shares`
+    let mut args = std::env::args().skip(1);
+    let asset = args.next().ok_or_else(|| anyhow::anyhow!("missing asset mint"))?;
+    let ui = args.next().ok_or_else(|| anyhow::anyhow!("missing amount"))?;
+    let vault = get_lend_token(&try_http_client()?, &asset).await?;
+    let amount = match args.next().as_deref() {
+        Some("shares") => LendWithdrawAmount::Shares(TokenAmount::from_ui(&ui, vault.decimals)?),
+        _ => LendWithdrawAmount::Assets(TokenAmount::from_ui(&ui, vault.asset.decimals)?),
+    };
+
+    let result = lend_withdraw_flow(&wallet, LendWithdrawParams { asset, amount }).await.or_emit()?;
+    progress(format!("Lend withdraw: {result:#?}"));
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use jupiter_sdk::{
+    shutdown_token, start_metrics, try_http_client, Config, LiveQuoter, SwapParams, TokenAmount, USDC_MINT, WSOL_MINT,
+};
+use anyhow::Result;
+use std::time::Duration;
+
+const JUP_MINT: &str = "JUPyiwrYJFskUPiHa7hkeR8VUtAeFoSYbKedZNsDvCN";
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // load .env (optional API_KEY for higher rate limits)
+    Config::load()?;
+    // serve /metrics on METRICS_ADDR when built with `--features jupiter-sdk/metrics`
+    start_metrics();
+
+    let pair = |input: &str, output: &str, amount: TokenAmount| SwapParams {
+        input_mint: input.to_string(),
+        output_mint: output.to_string(),
+        amount,
+        ..SwapParams::default()
+    };
+    let cancel = shutdown_token();
+    let quoter = LiveQuoter::new(try_http_client()?, Duration::from_secs(5))
+        .pair(pair(WSOL_MINT, USDC_MINT, TokenAmount::new(1_000_000_000, 9)))
+        .pair(pair(WSOL_MINT, USDC_MINT, TokenAmount::new(100_000_000_000, 9)))
+        .pair(pair(USDC_MINT, JUP_MINT, TokenAmount::new(1_000_000_000, 6)))
+        .with_cancellation(cancel.clone())
+        .spawn();
+
+    // print every refreshed quote until Ctrl-C
+    let mut updates = quoter.subscribe();
+    loop {
+        let q = tokio::select! {
+            q = updates.recv() => q?,
+            _ = cancel.cancelled() => break,
+        };
+        println!(
+            "{}: {} out (rate {:.9}, impact {:.4}%, slot {}) via {}",
+            q.pair,
+            q.out_amount,
+            q.rate(),
+            q.price_impact_pct * 100.0,
+            q.context_slot,
+            q.route.join(" → "),
+        );
+    }
+    println!("stopped with {} pairs quoted", quoter.snapshot().len());
+    Ok(())
+}
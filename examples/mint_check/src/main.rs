@@ -0,0 +1,12 @@
+use jupiter_sdk::{check_mint_flow, Config, EmitErrorExt, USDC_MINT};
+use anyhow::Result;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // load .env (RPC_URL)
+    Config::load()?;
+    // usage: cargo run -p mint_check -- [MINT]  (defaults to USDC)
+    let mint = std::env::args().nth(1).unwrap_or_else(|| USDC_MINT.to_string());
+    check_mint_flow(&mint.parse()?).await.or_emit()?;
+    Ok(())
+}
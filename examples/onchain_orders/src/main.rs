@@ -0,0 +1,12 @@
+use jupiter_sdk::{onchain_orders_flow, try_signer, Config, EmitErrorExt, TxSigner};
+use anyhow::Result;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // load .env (RPC_URL, KEYPAIR_PATH)
+    let cfg = Config::load()?;
+    let owner = try_signer(&cfg)?.address();
+    // decode the wallet's Recurring and Trigger order accounts via getProgramAccounts
+    onchain_orders_flow(&owner).await.or_emit()?;
+    Ok(())
+}
@@ -0,0 +1,23 @@
+use jupiter_sdk::{payment_flow, progress, try_signer, Config, EmitErrorExt, PaymentRequest, TokenAmount};
+use anyhow::Result;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // load .env (RPC_URL, KEYPAIR_PATH)
+    let cfg = Config::load()?;
+    let wallet = try_signer(&cfg)?;
+
+    // usage: cargo run -p payment -- <MERCHANT> [USDC_AMOUNT] [PAY_MINT]  (defaults: 1 USDC, paid in SOL)
+    let mut args = std::env::args().skip(1);
+    let merchant = args.next().ok_or_else(|| anyhow::anyhow!("usage: payment <MERCHANT> [USDC_AMOUNT] [PAY_MINT]"))?;
+    let usdc = TokenAmount::from_ui(&args.next().unwrap_or_else(|| "1".into()), 6)?;
+
+    let mut req = PaymentRequest::usdc(merchant, usdc);
+    if let Some(mint) = args.next() {
+        req = req.paid_with(mint);
+    }
+
+    let receipt = payment_flow(&wallet, req).await.or_emit()?;
+    progress(format!("Receipt: {receipt:#?}"));
+    Ok(())
+}
@@ -0,0 +1,20 @@
+use jupiter_sdk::{payroll_flow, try_signer, Config, EmitErrorExt};
+use anyhow::Result;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // load .env (RPC_URL, KEYPAIR_PATH) – the keypair is the USDC treasury
+    let cfg = Config::load()?;
+    let wallet = try_signer(&cfg)?;
+
+    // usage: cargo run -p payroll -- [CSV]  with rows of address,token_mint,amount
+    let csv = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| concat!(env!("CARGO_MANIFEST_DIR"), "/payroll.example.csv").to_string());
+
+    let report = payroll_flow(&wallet, &csv).await.or_emit()?;
+    if report.failed > 0 {
+        anyhow::bail!("{} payouts failed", report.failed);
+    }
+    Ok(())
+}
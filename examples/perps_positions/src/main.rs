@@ -0,0 +1,12 @@
+use jupiter_sdk::{perps_positions_flow, try_signer, Config, EmitErrorExt, TxSigner};
+use anyhow::Result;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // load .env (RPC_URL, KEYPAIR_PATH)
+    let cfg = Config::load()?;
+    let owner = try_signer(&cfg)?.address();
+    // decode the wallet's Jupiter Perps positions and value them at mark price
+    perps_positions_flow(&owner).await.or_emit()?;
+    Ok(())
+}
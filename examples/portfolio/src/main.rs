@@ -0,0 +1,13 @@
+use jupiter_sdk::{portfolio_flow, try_signer, Config, EmitErrorExt, TxSigner};
+use anyhow::Result;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // load .env (KEYPAIR_PATH)
+    let cfg = Config::load()?;
+    let owner = try_signer(&cfg)?.address();
+    // Ultra balances valued with the Price API; pass --json for machine-readable output
+    let json = std::env::args().any(|a| a == "--json");
+    portfolio_flow(&owner, json).await.or_emit()?;
+    Ok(())
+}
@@ -1,11 +1,12 @@
-use common::{load_config, recurring_flow};
+use jupiter_sdk::{recurring_flow, try_signer, Config, EmitErrorExt, RecurringOrderParams};
 use anyhow::Result;
 
 #[tokio::main]
 async fn main() -> Result<()> {
     // load .env (RPC_URL, KEYPAIR_PATH)
-    let _cfg = load_config();
-    // run the stub flow
-    recurring_flow().await?;
+    let cfg = Config::load()?;
+    let wallet = try_signer(&cfg)?;
+    // 0.05 SOL → USDC over 2 daily cycles; adjust to meet the API minimums
+    recurring_flow(&wallet, RecurringOrderParams::default()).await.or_emit()?;
     Ok(())
 }
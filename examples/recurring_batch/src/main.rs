@@ -0,0 +1,32 @@
+use jupiter_sdk::{
+    recurring_batch_flow, try_signer, Config, EmitErrorExt, RecurringOrderParams, TokenAmount, USDC_MINT, WSOL_MINT,
+};
+use anyhow::Result;
+
+const JUP_MINT: &str = "JUPyiwrYJFskUPiHa7hkeR8VUtAeFoSYbKedZNsDvCN";
+const JLP_MINT: &str = "27G8MtK7VtTcCHkpASjSDdkWWYfoqT6ggEuKidVJidD4";
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // load .env (RPC_URL, KEYPAIR_PATH)
+    let cfg = Config::load()?;
+    let wallet = try_signer(&cfg)?;
+
+    // weekly buys of SOL, JUP and JLP: 100 USDC each, split over 2 weeks
+    let basket: Vec<RecurringOrderParams> = [WSOL_MINT, JUP_MINT, JLP_MINT]
+        .into_iter()
+        .map(|output| {
+            RecurringOrderParams {
+                input_mint: USDC_MINT.to_string(),
+                output_mint: output.to_string(),
+                in_amount: TokenAmount::new(100_000_000, 6),
+                number_of_orders: 2,
+                ..Default::default()
+            }
+            .with_interval("1w")
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    recurring_batch_flow(&wallet, basket, 2).await.or_emit()?;
+    Ok(())
+}
@@ -0,0 +1,12 @@
+use jupiter_sdk::{close_and_claim_flow, try_signer, Config, EmitErrorExt};
+use anyhow::Result;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // load .env (RPC_URL, KEYPAIR_PATH)
+    let cfg = Config::load()?;
+    let wallet = try_signer(&cfg)?;
+    // sweep proceeds of completed recurring orders back to the wallet
+    close_and_claim_flow(&wallet).await.or_emit()?;
+    Ok(())
+}
@@ -0,0 +1,15 @@
+use jupiter_sdk::{recurring_monitor_flow, shutdown_token, start_metrics, try_signer, Config, EmitErrorExt, TxSigner};
+use anyhow::Result;
+use std::time::Duration;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // load .env (RPC_URL, KEYPAIR_PATH, NOTIFY_WEBHOOK_URL)
+    let cfg = Config::load()?;
+    let owner = try_signer(&cfg)?.address();
+    // serve /metrics on METRICS_ADDR when built with `--features jupiter-sdk/metrics`
+    start_metrics();
+    // poll the wallet's recurring orders once a minute until Ctrl-C
+    recurring_monitor_flow(&owner, Duration::from_secs(60), shutdown_token()).await.or_emit()?;
+    Ok(())
+}
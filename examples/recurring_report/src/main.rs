@@ -0,0 +1,17 @@
+use jupiter_sdk::{recurring_report_flow, try_signer, Config, EmitErrorExt, TxSigner};
+use anyhow::Result;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // load .env (RPC_URL, KEYPAIR_PATH)
+    let cfg = Config::load()?;
+    let owner = try_signer(&cfg)?.address();
+
+    // usage: cargo run -p recurring_report -- <ORDER> [BENCHMARK_PRICE]
+    let mut args = std::env::args().skip(1);
+    let order = args.next().ok_or_else(|| anyhow::anyhow!("missing recurring order pubkey"))?;
+    let benchmark = args.next().map(|p| p.parse::<f64>()).transpose()?;
+
+    recurring_report_flow(&owner, &order, benchmark).await.or_emit()?;
+    Ok(())
+}
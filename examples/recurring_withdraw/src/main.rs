@@ -0,0 +1,37 @@
+use jupiter_sdk::{
+    mint_decimals, rpc_client, try_signer, withdraw_flow, Config, EmitErrorExt, JupiterClient, RecurringOrderStatus,
+    TokenAmount, TxSigner, WithdrawParams, WithdrawSide,
+};
+use anyhow::Result;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // load .env (RPC_URL, KEYPAIR_PATH)
+    let cfg = Config::load()?;
+    let wallet = try_signer(&cfg)?;
+
+    // usage: cargo run -p recurring_withdraw -- <ORDER> <in|out> [AMOUNT]
+    //   AMOUNT is in whole tokens of that side's mint; omit it to withdraw everything
+    let mut args = std::env::args().skip(1);
+    let order = args.next().ok_or_else(|| anyhow::anyhow!("missing recurring order pubkey"))?;
+    let side: WithdrawSide = args.next().as_deref().unwrap_or("out").parse()?;
+    let amount = match args.next() {
+        Some(ui) => {
+            let user = wallet.address().to_string();
+            let orders = JupiterClient::new(&cfg)?.recurring().get_orders(&user, RecurringOrderStatus::Active).await?;
+            let open = orders
+                .into_iter()
+                .find(|o| o.order_key == order)
+                .ok_or_else(|| anyhow::anyhow!("{user} has no open recurring order {order}"))?;
+            let mint = match side {
+                WithdrawSide::Input => open.input_mint,
+                WithdrawSide::Output => open.output_mint,
+            };
+            Some(TokenAmount::from_ui(&ui, mint_decimals(&rpc_client(&cfg), &mint).await?)?)
+        }
+        None => None,
+    };
+
+    withdraw_flow(&wallet, WithdrawParams { order, side, amount }).await.or_emit()?;
+    Ok(())
+}
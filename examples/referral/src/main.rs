@@ -0,0 +1,28 @@
+use jupiter_sdk::{
+    referral_account_flow, referral_token_accounts_flow, try_signer, Config, EmitErrorExt, USDC_MINT, WSOL_MINT,
+};
+use anyhow::Result;
+use std::str::FromStr;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // load .env (RPC_URL, KEYPAIR_PATH)
+    let cfg = Config::load()?;
+    let wallet = try_signer(&cfg)?;
+
+    // usage: cargo run -p referral -- <NAME> [PROJECT]  (defaults to the Jupiter Ultra project)
+    let mut args = std::env::args().skip(1);
+    let name = args.next().ok_or_else(|| anyhow::anyhow!("missing referral account name"))?;
+    let project = args.next().map(|p| p.parse()).transpose()?;
+
+    let referral_account = referral_account_flow(&wallet, &name, project).await.or_emit()?;
+
+    // token accounts so fees in USDC and SOL have somewhere to accrue;
+    // set FEE_ACCOUNT to the referral account for Ultra, or to one of these for /swap
+    let mints = [USDC_MINT, WSOL_MINT]
+        .iter()
+        .map(|m| solana_sdk::pubkey::Pubkey::from_str(m))
+        .collect::<Result<Vec<_>, _>>()?;
+    referral_token_accounts_flow(&wallet, &referral_account, &mints).await.or_emit()?;
+    Ok(())
+}
@@ -0,0 +1,18 @@
+use jupiter_sdk::{claim_referral_fees_flow, try_signer, Config, EmitErrorExt};
+use anyhow::Result;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // load .env (RPC_URL, KEYPAIR_PATH)
+    let cfg = Config::load()?;
+    let wallet = try_signer(&cfg)?;
+
+    // usage: cargo run -p referral_claim -- [REFERRAL_ACCOUNT]  (defaults to FEE_ACCOUNT)
+    let referral = std::env::args()
+        .nth(1)
+        .or_else(|| std::env::var("FEE_ACCOUNT").ok().filter(|s| !s.is_empty()))
+        .ok_or_else(|| anyhow::anyhow!("pass a referral account or set FEE_ACCOUNT"))?;
+
+    claim_referral_fees_flow(&wallet, &referral.parse()?).await.or_emit()?;
+    Ok(())
+}
@@ -0,0 +1,92 @@
+// examples/round_trip/src/main.rs
+//
+// Two-leg round trip: quote A→B and B→A at the same time, multiply the two
+// quoted rates and see whether going around the loop returns more A than it
+// started with. Quotes already include route fees (and any platform fee);
+// the network cost of two transactions is estimated separately.
+//
+// Pass --execute to actually send both legs when the edge clears MIN_PROFIT_BPS.
+
+use jupiter_sdk::{
+    get_quote, get_usd_price, swap_with_params, try_http_client, try_signer, Config, SwapParams, TokenAmount, USDC_MINT,
+    WSOL_MINT,
+};
+use anyhow::Result;
+
+const MINT_A: &str = USDC_MINT;
+const MINT_B: &str = WSOL_MINT;
+const DECIMALS_A: u8 = 6;
+const DECIMALS_B: u8 = 9;
+
+/// Notional of the first leg, in units of MINT_A
+const NOTIONAL_A: f64 = 10.0;
+const SLIPPAGE_BPS: u64 = 30;
+/// Rough cost of two transactions (base + priority fee) in USD
+const NETWORK_FEE_USD: f64 = 0.002;
+const MIN_PROFIT_BPS: f64 = 5.0;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // load .env (RPC_URL, KEYPAIR_PATH)
+    let cfg = Config::load()?;
+    let http = try_http_client()?;
+    let execute = std::env::args().any(|a| a == "--execute");
+
+    // Size the return leg from spot prices so both quotes can run concurrently
+    let (price_a, price_b) = tokio::try_join!(get_usd_price(&http, MINT_A), get_usd_price(&http, MINT_B))?;
+    let (price_a, price_b) = price_a
+        .zip(price_b)
+        .ok_or_else(|| anyhow::anyhow!("missing USD price for {MINT_A} or {MINT_B}"))?;
+    let notional_b = NOTIONAL_A * price_a / price_b;
+
+    let leg1 = SwapParams {
+        input_mint: MINT_A.to_string(),
+        output_mint: MINT_B.to_string(),
+        amount: TokenAmount::new((NOTIONAL_A * 10f64.powi(DECIMALS_A as i32)).round() as u64, DECIMALS_A),
+        slippage_bps: SLIPPAGE_BPS,
+        mint_policy: None,
+    };
+    let leg2 = SwapParams {
+        input_mint: MINT_B.to_string(),
+        output_mint: MINT_A.to_string(),
+        amount: TokenAmount::new((notional_b * 10f64.powi(DECIMALS_B as i32)).round() as u64, DECIMALS_B),
+        slippage_bps: SLIPPAGE_BPS,
+        mint_policy: None,
+    };
+    let (q1, q2) = tokio::try_join!(get_quote(&http, &leg1), get_quote(&http, &leg2))?;
+
+    // A→B rate × B→A rate, in raw units (decimals cancel out around the loop)
+    let rate1 = q1.outAmount.parse::<f64>()? / q1.inAmount.parse::<f64>()?;
+    let rate2 = q2.outAmount.parse::<f64>()? / q2.inAmount.parse::<f64>()?;
+    let round_trip = rate1 * rate2;
+
+    let gross_bps = (round_trip - 1.0) * 10_000.0;
+    let fee_bps   = NETWORK_FEE_USD / (NOTIONAL_A * price_a) * 10_000.0;
+    let net_bps   = gross_bps - fee_bps;
+    println!(
+        "round trip {NOTIONAL_A} {}: ratio {round_trip:.6}, gross {gross_bps:+.2} bps, network {fee_bps:.2} bps, net {net_bps:+.2} bps",
+        jupiter_sdk::mint_label(MINT_A),
+    );
+
+    if net_bps < MIN_PROFIT_BPS {
+        println!("below {MIN_PROFIT_BPS} bps threshold, not trading");
+        return Ok(());
+    }
+    if !execute {
+        println!("profitable; re-run with --execute to send both legs");
+        return Ok(());
+    }
+
+    let wallet = try_signer(&cfg)?;
+    let first = swap_with_params(&wallet, leg1).await?;
+    // Sell exactly what the first leg was quoted to deliver
+    let second = swap_with_params(&wallet, SwapParams { amount: first.out_amount, ..leg2 }).await?;
+    let pnl = second.out_amount.ui_f64() - first.in_amount.ui_f64();
+    println!(
+        "legs {} / {}: quoted PnL {pnl:+.6} {}",
+        first.signature,
+        second.signature,
+        jupiter_sdk::mint_label(MINT_A)
+    );
+    Ok(())
+}
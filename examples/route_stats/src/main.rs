@@ -0,0 +1,28 @@
+use jupiter_sdk::{route_stats_flow, Config, EmitErrorExt, SwapParams, TokenAmount, USDC_MINT, WSOL_MINT};
+use anyhow::Result;
+use std::time::Duration;
+
+const JUP_MINT: &str = "JUPyiwrYJFskUPiHa7hkeR8VUtAeFoSYbKedZNsDvCN";
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // load .env (RPC_URL)
+    Config::load()?;
+
+    // small and large sizes of the same pair route differently; so do long-tail pairs
+    let pair = |input: &str, output: &str, amount: TokenAmount| SwapParams {
+        input_mint: input.to_string(),
+        output_mint: output.to_string(),
+        amount,
+        ..SwapParams::default()
+    };
+    let pairs = vec![
+        pair(WSOL_MINT, USDC_MINT, TokenAmount::new(1_000_000_000, 9)),
+        pair(WSOL_MINT, USDC_MINT, TokenAmount::new(500_000_000_000, 9)),
+        pair(USDC_MINT, JUP_MINT, TokenAmount::new(100_000_000, 6)),
+    ];
+
+    // 10 rounds, one every 30 seconds
+    route_stats_flow(pairs, 10, Duration::from_secs(30)).await.or_emit()?;
+    Ok(())
+}
@@ -1,15 +1,16 @@
 // examples/swap/src/main.rs
 
-use common::{load_config, swap_flow};
+use jupiter_sdk::{swap_flow, try_signer, Config, EmitErrorExt};
 use anyhow::Result;
 
 #[tokio::main]
 async fn main() -> Result<()> {
     // Load .env (RPC_URL, KEYPAIR_PATH or SECRET_KEY)
-    let _cfg = load_config();
+    let cfg = Config::load()?;
+    let wallet = try_signer(&cfg)?;
 
     // Execute the swap flow
-    swap_flow().await?;
+    swap_flow(&wallet).await.or_emit()?;
 
     Ok(())
 }
@@ -0,0 +1,30 @@
+use jupiter_sdk::{
+    install_pre_trade_hook, rpc_client, swap_flow, try_http_client, try_signer, Config, EmitErrorExt, SafetyPipeline,
+    USDC_MINT,
+};
+use anyhow::Result;
+use std::sync::Arc;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // load .env (RPC_URL, KEYPAIR_PATH)
+    let cfg = Config::load()?;
+
+    // usage: cargo run -p token_safety -- [MINT] [--swap]
+    let mint = std::env::args().nth(1).filter(|a| !a.starts_with("--")).unwrap_or_else(|| USDC_MINT.to_string());
+    let pipeline = SafetyPipeline::standard(try_http_client()?, Arc::new(rpc_client(&cfg)));
+
+    // Shield + Token API + on-chain mint account, folded into one verdict
+    let verdict = pipeline.evaluate(&mint.parse()?).await;
+    println!("{mint}: {}", if verdict.blocked { "BLOCKED" } else { "ok" });
+    for reason in verdict.reasons() {
+        println!("  {reason}");
+    }
+
+    // With --swap, install the pipeline as the pre-trade hook and run the default swap through it
+    if std::env::args().any(|a| a == "--swap") {
+        install_pre_trade_hook(pipeline)?;
+        swap_flow(&try_signer(&cfg)?).await.or_emit()?;
+    }
+    Ok(())
+}
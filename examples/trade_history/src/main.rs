@@ -0,0 +1,14 @@
+use jupiter_sdk::{trade_history_flow, try_signer, Config, EmitErrorExt, TxSigner};
+use anyhow::Result;
+use std::path::Path;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // load .env (RPC_URL, KEYPAIR_PATH)
+    let cfg = Config::load()?;
+    let owner = try_signer(&cfg)?.address();
+    // usage: cargo run -p trade_history -- [MAX_SIGNATURES]  (default 200)
+    let max = std::env::args().nth(1).map(|n| n.parse()).transpose()?.unwrap_or(200);
+    trade_history_flow(&owner, max, Some(Path::new("trades.csv"))).await.or_emit()?;
+    Ok(())
+}
@@ -0,0 +1,15 @@
+use jupiter_sdk::{basket_flow, try_signer, BasketParams, Config, EmitErrorExt};
+use anyhow::Result;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // load .env (RPC_URL, KEYPAIR_PATH)
+    let cfg = Config::load()?;
+    let wallet = try_signer(&cfg)?;
+
+    // quotes all legs concurrently and checks price impact / value loss;
+    // only swaps with --execute
+    let execute = std::env::args().any(|a| a == "--execute");
+    basket_flow(&wallet, BasketParams::default(), execute).await.or_emit()?;
+    Ok(())
+}
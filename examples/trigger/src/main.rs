@@ -1,11 +1,12 @@
-use common::{load_config, trigger_flow};
+use jupiter_sdk::{trigger_flow, try_signer, Config, EmitErrorExt};
 use anyhow::Result;
 
 #[tokio::main]
 async fn main() -> Result<()> {
     // load .env (RPC_URL, KEYPAIR_PATH)
-    let _cfg = load_config();
+    let cfg = Config::load()?;
+    let wallet = try_signer(&cfg)?;
     // run the stub flow
-    trigger_flow().await?;
+    trigger_flow(&wallet).await.or_emit()?;
     Ok(())
 }
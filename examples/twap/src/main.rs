@@ -0,0 +1,26 @@
+use jupiter_sdk::{parse_interval, try_signer, twap_flow, Config, EmitErrorExt, TokenAmount, TwapParams};
+use anyhow::Result;
+use std::time::Duration;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // load .env (RPC_URL, KEYPAIR_PATH)
+    let cfg = Config::load()?;
+    let wallet = try_signer(&cfg)?;
+
+    // usage: cargo run -p twap -- [SOL e.g. 0.2] [SLICES] [WINDOW e.g. "30m"]
+    let mut args = std::env::args().skip(1);
+    let mut params = TwapParams::default();
+    if let Some(amount) = args.next() {
+        params.total_amount = TokenAmount::from_ui(&amount, 9)?;
+    }
+    if let Some(slices) = args.next() {
+        params.slices = slices.parse()?;
+    }
+    if let Some(window) = args.next() {
+        params.window = Duration::from_secs(parse_interval(&window)?);
+    }
+
+    twap_flow(&wallet, params).await.or_emit()?;
+    Ok(())
+}
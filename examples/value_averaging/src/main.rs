@@ -0,0 +1,69 @@
+// examples/value_averaging/src/main.rs
+//
+// Value averaging: every period the position should be worth `TARGET_STEP_USD`
+// more than the period before. We price the position with the Price API and
+// buy the shortfall from USDC with a one-off swap. Periods where the position
+// is already above target are skipped (a full VA strategy would sell the excess).
+
+use jupiter_sdk::{
+    get_usd_price, swap_with_params, try_http_client, try_signer, Config, MintPolicy, SwapParams, TokenAmount,
+    USDC_MINT, WSOL_MINT,
+};
+use anyhow::Result;
+use std::time::Duration;
+
+const TARGET_MINT: &str = WSOL_MINT;
+const USDC_DECIMALS: u8 = 6;
+
+/// Target value increase per period
+const TARGET_STEP_USD: f64 = 10.0;
+/// Never spend more than this in a single period
+const MAX_BUY_USD: f64 = 25.0;
+const PERIODS: u32 = 4;
+const PERIOD: Duration = Duration::from_secs(60 * 60);
+const SLIPPAGE_BPS: u64 = 50;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // load .env (RPC_URL, KEYPAIR_PATH or SECRET_KEY)
+    let cfg = Config::load()?;
+    let wallet = try_signer(&cfg)?;
+    let http = try_http_client()?;
+
+    // Units of TARGET_MINT acquired by this strategy run
+    let mut held: f64 = 0.0;
+    let mut ticker = tokio::time::interval(PERIOD);
+
+    for period in 1..=PERIODS {
+        ticker.tick().await;
+
+        let price = get_usd_price(&http, TARGET_MINT)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("no USD price for {TARGET_MINT}"))?;
+        let target_value  = TARGET_STEP_USD * period as f64;
+        let current_value = held * price;
+        let shortfall     = target_value - current_value;
+
+        println!(
+            "period {period}/{PERIODS}: price ${price:.4}, value ${current_value:.2}, target ${target_value:.2}"
+        );
+        if shortfall <= 0.0 {
+            println!("  above target by ${:.2}, skipping", -shortfall);
+            continue;
+        }
+
+        let buy_usd = shortfall.min(MAX_BUY_USD);
+        let params = SwapParams {
+            input_mint: USDC_MINT.to_string(),
+            output_mint: TARGET_MINT.to_string(),
+            amount: TokenAmount::new((buy_usd * 10f64.powi(USDC_DECIMALS as i32)).round() as u64, USDC_DECIMALS),
+            slippage_bps: SLIPPAGE_BPS,
+            mint_policy: Some(MintPolicy::default()),
+        };
+        let result = swap_with_params(&wallet, params).await?;
+        held += result.out_amount.ui_f64();
+        println!("  bought ${buy_usd:.2} → {held:.6} held ({})", result.signature);
+    }
+
+    Ok(())
+}